@@ -0,0 +1,93 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::cmp::min;
+
+pub fn parse_input(input: &str) -> Result<Vec<(u32, u32, u32)>, ParseError> {
+    let mut dimensions: Vec<(u32, u32, u32)> = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        let x = line.number()?;
+        line.expect("x")?;
+        let y = line.number()?;
+        line.expect("x")?;
+        let z = line.number()?;
+        line.end()?;
+        dimensions.push((x, y, z));
+    }
+    Ok(dimensions)
+}
+
+/// total required paper in sqft
+pub fn part_one(dimensions: &[(u32, u32, u32)]) -> u32 {
+    let mut area = 0;
+    for &(x, y, z) in dimensions {
+        let area_xy = x * y;
+        let area_xz = x * z;
+        let area_yz = y * z;
+        let min = min(area_xy, min(area_xz, area_yz));
+        area += 2 * area_xy + 2 * area_xz + 2 * area_yz + min;
+    }
+    area
+}
+
+/// total required ribbon in ft
+pub fn part_two(dimensions: &[(u32, u32, u32)]) -> u32 {
+    let mut ribbon = 0;
+    for &(x, y, z) in dimensions {
+        let xy = 2 * x + 2 * y;
+        let xz = 2 * x + 2 * z;
+        let yz = 2 * y + 2 * z;
+        let min = min(xy, min(xz, yz));
+        ribbon += min + (x * y * z);
+    }
+    ribbon
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(u32, u32, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(dimensions: &Self::Input) -> Answer {
+        part_one(dimensions).into()
+    }
+
+    fn part2(dimensions: &Self::Input) -> Answer {
+        part_two(dimensions).into()
+    }
+}
+
+impl Generate for Day02 {
+    /// `size` presents
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let (l, w, h) = (rng.range(1, 30), rng.range(1, 30), rng.range(1, 30));
+                format!("{}x{}x{}\n", l, w, h)
+            })
+            .collect()
+    }
+
+    fn render(dimensions: &Self::Input) -> String {
+        dimensions
+            .iter()
+            .map(|(l, w, h)| format!("{}x{}x{}\n", l, w, h))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day02>(EXAMPLE, 1, 58 + 43);
+        assert_example::<Day02>(EXAMPLE, 2, 34 + 14);
+    }
+}
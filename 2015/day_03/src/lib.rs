@@ -0,0 +1,115 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::collections::HashSet;
+
+fn move_(pos: &(isize, isize), dir: &char) -> (isize, isize) {
+    match dir {
+        '^' => (pos.0, pos.1 - 1),
+        'v' => (pos.0, pos.1 + 1),
+        '>' => (pos.0 + 1, pos.1),
+        '<' => (pos.0 - 1, pos.1),
+        _ => unreachable!(),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<char>, ParseError> {
+    let mut line = aoc_core::first_line(input)?;
+    if line.is_done() {
+        return Err(line.error("'^', 'v', '>' or '<'"));
+    }
+    let mut moves = Vec::new();
+    while !line.is_done() {
+        moves.push(line.char_as("'^', 'v', '>' or '<'", |c| "^v><".contains(c).then_some(c))?);
+    }
+    Ok(moves)
+}
+
+pub fn part1(moves: &[char]) -> usize {
+    let mut pos: (isize, isize) = (0, 0);
+
+    let mut visited: HashSet<(isize, isize)> = HashSet::from([pos]);
+    for dir in moves {
+        pos = move_(&pos, dir);
+        visited.insert(pos);
+    }
+
+    visited.len()
+}
+
+pub fn part2(moves: &[char]) -> usize {
+    let mut pos1: (isize, isize) = (0, 0);
+    let mut pos2: (isize, isize) = (0, 0);
+
+    let mut visited: HashSet<(isize, isize)> = HashSet::from([pos1]);
+    for (i, dir) in moves.iter().enumerate() {
+        match i % 2 {
+            0 => {
+                pos1 = move_(&pos1, dir);
+                visited.insert(pos1);
+            }
+            1 => {
+                pos2 = move_(&pos2, dir);
+                visited.insert(pos2);
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    visited.len()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        part1(moves).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Answer {
+        part2(moves).into()
+    }
+}
+
+impl Generate for Day03 {
+    /// `size` moves
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut moves: String = (0..size.max(1))
+            .map(|_| *rng.pick(&['^', 'v', '>', '<']))
+            .collect();
+        moves.push('\n');
+        moves
+    }
+
+    fn render(moves: &Self::Input) -> String {
+        let mut input: String = moves.iter().collect();
+        input.push('\n');
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day03>(EXAMPLE, 1, 4);
+        assert_example::<Day03>(EXAMPLE, 2, 3);
+    }
+
+    #[test]
+    fn test_starting_house_is_visited() {
+        assert_example::<Day03>(">", 1, 2);
+        assert_example::<Day03>("^v", 2, 3);
+        assert_example::<Day03>("^v^v^v^v^v", 1, 2);
+        assert_example::<Day03>("^v^v^v^v^v", 2, 11);
+    }
+}
@@ -0,0 +1,314 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+/// Digits as they're written, for part one.
+pub const DIGITS: &[(&str, u32)] = &[
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Digits spelled out, which count too in part two.
+pub const WORDS: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// A word found in a line, at bytes `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+struct Node {
+    children: Vec<(u8, usize)>,
+    /// the node for the longest proper suffix of this one's text that's
+    /// also in the trie
+    fail: usize,
+    /// the words that end here, this node's own and those of its suffixes
+    words: Vec<usize>,
+}
+
+impl Node {
+    fn new() -> Node {
+        Node {
+            children: Vec::new(),
+            fail: 0,
+            words: Vec::new(),
+        }
+    }
+
+    fn child(&self, byte: u8) -> Option<usize> {
+        self.children
+            .iter()
+            .find(|&&(b, _)| b == byte)
+            .map(|&(_, node)| node)
+    }
+}
+
+/// Finds every word of a vocabulary in one pass over a line, with the
+/// Aho-Corasick automaton: a trie of the words, where a byte that can't
+/// follow falls back to the longest suffix that can. Nothing is read twice,
+/// and words that overlap like the two in "eightwo" are all found.
+pub struct Matcher {
+    nodes: Vec<Node>,
+    words: Vec<(String, u32)>,
+}
+
+impl Matcher {
+    /// A matcher for the words of `vocabulary`, each with the value it
+    /// stands for. Words can be anything but empty.
+    pub fn new(vocabulary: &[(&str, u32)]) -> Result<Matcher, String> {
+        let mut nodes = vec![Node::new()];
+        for (i, (word, value)) in vocabulary.iter().enumerate() {
+            if word.is_empty() {
+                return Err(format!("The word for {} is empty", value));
+            }
+            let mut node = 0;
+            for byte in word.bytes() {
+                node = match nodes[node].child(byte) {
+                    Some(child) => child,
+                    None => {
+                        nodes.push(Node::new());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.push((byte, child));
+                        child
+                    }
+                };
+            }
+            nodes[node].words.push(i);
+        }
+
+        // fall back links, by breadth so shorter texts have theirs already
+        let mut queue: std::collections::VecDeque<usize> =
+            nodes[0].children.iter().map(|&(_, child)| child).collect();
+        while let Some(node) = queue.pop_front() {
+            for (byte, child) in nodes[node].children.clone() {
+                let mut fail = nodes[node].fail;
+                while fail != 0 && nodes[fail].child(byte).is_none() {
+                    fail = nodes[fail].fail;
+                }
+                let fail = nodes[fail].child(byte).unwrap_or(0);
+                nodes[child].fail = fail;
+                let inherited = nodes[fail].words.clone();
+                nodes[child].words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        let words = vocabulary
+            .iter()
+            .map(|&(word, value)| (word.to_string(), value))
+            .collect();
+        Ok(Matcher { nodes, words })
+    }
+
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(child) = self.nodes[node].child(byte) {
+                return child;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every word in `text`, overlapping or not, in the order they end.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut node = 0;
+        text.bytes().enumerate().flat_map(move |(i, byte)| {
+            node = self.step(node, byte);
+            self.nodes[node].words.iter().map(move |&word| {
+                let (word, value) = &self.words[word];
+                Match {
+                    start: i + 1 - word.len(),
+                    end: i + 1,
+                    value: *value,
+                }
+            })
+        })
+    }
+
+    /// The word that starts first in `line` and the one that ends last,
+    /// which can be the same one.
+    pub fn first_and_last(&self, line: &str) -> Option<(Match, Match)> {
+        let mut matches = self.find_iter(line);
+        let found = matches.next()?;
+        let (mut first, mut last) = (found, found);
+        for found in matches {
+            if found.start < first.start {
+                first = found;
+            }
+            last = found;
+        }
+        Some((first, last))
+    }
+}
+
+/// The first and last values in each line read as a two digit number, all
+/// added up. A line without any word counts as 0.
+pub fn calibrate(input: &str, matcher: &Matcher) -> u32 {
+    let mut sum = 0;
+    for line in input.lines() {
+        let Some((first, last)) = matcher.first_and_last(line) else {
+            aoc_core::debug!("{}: nothing", line);
+            continue;
+        };
+        aoc_core::debug!(
+            "{}: {} at {}, {} at {}",
+            line,
+            &line[first.start..first.end],
+            first.start,
+            &line[last.start..last.end],
+            last.start
+        );
+        sum += first.value * 10 + last.value;
+    }
+    sum
+}
+
+pub fn part_one(input: &str) -> u32 {
+    calibrate(input, &Matcher::new(DIGITS).expect("digits aren't empty"))
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let matcher = Matcher::new(&[DIGITS, WORDS].concat()).expect("digits aren't empty");
+    calibrate(input, &matcher)
+}
+
+/// lines can hold anything but control characters, words are found
+/// among whatever else there is
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    for mut line in aoc_core::lines(input) {
+        while !line.is_done() {
+            line.char_as("anything but a control character", |c| {
+                (!c.is_control()).then_some(c)
+            })?;
+        }
+    }
+    Ok(input.to_string())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day01 {
+    /// `size` lines of letters, digits and spelled out digits
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let words: Vec<&str> = WORDS.iter().map(|&(word, _)| word).collect();
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..rng.range(1, 8) {
+                match rng.below(3) {
+                    0 => input.push(char::from(b'a' + rng.below(26) as u8)),
+                    1 => input.push(char::from(b'1' + rng.below(9) as u8)),
+                    _ => input.push_str(rng.pick::<&str>(&words)),
+                }
+            }
+            input.push(char::from(b'1' + rng.below(9) as u8));
+            input.push('\n');
+        }
+        input
+    }
+
+    fn render(input: &Self::Input) -> String {
+        input.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day01>(EXAMPLE, 1, 142);
+        assert_example::<Day01>(EXAMPLE_2, 2, 281);
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let matcher = Matcher::new(&[DIGITS, WORDS].concat()).unwrap();
+        let found: Vec<(usize, u32)> = matcher
+            .find_iter("eightwone3")
+            .map(|m| (m.start, m.value))
+            .collect();
+        assert_eq!(found, [(0, 8), (4, 2), (6, 1), (9, 3)]);
+
+        let (first, last) = matcher.first_and_last("xtwoneight").unwrap();
+        assert_eq!((first.start, first.end, first.value), (1, 4, 2));
+        assert_eq!((last.start, last.end, last.value), (5, 10, 8));
+        assert_eq!(matcher.first_and_last("abc"), None);
+        assert_eq!(part_two("sevenine\nabcone\n"), 79 + 11);
+    }
+
+    #[test]
+    fn test_custom_vocabulary() {
+        let spanish = [
+            ("uno", 1),
+            ("dos", 2),
+            ("tres", 3),
+            ("cuatro", 4),
+            ("cinco", 5),
+            ("seis", 6),
+            ("siete", 7),
+            ("ocho", 8),
+            ("nueve", 9),
+        ];
+        let matcher = Matcher::new(&[DIGITS, &spanish].concat()).unwrap();
+        assert_eq!(calibrate("xdosiete\nnueveocho4\n", &matcher), 27 + 94);
+
+        // words inside others, and ones sharing a start
+        let matcher = Matcher::new(&[("he", 1), ("she", 2), ("hers", 3), ("his", 4)]).unwrap();
+        let found: Vec<(usize, usize)> = matcher
+            .find_iter("ushers")
+            .map(|m| (m.start, m.end))
+            .collect();
+        assert_eq!(found, [(1, 4), (2, 4), (2, 6)]);
+
+        assert!(Matcher::new(&[("uno", 1), ("", 2)]).is_err());
+    }
+
+    #[test]
+    fn test_any_printable_text() {
+        let input = parse_input("x-two, 3 é!\n7 señales\n").unwrap();
+        assert_eq!(part_two(&input), 23 + 77);
+        let error = parse_input("one\ttwo\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_2023_day_02::{self as day, Cubes, Game};
+use std::io::{self, Read};
+use std::{env, fs, process};
+
+const USAGE: &str = "Usage: cubes [--bag <cubes>] [--game <id>] [--input <path>|-]";
+
+struct CubesArgs {
+    /// what the bag holds, `12 red, 13 green, 14 blue` unless asked
+    bag: Cubes,
+    /// every game when there's none
+    game: Option<u16>,
+    /// `input.txt` unless asked, `-` for stdin
+    input: String,
+}
+
+fn parse_cubes_args(args: &[String]) -> Result<CubesArgs, String> {
+    let mut bag = None;
+    let mut game = None;
+    let mut input = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--bag" => {
+                let value = iter
+                    .next()
+                    .ok_or("--bag needs cubes, like '3 red, 4 blue'")?;
+                let cubes = day::parse_cubes(value)
+                    .map_err(|e| format!("Invalid bag '{}': {}", value, e))?;
+                bag = Some(cubes);
+            }
+            "--game" => {
+                let value = iter.next().ok_or("--game needs an id")?;
+                let id = value
+                    .parse()
+                    .map_err(|_| format!("Invalid game '{}'", value))?;
+                game = Some(id);
+            }
+            "--input" | "-i" => {
+                let value = iter.next().ok_or("--input needs a path, or - for stdin")?;
+                input = Some(value.clone());
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    Ok(CubesArgs {
+        bag: bag.unwrap_or_else(|| day::parse_cubes(day::BAG).unwrap()),
+        game,
+        input: input.unwrap_or_else(|| "input.txt".to_string()),
+    })
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    let mut input = String::new();
+    match path {
+        "-" => io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|e| format!("Could not read input from stdin: {}", e)),
+        path => fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path, e)),
+    }
+}
+
+/// Plays the cube games against any bag, see `day::report`.
+fn cubes(args: CubesArgs) -> Result<(), String> {
+    let input = read_input(&args.input)?;
+    let games = day::parse_games(&input).map_err(|e| format!("Invalid input: {}", e))?;
+    let games: Vec<&Game> = games
+        .iter()
+        .filter(|game| args.game.is_none_or(|id| game.id == id))
+        .collect();
+    if games.is_empty() {
+        return Err("No games match".to_string());
+    }
+    print!("{}", day::report(&games, &args.bag));
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = parse_cubes_args(&args).and_then(cubes) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cubes_args() {
+        let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+        let defaults = parse_cubes_args(&[]).unwrap();
+        assert_eq!(
+            day::format_cubes(&defaults.bag),
+            "14 blue, 13 green, 12 red"
+        );
+        assert_eq!(
+            (defaults.game, defaults.input.as_str()),
+            (None, "input.txt")
+        );
+
+        let bag = vec!["--bag".to_string(), "2 cyan, 1 red".to_string()];
+        let parsed = parse_cubes_args(&bag).unwrap();
+        assert_eq!(day::format_cubes(&parsed.bag), "2 cyan, 1 red");
+        let bag = vec!["--bag".to_string(), "300 red, 5 green".to_string()];
+        let parsed = parse_cubes_args(&bag).unwrap();
+        assert_eq!(parsed.bag["red"], 300);
+        assert_eq!(parse_cubes_args(&args("--game 4")).unwrap().game, Some(4));
+        assert_eq!(parse_cubes_args(&args("-i -")).unwrap().input, "-");
+
+        assert!(parse_cubes_args(&args("--bag red")).is_err());
+        assert!(parse_cubes_args(&args("--game x")).is_err());
+        assert!(parse_cubes_args(&args("2023")).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::collections::BTreeMap;
+
+/// A number of cubes of each colour, by name. Colours that aren't there
+/// have none.
+pub type Cubes = BTreeMap<String, u32>;
+
+/// What the elf's bag holds in part one.
+pub const BAG: &str = "12 red, 13 green, 14 blue";
+
+/// The colours part two multiplies together.
+pub const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub struct Game {
+    pub id: u16,
+    /// the cubes shown each time, in order
+    handfuls: Vec<Cubes>,
+}
+
+pub fn part_one(games: &[Game]) -> u32 {
+    let bag = parse_cubes(BAG).unwrap();
+    let mut sum: u32 = 0;
+
+    for game in games {
+        if is_game_possible(game, &bag) {
+            sum += game.id as u32;
+        }
+    }
+    sum
+}
+
+/// Whether every handful shown could have come out of `bag`.
+pub fn is_game_possible(game: &Game, bag: &Cubes) -> bool {
+    game.handfuls
+        .iter()
+        .flatten()
+        .all(|(color, &count)| count <= bag.get(color).copied().unwrap_or(0))
+}
+
+/// The sum of the powers, or `None` when it doesn't fit in a `u64`.
+pub fn part_two(games: &[Game]) -> Option<u64> {
+    let mut sum_of_powers: u64 = 0;
+
+    for game in games {
+        sum_of_powers =
+            sum_of_powers.checked_add(power_of_minimum_possible_cubes(game, &COLORS)?)?;
+    }
+    Some(sum_of_powers)
+}
+
+/// The fewest cubes of each colour the game could have been played with,
+/// for the colours it shows.
+pub fn minimum_cubes(game: &Game) -> Cubes {
+    let mut minimum = Cubes::new();
+    for (color, &count) in game.handfuls.iter().flatten() {
+        let fewest = minimum.entry(color.clone()).or_insert(0);
+        *fewest = (*fewest).max(count);
+    }
+    minimum
+}
+
+/// The fewest cubes of each of `colors` multiplied together, which is 0
+/// when the game never shows one of them, or `None` when the product
+/// doesn't fit in a `u64`.
+pub fn power_of_minimum_possible_cubes(game: &Game, colors: &[&str]) -> Option<u64> {
+    let minimum = minimum_cubes(game);
+    colors
+        .iter()
+        .map(|&color| minimum.get(color).copied().unwrap_or(0) as u64)
+        .try_fold(1, u64::checked_mul)
+}
+
+/// Cubes written the way games show them, like `3 blue, 4 red`, each
+/// colour once.
+pub fn parse_cubes(text: &str) -> Result<Cubes, ParseError> {
+    let mut line = Scanner::new(1, text);
+    let cubes = parse_handful(&mut line)?;
+    line.end()?;
+    Ok(cubes)
+}
+
+/// Cubes the way `parse_cubes` reads them.
+pub fn format_cubes(cubes: &Cubes) -> String {
+    let cubes: Vec<String> = cubes
+        .iter()
+        .map(|(color, count)| format!("{} {}", count, color))
+        .collect();
+    cubes.join(", ")
+}
+
+fn parse_handful(line: &mut Scanner) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::new();
+    loop {
+        let before = line.clone();
+        let count = line.number()?;
+        let color = line.word()?.to_string();
+        if cubes.insert(color, count).is_some() {
+            return Err(before.error("a colour not given yet"));
+        }
+        if !line.eat(",") {
+            break;
+        }
+    }
+    Ok(cubes)
+}
+
+/// How every game in `games` plays against `bag`: whether the bag could
+/// have held it, its power over the bag's colours and the fewest cubes it
+/// needs, then the sums of the possible games' ids and of the powers.
+pub fn report(games: &[&Game], bag: &Cubes) -> String {
+    let colors: Vec<&str> = bag.keys().map(String::as_str).collect();
+    let mut report = format!("Bag: {}\n", format_cubes(bag));
+    report.push_str(&format!("game  possible  {:>8}  fewest cubes\n", "power"));
+    // a power too big for a u64 is shown as such, and so is the sum
+    let (mut ids, mut powers) = (0u32, Some(0u64));
+    let show = |power: Option<u64>| power.map_or("too big".to_string(), |p| p.to_string());
+    for game in games {
+        let possible = is_game_possible(game, bag);
+        let power = power_of_minimum_possible_cubes(game, &colors);
+        report.push_str(&format!(
+            "{:>4}  {:<8}  {:>8}  {}\n",
+            game.id,
+            if possible { "yes" } else { "no" },
+            show(power),
+            format_cubes(&minimum_cubes(game))
+        ));
+        if possible {
+            ids += game.id as u32;
+        }
+        powers = powers
+            .zip(power)
+            .and_then(|(sum, power)| sum.checked_add(power));
+    }
+    report.push_str(&format!(
+        "Possible games add up to {}, powers to {}\n",
+        ids,
+        show(powers)
+    ));
+    report
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games: Vec<Game> = Vec::new();
+
+    for mut line in aoc_core::lines(input) {
+        line.expect("Game ")?;
+        let game_id = line.number()?;
+        line.expect(":")?;
+
+        let handfuls = line.separated(";", parse_handful)?;
+        line.end()?;
+
+        games.push(Game {
+            id: game_id,
+            handfuls,
+        });
+    }
+    Ok(games)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_games(input)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        part_one(games).into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        part_two(games).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day02 {
+    /// `size` games
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for id in 1..=size {
+            let shows: Vec<String> = (0..rng.range(1, 6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    let cubes: Vec<String> = colors[..rng.range(1, 3) as usize]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1, 20), color))
+                        .collect();
+                    cubes.join(", ")
+                })
+                .collect();
+            input.push_str(&format!("Game {}: {}\n", id, shows.join("; ")));
+        }
+        input
+    }
+
+    /// each handful with its colours in alphabetical order
+    fn render(games: &Self::Input) -> String {
+        games
+            .iter()
+            .map(|game| {
+                let handfuls: Vec<String> = game.handfuls.iter().map(format_cubes).collect();
+                format!("Game {}: {}\n", game.id, handfuls.join("; "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day02>(EXAMPLE, 1, 8);
+        assert_example::<Day02>(EXAMPLE, 2, 2286);
+    }
+
+    #[test]
+    fn test_any_colors() {
+        let games = parse_games("Game 7: 3 cyan, 2 red; 5 cyan\nGame 8: 1 magenta\n").unwrap();
+        let minimum = minimum_cubes(&games[0]);
+        assert_eq!(format_cubes(&minimum), "5 cyan, 2 red");
+        assert_eq!(
+            power_of_minimum_possible_cubes(&games[0], &["cyan", "red"]),
+            Some(10)
+        );
+        assert_eq!(power_of_minimum_possible_cubes(&games[0], &COLORS), Some(0));
+
+        let bag = parse_cubes("5 cyan, 300 red").unwrap();
+        assert_eq!(format_cubes(&bag), "5 cyan, 300 red");
+        assert!(is_game_possible(&games[0], &bag));
+        assert!(!is_game_possible(&games[1], &bag));
+        assert!(parse_cubes("5 cyan,").is_err());
+        assert!(parse_cubes("5 cyan, 1 red, 1 red").is_err());
+    }
+
+    #[test]
+    fn test_handfuls_kept_apart() {
+        let games = parse_games("Game 3: 1 red, 2 blue; 4 red; 1 green, 1 red\n").unwrap();
+        assert_eq!(games[0].handfuls.len(), 3);
+        assert_eq!(
+            Day02::render(&games),
+            "Game 3: 2 blue, 1 red; 4 red; 1 green, 1 red\n"
+        );
+        assert!(parse_games("Game 3: 1 red, 2 red\n").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let games = parse_games(EXAMPLE).unwrap();
+        let games: Vec<&Game> = games.iter().take(2).collect();
+        let bag = parse_cubes("4 red, 2 green, 6 blue").unwrap();
+        assert_eq!(
+            report(&games, &bag),
+            "Bag: 6 blue, 2 green, 4 red\n\
+             game  possible     power  fewest cubes\n   \
+             1  yes             48  6 blue, 2 green, 4 red\n   \
+             2  no              12  4 blue, 3 green, 1 red\n\
+             Possible games add up to 1, powers to 60\n"
+        );
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let games =
+            parse_games("Game 1: 4000000000 red, 4000000000 green, 4000000000 blue\n").unwrap();
+        assert_eq!(power_of_minimum_possible_cubes(&games[0], &COLORS), None);
+        assert_eq!(
+            power_of_minimum_possible_cubes(&games[0], &["red", "green"]),
+            Some(16_000_000_000_000_000_000)
+        );
+        assert_eq!(part_two(&games), None);
+    }
+}
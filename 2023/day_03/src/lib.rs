@@ -0,0 +1,218 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::Grid;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),   // right
+    (-1, 0),  // left
+    (0, 1),   // down
+    (0, -1),  //up
+    (1, 1),   // right down
+    (1, -1),  // right up
+    (-1, 1),  // left down
+    (-1, -1), // left up
+];
+
+pub struct Matrix {
+    data: Vec<Vec<char>>,
+    height: usize,
+    width: usize,
+}
+
+#[derive(PartialEq)]
+struct Range {
+    x: (usize, usize),
+    y: usize,
+}
+
+fn get_adjacent(matrix: &Matrix, x: usize, y: usize, dx: isize, dy: isize) -> Option<char> {
+    let nx = x as isize + dx;
+    let ny = y as isize + dy;
+    if nx < 0 || nx >= matrix.width as isize || ny < 0 || ny >= matrix.height as isize {
+        return None;
+    }
+    Some(matrix.data[ny as usize][nx as usize])
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+pub fn part_one(matrix: &Matrix) -> u32 {
+    let mut sum = 0;
+    for y in 0..matrix.height {
+        let mut x = 0;
+        while x < matrix.width {
+            let mut number_len = 0;
+            while x + number_len < matrix.width && matrix.data[y][x + number_len].is_ascii_digit() {
+                number_len += 1;
+            }
+
+            if number_len == 0 {
+                x += 1;
+                continue;
+            }
+
+            let number_as_str: String = matrix.data[y][x..x + number_len].iter().collect();
+            let number: u32 = number_as_str.parse().unwrap();
+
+            'adjacent_symbol_search: for l in 0..number_len {
+                for direction in DIRECTIONS {
+                    let adj = get_adjacent(matrix, x + l, y, direction.0, direction.1);
+                    if adj.is_none() {
+                        continue;
+                    };
+                    if is_symbol(adj.unwrap()) {
+                        sum += number;
+                        break 'adjacent_symbol_search;
+                    }
+                }
+            }
+
+            x += number_len;
+        }
+    }
+    sum
+}
+
+pub fn part_two(matrix: &Matrix) -> u32 {
+    // find all the surrounding digits for each '*', and expand the numbers
+    // outwards to obtain their coordinate range. Keep only unique ranges,
+    // and add the product if there are exactly 2 unique numbers.
+    let mut sum = 0;
+    for y in 0..matrix.height {
+        for x in 0..matrix.width {
+            if matrix.data[y][x] != '*' {
+                continue;
+            }
+
+            let mut ranges: Vec<Range> = Vec::new();
+            for direction in DIRECTIONS {
+                let adj = get_adjacent(matrix, x, y, direction.0, direction.1);
+                if adj.is_none() || !adj.unwrap().is_ascii_digit() {
+                    continue;
+                };
+                let nx = x as isize + direction.0;
+                let ny = y as isize + direction.1;
+                let range = get_number_range(matrix, nx as usize, ny as usize);
+                ranges.push(range);
+            }
+
+            let mut unique_ranges = Vec::new();
+            for range in ranges {
+                if !unique_ranges.contains(&range) {
+                    unique_ranges.push(range);
+                }
+            }
+
+            if unique_ranges.len() == 2 {
+                let number0_as_str: String = matrix.data[unique_ranges[0].y]
+                    [unique_ranges[0].x.0..unique_ranges[0].x.1 + 1]
+                    .iter()
+                    .collect();
+                let number0: u32 = number0_as_str.parse().unwrap();
+                let number1_as_str: String = matrix.data[unique_ranges[1].y]
+                    [unique_ranges[1].x.0..unique_ranges[1].x.1 + 1]
+                    .iter()
+                    .collect();
+                let number1: u32 = number1_as_str.parse().unwrap();
+                sum += number0 * number1
+            }
+        }
+    }
+    sum
+}
+
+fn get_number_range(matrix: &Matrix, x: usize, y: usize) -> Range {
+    let mut r = x;
+    let mut l = x;
+
+    while r + 1 < matrix.width && matrix.data[y][r + 1].is_ascii_digit() {
+        r += 1;
+    }
+    while l > 0 && matrix.data[y][l - 1].is_ascii_digit() {
+        l -= 1;
+    }
+    Range { x: (l, r), y }
+}
+
+pub fn parse_matrix(input: &str) -> Result<Matrix, ParseError> {
+    let grid = Grid::parse(input, "a digit, a symbol or '.'", |c| {
+        c.is_ascii_graphic().then_some(c)
+    })?;
+    Ok(Matrix {
+        data: grid.rows().map(|row| row.to_vec()).collect(),
+        height: grid.height(),
+        width: grid.width(),
+    })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Matrix;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_matrix(input)
+    }
+
+    fn part1(matrix: &Self::Input) -> Answer {
+        part_one(matrix).into()
+    }
+
+    fn part2(matrix: &Self::Input) -> Answer {
+        part_two(matrix).into()
+    }
+}
+
+impl Generate for Day03 {
+    /// a `size` by `size` schematic
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut row = String::new();
+            while row.len() < size {
+                let left = size - row.len();
+                if rng.chance(0.15) {
+                    let digits = rng.range(1, 3.min(left as i64)) as u32;
+                    row.push_str(
+                        &rng.range(10_i64.pow(digits - 1), 10_i64.pow(digits) - 1)
+                            .to_string(),
+                    );
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                } else if rng.chance(0.1) {
+                    row.push(*rng.pick(&['*', '#', '+', '$', '/', '=', '%', '@', '&', '-']));
+                } else {
+                    row.push('.');
+                }
+            }
+            input.push_str(&row);
+            input.push('\n');
+        }
+        input
+    }
+
+    fn render(matrix: &Self::Input) -> String {
+        matrix
+            .data
+            .iter()
+            .map(|row| format!("{}\n", row.iter().collect::<String>()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day03>(EXAMPLE, 1, 4361);
+        assert_example::<Day03>(EXAMPLE, 2, 467835);
+    }
+}
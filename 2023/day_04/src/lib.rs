@@ -0,0 +1,146 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+pub struct Card {
+    winning_numbers: Vec<u8>,
+    numbers: Vec<u8>,
+}
+
+pub fn part_one(cards: &[Card]) -> u32 {
+    let mut points = 0;
+    for card in cards {
+        let mut matches = 0;
+        for number in &card.numbers {
+            if card.winning_numbers.contains(number) {
+                matches += 1;
+            }
+        }
+        if matches == 0 {
+            continue;
+        }
+        points += 2_u32.pow(matches - 1);
+    }
+    points
+}
+
+pub fn part_two(cards: &[Card]) -> u32 {
+    let mut scratchcards = vec![1; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let mut matches = 0;
+        for number in &card.numbers {
+            if card.winning_numbers.contains(number) {
+                matches += 1;
+            }
+        }
+
+        for j in i..i + matches {
+            scratchcards[j + 1] += scratchcards[i]
+        }
+    }
+    scratchcards.iter().sum()
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    let mut cards: Vec<Card> = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        line.expect("Card")?;
+        line.number::<u32>()?;
+        line.expect(":")?;
+
+        let winning_numbers: Vec<u8> = line.numbers()?;
+        line.expect("|")?;
+        let numbers: Vec<u8> = line.numbers()?;
+        line.end()?;
+        cards.push(Card {
+            winning_numbers,
+            numbers,
+        });
+    }
+    Ok(cards)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        part_one(cards).into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        part_two(cards).into()
+    }
+}
+
+impl Generate for Day04 {
+    /// `size` cards, winning seldom enough that the copies stay countable
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for i in 0..size {
+            let mut numbers: Vec<u8> = (1..100).collect();
+            rng.shuffle(&mut numbers);
+            let (winning, rest) = numbers.split_at(5);
+            let matches = if rng.chance(0.2) {
+                rng.below(6).min(size - 1 - i)
+            } else {
+                0
+            };
+            let mut have: Vec<u8> = winning[..matches].to_vec();
+            have.extend(&rest[..8 - matches]);
+            rng.shuffle(&mut have);
+            let column = |numbers: &[u8]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{:2}", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            input.push_str(&format!(
+                "Card {:3}: {} | {}\n",
+                i + 1,
+                column(winning),
+                column(&have)
+            ));
+        }
+        input
+    }
+
+    fn render(cards: &Self::Input) -> String {
+        let column = |numbers: &[u8]| {
+            numbers
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                format!(
+                    "Card {}: {} | {}\n",
+                    i + 1,
+                    column(&card.winning_numbers),
+                    column(&card.numbers)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day04>(EXAMPLE, 1, 13);
+        assert_example::<Day04>(EXAMPLE, 2, 30);
+    }
+}
@@ -0,0 +1,504 @@
+// part two maps whole ranges of seeds at once, splitting them wherever a map's submaps start or
+// end, instead of going through billions of seeds one by one.
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::ops::Range;
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// each map takes its numbers from the category the one before maps to,
+    /// starting from seeds
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// Every category in the order the maps go through them, from `seed`.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = vec![self.maps[0].source.as_str()];
+        categories.extend(self.maps.iter().map(|map| map.destination.as_str()));
+        categories
+    }
+
+    /// The category the chain ends with, `location` in the puzzle.
+    pub fn last_category(&self) -> &str {
+        &self.maps[self.maps.len() - 1].destination
+    }
+
+    /// The maps that take `from` numbers to `to` numbers, or `None` when
+    /// either isn't a category or `to` comes before `from`.
+    fn chain(&self, from: &str, to: &str) -> Option<&[Map]> {
+        let categories = self.categories();
+        let from = categories.iter().position(|&c| c == from)?;
+        let to = categories.iter().position(|&c| c == to)?;
+        self.maps.get(from..to)
+    }
+
+    /// The `to` number for the `from` number `n`.
+    pub fn map(&self, n: u64, from: &str, to: &str) -> Option<u64> {
+        let chain = self.chain(from, to)?;
+        Some(chain.iter().fold(n, |n, map| map.map(n)))
+    }
+
+    /// Every `from` number that maps to the `to` number `n`, in order, for
+    /// tracing an answer back: `map_back(location, "location", "seed")`.
+    /// There can be none, or more than one when submaps overlap.
+    pub fn map_back(&self, n: u64, from: &str, to: &str) -> Option<Vec<u64>> {
+        let chain = self.chain(to, from)?;
+        let mut numbers = vec![n];
+        for map in chain.iter().rev() {
+            numbers = numbers.iter().flat_map(|&n| map.map_back(n)).collect();
+            numbers.sort_unstable();
+            numbers.dedup();
+        }
+        Some(numbers)
+    }
+
+    /// The seeds read as pairs of a start and a length, which the parser
+    /// made sure come in whole pairs that fit in a u64.
+    pub fn seed_ranges(&self) -> Vec<Range<u64>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .filter(|range| !range.is_empty())
+            .collect()
+    }
+
+    /// Maps `from` ranges to `to` ranges. The ranges that come out don't
+    /// overlap and are in no particular order.
+    pub fn map_ranges(
+        &self,
+        ranges: &[Range<u64>],
+        from: &str,
+        to: &str,
+    ) -> Option<Vec<Range<u64>>> {
+        let mut ranges = ranges.to_vec();
+        for map in self.chain(from, to)? {
+            ranges = map.map_ranges(ranges);
+        }
+        Some(ranges)
+    }
+}
+
+/// Both of its ranges end within a u64, which parsing checks.
+struct Submap {
+    start: u64,
+    source_start: u64,
+    length: u64,
+}
+
+impl Submap {
+    fn contains(&self, n: u64) -> bool {
+        n >= self.source_start && n < self.source_start + self.length
+    }
+
+    fn map(&self, n: u64) -> u64 {
+        self.start + (n - self.source_start)
+    }
+
+    fn source(&self) -> Range<u64> {
+        self.source_start..self.source_start + self.length
+    }
+
+    fn destination(&self) -> Range<u64> {
+        self.start..self.start + self.length
+    }
+}
+
+struct Map {
+    source: String,
+    destination: String,
+    submaps: Vec<Submap>,
+}
+
+impl Map {
+    fn map(&self, n: u64) -> u64 {
+        for submap in &self.submaps {
+            if submap.contains(n) {
+                return submap.map(n);
+            }
+        }
+        n
+    }
+
+    /// Every number `map` takes to `n`: one for each submap that maps to it
+    /// and is the first to contain what it maps from, and `n` itself when no
+    /// submap contains it.
+    fn map_back(&self, n: u64) -> Vec<u64> {
+        let mut numbers: Vec<u64> = Vec::new();
+        for (i, submap) in self.submaps.iter().enumerate() {
+            if !submap.destination().contains(&n) {
+                continue;
+            }
+            let source = n - submap.start + submap.source_start;
+            if self.submaps.iter().position(|s| s.contains(source)) == Some(i) {
+                numbers.push(source);
+            }
+        }
+        if !self.submaps.iter().any(|submap| submap.contains(n)) {
+            numbers.push(n);
+        }
+        numbers
+    }
+
+    /// Every number in `ranges`, mapped, as ranges again. A range is split
+    /// wherever a submap starts or ends inside it, and what no submap covers
+    /// maps to itself.
+    fn map_ranges(&self, mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut mapped: Vec<Range<u64>> = Vec::new();
+        // the first submap to contain a number maps it, like in `map`
+        for submap in &self.submaps {
+            let source = submap.source();
+            let mut unmapped: Vec<Range<u64>> = Vec::new();
+            for range in ranges {
+                let overlap = range.start.max(source.start)..range.end.min(source.end);
+                if overlap.is_empty() {
+                    unmapped.push(range);
+                    continue;
+                }
+                mapped.push(
+                    submap.map(overlap.start)
+                        ..submap.map(overlap.start) + (overlap.end - overlap.start),
+                );
+                if range.start < overlap.start {
+                    unmapped.push(range.start..overlap.start);
+                }
+                if overlap.end < range.end {
+                    unmapped.push(overlap.end..range.end);
+                }
+            }
+            ranges = unmapped;
+        }
+        mapped.extend(ranges);
+        mapped
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    let mut sections = aoc_core::sections(input).into_iter();
+
+    let first = sections
+        .next()
+        .ok_or_else(|| aoc_core::unexpected_end(input, "'seeds:'"))?;
+    let mut line = first[0].clone();
+    line.expect("seeds:")?;
+    let mut seeds: Vec<u64> = Vec::new();
+    line.skip_spaces();
+    while !line.is_done() {
+        let before = line.clone();
+        let seed = line.number()?;
+        // every other number is the length of a range starting at the last
+        if seeds.len() % 2 == 1 && seeds[seeds.len() - 1].checked_add(seed).is_none() {
+            return Err(before.error("a length that keeps the seeds within a u64"));
+        }
+        seeds.push(seed);
+        line.skip_spaces();
+    }
+    if seeds.len() % 2 == 1 {
+        return Err(line.error("a length after the last seed"));
+    }
+    if let Some(extra) = first.get(1) {
+        return Err(extra.error("a blank line"));
+    }
+
+    // as many maps as there are, each going on from where the last one ended
+    let mut maps: Vec<Map> = Vec::new();
+    let mut categories = vec!["seed".to_string()];
+    for lines in sections {
+        let map = get_map(&categories, &lines)?;
+        categories.push(map.destination.clone());
+        maps.push(map);
+    }
+    if maps.is_empty() {
+        return Err(aoc_core::unexpected_end(input, "'seed-to-' and a map"));
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+/// A map from the last of `categories` to one that isn't among them yet.
+fn get_map(categories: &[String], lines: &[Scanner]) -> Result<Map, ParseError> {
+    let mut header = lines[0].clone();
+    let source = &categories[categories.len() - 1];
+    header.expect(source)?;
+    header.expect("-to-")?;
+    let before = header.clone();
+    let destination = header.word()?;
+    if categories.iter().any(|c| c == destination) {
+        return Err(before.error("a category not mapped to yet"));
+    }
+    header.expect(" map:")?;
+    header.end()?;
+
+    let mut submaps: Vec<Submap> = Vec::new();
+    for line in &lines[1..] {
+        let mut line = line.clone();
+        let start: u64 = line.number()?;
+        let source_start: u64 = line.number()?;
+        line.skip_spaces();
+        let before = line.clone();
+        let length = line.number()?;
+        if start.max(source_start).checked_add(length).is_none() {
+            return Err(before.error("a length that keeps both ranges within a u64"));
+        }
+        line.end()?;
+        submaps.push(Submap {
+            start,
+            source_start,
+            length,
+        });
+    }
+    Ok(Map {
+        source: source.clone(),
+        destination: destination.to_string(),
+        submaps,
+    })
+}
+
+pub fn part_one(almanac: &Almanac) -> u64 {
+    let mut lowest: u64 = u64::MAX;
+    for seed in &almanac.seeds {
+        let location = almanac.map(*seed, "seed", almanac.last_category()).unwrap();
+        if location < lowest {
+            lowest = location;
+        }
+    }
+    lowest
+}
+
+pub fn part_two(almanac: &Almanac) -> u64 {
+    let seeds = almanac.seed_ranges();
+    let last = almanac.last_category();
+    let locations = almanac.map_ranges(&seeds, "seed", last).unwrap();
+    aoc_core::info!(
+        "The seeds end up in {} ranges of locations",
+        locations.len()
+    );
+    let lowest = locations
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .unwrap_or(u64::MAX);
+    aoc_core::info!(
+        "Lowest {} {} comes from seeds {:?}",
+        last,
+        lowest,
+        almanac
+            .map_back(lowest, last, "seed")
+            .unwrap()
+            .into_iter()
+            .filter(|seed| seeds.iter().any(|range| range.contains(seed)))
+            .collect::<Vec<u64>>()
+    );
+    lowest
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Input) -> Answer {
+        part_one(almanac).into()
+    }
+
+    fn part2(almanac: &Self::Input) -> Answer {
+        part_two(almanac).into()
+    }
+}
+
+impl Generate for Day05 {
+    /// `size` seed ranges and `size` ranges in each map
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let span = 100 * size as i64;
+        let seeds: Vec<String> = (0..2 * size)
+            .map(|i| match i % 2 {
+                0 => rng.range(0, span),
+                _ => rng.range(1, 100),
+            })
+            .map(|n| n.to_string())
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        let names = [
+            "seed-to-soil",
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ];
+        for name in names {
+            input.push_str(&format!("\n{} map:\n", name));
+            for _ in 0..size {
+                let (start, source_start) = (rng.range(0, span), rng.range(0, span));
+                input.push_str(&format!(
+                    "{} {} {}\n",
+                    start,
+                    source_start,
+                    rng.range(1, 100)
+                ));
+            }
+        }
+        input
+    }
+
+    fn render(almanac: &Self::Input) -> String {
+        let seeds: Vec<String> = almanac.seeds.iter().map(u64::to_string).collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for map in &almanac.maps {
+            input.push_str(&format!("\n{}-to-{} map:\n", map.source, map.destination));
+            for submap in &map.submaps {
+                input.push_str(&format!(
+                    "{} {} {}\n",
+                    submap.start, submap.source_start, submap.length
+                ));
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day05>(EXAMPLE, 1, 35);
+        assert_example::<Day05>(EXAMPLE, 2, 46);
+    }
+
+    #[test]
+    fn test_map_between_categories() {
+        let almanac = parse_input(EXAMPLE).unwrap();
+        assert_eq!(almanac.categories().len(), 8);
+        assert_eq!(almanac.last_category(), "location");
+        assert_eq!(almanac.map(79, "seed", "soil"), Some(81));
+        assert_eq!(almanac.map(81, "soil", "location"), Some(82));
+        assert_eq!(almanac.map(74, "light", "light"), Some(74));
+        assert_eq!(almanac.map(81, "soil", "seed"), None);
+        assert_eq!(almanac.map(81, "soil", "weather"), None);
+        assert_eq!(almanac.map_back(46, "location", "seed"), Some(vec![82]));
+        assert_eq!(almanac.map_back(82, "seed", "location"), None);
+    }
+
+    #[test]
+    fn test_chain_of_any_length() {
+        let almanac = parse_input("seeds: 1 5\n\nseed-to-soil map:\n10 0 3\n").unwrap();
+        assert_eq!(almanac.categories(), ["seed", "soil"]);
+        assert_eq!(part_one(&almanac), 5);
+        assert_eq!(part_two(&almanac), 3);
+
+        let error = |input: &str| parse_input(input).err().map(|e| (e.line, e.column));
+        assert_eq!(
+            error("seeds: 1 1\n\nseed-to-soil map:\n\nwater-to-light map:\n"),
+            Some((5, 1))
+        );
+        assert_eq!(
+            error("seeds: 1 1\n\nseed-to-soil map:\n\nsoil-to-seed map:\n"),
+            Some((5, 9))
+        );
+        assert_eq!(error("seeds: 1 1\n"), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_seeds_in_whole_ranges() {
+        let error = |seeds: &str| {
+            parse_input(&format!("seeds: {}\n\nseed-to-soil map:\n0 1 1\n", seeds))
+                .err()
+                .map(|e| (e.line, e.column, e.expected))
+        };
+        assert_eq!(
+            error("1 2 3"),
+            Some((1, 13, "a length after the last seed".to_string()))
+        );
+        assert_eq!(
+            error(&format!("1 {}", u64::MAX)),
+            Some((
+                1,
+                10,
+                "a length that keeps the seeds within a u64".to_string()
+            ))
+        );
+        assert_eq!(error(&format!("1 {}", u64::MAX - 1)), None);
+    }
+
+    #[test]
+    fn test_submap_contains() {
+        let submap = Submap {
+            start: 50,
+            source_start: 98,
+            length: 2,
+        };
+        assert!(!submap.contains(97));
+        assert!(submap.contains(99));
+        assert!(!submap.contains(100));
+    }
+
+    #[test]
+    fn test_submaps_fit_in_u64() {
+        let input = |submap: String| format!("seeds: 1 1\n\nseed-to-soil map:\n{}\n", submap);
+        let error = |submap| {
+            parse_input(&input(submap))
+                .err()
+                .map(|e| (e.line, e.column))
+        };
+        let max = u64::MAX;
+        assert_eq!(error(format!("0 {} 2", max - 1)), Some((4, 24)));
+        assert_eq!(error(format!("{} 0 2", max)), Some((4, 24)));
+
+        let Ok(almanac) = parse_input(&input(format!("{} 0 2", max - 2))) else {
+            panic!("a submap ending at u64::MAX is valid");
+        };
+        assert_eq!(almanac.maps[0].map(1), max - 1);
+        assert_eq!(almanac.maps[0].map(2), 2);
+    }
+
+    #[test]
+    fn test_map_ranges_splits_at_submaps() {
+        let map = Map {
+            source: "seed".to_string(),
+            destination: "soil".to_string(),
+            submaps: vec![
+                Submap {
+                    start: 50,
+                    source_start: 98,
+                    length: 2,
+                },
+                Submap {
+                    start: 52,
+                    source_start: 50,
+                    length: 48,
+                },
+            ],
+        };
+        let mut mapped = map.map_ranges(vec![40..60, 95..105]);
+        mapped.sort_by_key(|range| range.start);
+        assert_eq!(mapped, [40..50, 50..52, 52..62, 97..100, 100..105]);
+    }
+
+    #[test]
+    fn test_map_ranges_matches_each_seed() {
+        let almanac = parse_input(&Day05::generate(4, &mut Rng::new(7))).unwrap();
+        let seeds = almanac.seed_ranges();
+        for category in almanac.categories() {
+            let mapped = almanac.map_ranges(&seeds, "seed", category).unwrap();
+            let count: u64 = mapped.iter().map(|range| range.end - range.start).sum();
+            let total: u64 = seeds.iter().map(|range| range.end - range.start).sum();
+            assert_eq!(count, total);
+            for seed in seeds.iter().flat_map(|range| range.clone()) {
+                let n = almanac.map(seed, "seed", category).unwrap();
+                assert!(mapped.iter().any(|range| range.contains(&n)));
+                let back = almanac.map_back(n, category, "seed").unwrap();
+                assert!(back.contains(&seed));
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::iter::zip;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// The races of part one, and the one race of part two they make when the
+/// spaces between their numbers are ignored.
+pub struct Races {
+    races: Vec<Race>,
+    joined: Race,
+}
+
+/// Holding the button for `t` goes `t * (time - t)`, so the winning holds
+/// lie between the roots of `t * (time - t) = distance`, symmetric around
+/// half the time.
+fn ways_to_win(race: &Race) -> u64 {
+    let (time, distance) = (race.time as u128, race.distance as u128);
+    let goes = |t: u128| t * (time - t);
+    if time * time <= 4 * distance {
+        return 0;
+    }
+    // the root from the square root rounded down, nudged onto the first win
+    let mut shortest = (time - (time * time - 4 * distance).isqrt()) / 2;
+    while goes(shortest) <= distance {
+        shortest += 1;
+    }
+    while shortest > 0 && goes(shortest - 1) > distance {
+        shortest -= 1;
+    }
+    (time - 2 * shortest + 1) as u64
+}
+
+fn run(races: &[Race]) -> u64 {
+    races.iter().map(ways_to_win).product()
+}
+
+pub fn part_one(races: &[Race]) -> u64 {
+    run(races)
+}
+
+pub fn part_two(race: &Race) -> u64 {
+    run(&[*race])
+}
+
+/// The numbers after `label`, and all of them written as one.
+fn parse_numbers(
+    line: Option<Scanner>,
+    label: &str,
+    input: &str,
+) -> Result<(Vec<u64>, u64), ParseError> {
+    let mut line = line.ok_or_else(|| aoc_core::unexpected_end(input, format!("'{}'", label)))?;
+    line.expect(label)?;
+    line.skip_spaces();
+    let start = line.clone();
+    let numbers: Vec<u64> = line.numbers()?;
+    line.end()?;
+    let joined = numbers
+        .iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse()
+        .map_err(|_| start.error("numbers that still fit in a u64 when put together"))?;
+    Ok((numbers, joined))
+}
+
+pub fn parse_input(input: &str) -> Result<Races, ParseError> {
+    let mut lines = aoc_core::lines(input);
+    let (times, time) = parse_numbers(lines.next(), "Time:", input)?;
+    let mut distance_line = lines.next();
+    let (distances, distance) = parse_numbers(distance_line.clone(), "Distance:", input)?;
+
+    if times.len() != distances.len() {
+        let line = distance_line.as_mut().unwrap();
+        line.until("\n");
+        return Err(line.error(format!("{} distances, one per race", times.len())));
+    }
+    let races = zip(times, distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+    Ok(Races {
+        races,
+        joined: Race { time, distance },
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_one(&input.races).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_two(&input.joined).into()
+    }
+}
+
+impl Generate for Day06 {
+    /// races whose times add up to `size` digits, which is how long the one
+    /// race of part 2 is. Its count goes up tenfold with each, and past 9 its
+    /// distances no longer fit in a u64.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut left = size.clamp(1, 9) as u32;
+        let (mut times, mut distances) = (Vec::new(), Vec::new());
+        while left > 0 {
+            let digits = left.min(rng.range(1, 2) as u32);
+            left -= digits;
+            let time = rng.range(10_i64.pow(digits - 1).max(2), 10_i64.pow(digits) - 1);
+            times.push(time);
+            distances.push(rng.range(0, time * time / 4 - 1));
+        }
+        let width = times
+            .iter()
+            .chain(&distances)
+            .map(|d| d.to_string().len())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let column = |numbers: &[i64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>w$}", n, w = width))
+                .collect::<String>()
+        };
+        format!(
+            "Time:    {}\nDistance:{}\n",
+            column(&times),
+            column(&distances)
+        )
+    }
+
+    /// every way of holding the button tried in turn
+    fn brute_force(races: &Self::Input, part: u8) -> Option<Answer> {
+        let ways = |race: &Race| {
+            (1..race.time)
+                .filter(|&t| t as u128 * (race.time - t) as u128 > race.distance as u128)
+                .count() as u64
+        };
+        let answer = match part {
+            1 => races.races.iter().map(ways).product(),
+            _ => ways(&races.joined),
+        };
+        Some(answer.into())
+    }
+
+    fn render(races: &Self::Input) -> String {
+        let column = |number: fn(&Race) -> u64| {
+            races
+                .races
+                .iter()
+                .map(|race| format!(" {}", number(race)))
+                .collect::<String>()
+        };
+        format!(
+            "Time:{}\nDistance:{}\n",
+            column(|race| race.time),
+            column(|race| race.distance)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day06>(EXAMPLE, 1, 288);
+        assert_example::<Day06>(EXAMPLE, 2, 71503);
+        let races = parse_input(EXAMPLE).unwrap();
+        assert_eq!(Day06::brute_force(&races, 1), Some(Answer::from(288)));
+        assert_eq!(Day06::brute_force(&races, 2), Some(Answer::from(71503)));
+    }
+
+    #[test]
+    fn test_joined_numbers_must_fit() {
+        let error = parse_input("Time: 9999999999 9999999999\nDistance: 1 1\n")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 7));
+        let races = parse_input("Time: 7 15\nDistance: 9 40\n").unwrap();
+        assert_eq!((races.joined.time, races.joined.distance), (715, 940));
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::{collections::HashMap, iter::zip};
+
+const HAND_SIZE: usize = 5;
+const CARDS: &str = "AKQJT98765432";
+
+#[derive(Clone, Debug)]
+enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Clone, Debug)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: u32,
+    type_: HandType,
+    jokers: bool,
+}
+
+impl Hand {
+    fn from_line(line: &mut Scanner) -> Result<Hand, ParseError> {
+        let mut cards = Vec::new();
+        for _ in 0..HAND_SIZE {
+            cards.push(line.char_as("a card", |c| CARDS.contains(c).then_some(c))?);
+        }
+        let bid = line.number()?;
+        line.end()?;
+
+        let mut map: HashMap<char, u8> = HashMap::new();
+        for &card in &cards {
+            let mut count = match map.get(&card) {
+                Some(n) => *n,
+                None => 0,
+            };
+            count += 1;
+            map.insert(card, count);
+        }
+        let mut counts: Vec<u8> = map.values().cloned().collect();
+        counts.sort_by(|a, b| b.cmp(a));
+
+        let type_ = match counts[0] {
+            5 => HandType::FiveOfAKind,
+            4 => HandType::FourOfAKind,
+            3 => match counts[1] {
+                2 => HandType::FullHouse,
+                _ => HandType::ThreeOfAKind,
+            },
+            2 => match counts[1] {
+                2 => HandType::TwoPair,
+                _ => HandType::OnePair,
+            },
+            _ => HandType::HighCard,
+        };
+
+        Ok(Hand {
+            cards,
+            bid,
+            type_,
+            jokers: false,
+        })
+    }
+
+    pub fn use_jokers(&mut self) {
+        let joker_count = self.cards.iter().filter(|&x| *x == 'J').count();
+        if joker_count == 0 || self.jokers {
+            return;
+        }
+        match self.type_ {
+            HandType::FourOfAKind => self.type_ = HandType::FiveOfAKind,
+            HandType::FullHouse => self.type_ = HandType::FiveOfAKind,
+            HandType::ThreeOfAKind => self.type_ = HandType::FourOfAKind,
+            HandType::TwoPair => {
+                match joker_count {
+                    1 => self.type_ = HandType::FullHouse,
+                    2 => self.type_ = HandType::FourOfAKind,
+                    _ => unreachable!(),
+                };
+            }
+            HandType::OnePair => self.type_ = HandType::ThreeOfAKind,
+            HandType::HighCard => self.type_ = HandType::OnePair,
+            _ => {}
+        };
+        self.jokers = true;
+    }
+
+    fn get_rank(&self) -> u8 {
+        match self.type_ {
+            HandType::FiveOfAKind => 6,
+            HandType::FourOfAKind => 5,
+            HandType::FullHouse => 4,
+            HandType::ThreeOfAKind => 3,
+            HandType::TwoPair => 2,
+            HandType::OnePair => 1,
+            HandType::HighCard => 0,
+        }
+    }
+
+    fn is_larger_than(&self, other: &Hand) -> bool {
+        if self.get_rank() > other.get_rank() {
+            return true;
+        } else if other.get_rank() > self.get_rank() {
+            return false;
+        }
+        for (a, b) in zip(&self.cards, &other.cards) {
+            let rank_a = get_card_rank(*a, self.jokers);
+            let rank_b = get_card_rank(*b, self.jokers);
+            if rank_a > rank_b {
+                return true;
+            } else if rank_b > rank_a {
+                return false;
+            }
+        }
+        false
+    }
+}
+
+fn get_card_rank(card: char, jokers: bool) -> u8 {
+    match card {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => match jokers {
+            true => 1,
+            false => 11,
+        },
+        'T' => 10,
+        _ => card.to_digit(10).unwrap() as u8,
+    }
+}
+
+fn sort_hands(hands: &mut [Hand]) {
+    for i in 0..hands.len() {
+        for j in (i + 1)..hands.len() {
+            if hands[i].is_larger_than(&hands[j]) {
+                let tmp = hands[i].clone();
+                hands[i] = hands[j].clone();
+                hands[j] = tmp;
+            }
+        }
+    }
+}
+
+pub fn solve(hands: &mut [Hand]) -> u32 {
+    sort_hands(hands);
+
+    let mut winnings = 0;
+    for (i, hand) in hands.iter().enumerate() {
+        winnings += hand.bid * (i as u32 + 1);
+    }
+    winnings
+}
+
+pub fn parse_hands(input: &str) -> Result<Vec<Hand>, ParseError> {
+    let mut hands: Vec<Hand> = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        hands.push(Hand::from_line(&mut line)?);
+    }
+    Ok(hands)
+}
+
+pub fn part1(hands: &[Hand]) -> u32 {
+    let mut hands = hands.to_vec();
+    solve(&mut hands)
+}
+
+pub fn part2(hands: &[Hand]) -> u32 {
+    let mut hands = hands.to_vec();
+    for hand in &mut hands {
+        hand.use_jokers();
+    }
+    solve(&mut hands)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_hands(input)
+    }
+
+    fn part1(hands: &Self::Input) -> Answer {
+        part1(hands).into()
+    }
+
+    fn part2(hands: &Self::Input) -> Answer {
+        part2(hands).into()
+    }
+}
+
+impl Generate for Day07 {
+    /// `size` hands
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let cards: Vec<char> = CARDS.chars().collect();
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..HAND_SIZE {
+                input.push(*rng.pick(&cards));
+            }
+            input.push_str(&format!(" {}\n", rng.range(1, 1000)));
+        }
+        input
+    }
+
+    fn render(hands: &Self::Input) -> String {
+        hands
+            .iter()
+            .map(|hand| format!("{} {}\n", hand.cards.iter().collect::<String>(), hand.bid))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day07>(EXAMPLE, 1, 6440);
+        assert_example::<Day07>(EXAMPLE, 2, 5905);
+    }
+}
@@ -0,0 +1,258 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use aoc_math::lcm_all;
+use std::collections::HashMap;
+
+const START: [char; 3] = ['A', 'A', 'A'];
+const END: [char; 3] = ['Z', 'Z', 'Z'];
+
+pub type Node = [char; 3];
+pub type Network = HashMap<Node, (Node, Node)>;
+
+fn parse_node(line: &mut Scanner) -> Result<Node, ParseError> {
+    let at = line.clone();
+    let name = line.word()?;
+    let chars: Vec<char> = name.chars().collect();
+    chars
+        .try_into()
+        .map_err(|_| at.error("a node name of 3 characters"))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<char>, Network), ParseError> {
+    let [instruction_lines, node_lines] = aoc_core::two_sections(input, "the network")?;
+    if let Some(extra) = instruction_lines.get(1) {
+        return Err(extra.error("a blank line"));
+    }
+    let mut first = instruction_lines[0].clone();
+    let mut instructions: Vec<char> = Vec::new();
+    while !first.is_done() {
+        instructions.push(first.char_as("'L' or 'R'", |c| "LR".contains(c).then_some(c))?);
+    }
+
+    let mut graph: Network = HashMap::new();
+    // where each node leads, to check once they're all defined
+    let mut targets: Vec<(Scanner, Node)> = Vec::new();
+    for mut line in node_lines {
+        let start = parse_node(&mut line)?;
+        line.expect(" = (")?;
+        let left_at = line.clone();
+        let left = parse_node(&mut line)?;
+        line.expect(", ")?;
+        let right_at = line.clone();
+        let right = parse_node(&mut line)?;
+        line.expect(")")?;
+        line.end()?;
+        graph.insert(start, (left, right));
+        targets.extend([(left_at, left), (right_at, right)]);
+    }
+    for (mut at, target) in targets {
+        if !graph.contains_key(&target) {
+            at.skip_spaces();
+            return Err(at.error("a node the network defines"));
+        }
+    }
+
+    Ok((instructions, graph))
+}
+
+/// The steps from `AAA` to `ZZZ`, `None` for a network without an `AAA`.
+pub fn part_one(instructions: &[char], graph: &Network) -> Option<usize> {
+    let total_instructions = instructions.len();
+    let mut instruction_idx = 0;
+    let mut steps = 0;
+    let mut current = START;
+    while current != END {
+        let instruction = instructions[instruction_idx];
+        let (left, right) = graph.get(&current)?;
+        current = match instruction {
+            'L' => *left,
+            'R' => *right,
+            _ => unreachable!(),
+        };
+        steps += 1;
+
+        instruction_idx += 1;
+        if instruction_idx >= total_instructions {
+            instruction_idx = 0;
+        }
+    }
+
+    Some(steps)
+}
+
+pub fn part_two(instructions: &[char], graph: &Network) -> usize {
+    let starting_nodes: Vec<Node> = graph.keys().cloned().filter(|x| x[2] == 'A').collect();
+
+    let total_instructions = instructions.len();
+
+    let mut min_steps_per_node = Vec::new();
+    for node in &starting_nodes {
+        let mut instruction_idx = 0;
+        let mut steps = 0;
+        let mut current = *node;
+        while current[2] != 'Z' {
+            let instruction = instructions[instruction_idx];
+            current = match instruction {
+                'L' => graph.get(&current).unwrap().0,
+                'R' => graph.get(&current).unwrap().1,
+                _ => unreachable!(),
+            };
+            steps += 1;
+
+            instruction_idx += 1;
+            if instruction_idx >= total_instructions {
+                instruction_idx = 0;
+            }
+        }
+        min_steps_per_node.push(steps);
+    }
+    lcm_all(min_steps_per_node)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<char>, Network);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (instructions, graph) = input;
+        part_one(instructions, graph).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (instructions, graph) = input;
+        part_two(instructions, graph).into()
+    }
+}
+
+impl Generate for Day08 {
+    /// `size` instructions (up to 400) and a few ghosts, each going round a
+    /// loop of whole passes over them that ends on its `Z` node
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let passes_per_ghost = [2, 3, 5, 7];
+        let instructions: String = (0..size.clamp(1, 400))
+            .map(|_| *rng.pick(&['L', 'R']))
+            .collect();
+        let letter =
+            |rng: &mut Rng, from: u8, count: usize| char::from(from + rng.below(count) as u8);
+        let mut names: Vec<Node> = Vec::new();
+        let mut lines = Vec::new();
+        for (ghost, passes) in passes_per_ghost[..rng.range(1, 4) as usize]
+            .iter()
+            .enumerate()
+        {
+            let prefix = match ghost {
+                0 => ['A', 'A'],
+                _ => loop {
+                    let prefix = [letter(rng, b'A', 26), letter(rng, b'A', 26)];
+                    if prefix != ['A', 'A'] && !names.iter().any(|n| n[..2] == prefix) {
+                        break prefix;
+                    }
+                },
+            };
+            let start = [prefix[0], prefix[1], 'A'];
+            let end = match ghost {
+                0 => END,
+                _ => [prefix[0], prefix[1], 'Z'],
+            };
+            let mut path = vec![start];
+            names.push(start);
+            for _ in 1..passes * instructions.len() {
+                let node = loop {
+                    let node = [
+                        letter(rng, b'A', 26),
+                        letter(rng, b'A', 26),
+                        letter(rng, b'B', 24),
+                    ];
+                    if !names.contains(&node) {
+                        break node;
+                    }
+                };
+                names.push(node);
+                path.push(node);
+            }
+            path.push(end);
+            names.push(end);
+            for (i, turn) in instructions
+                .chars()
+                .cycle()
+                .take(path.len() - 1)
+                .enumerate()
+            {
+                let (taken, decoy) = (path[i + 1], *rng.pick(&path));
+                let (left, right) = if turn == 'L' {
+                    (taken, decoy)
+                } else {
+                    (decoy, taken)
+                };
+                lines.push((path[i], left, right));
+            }
+            // the end leads on like the start did, so the loop goes round again
+            let (_, left, right) = lines[lines.len() + 1 - path.len()];
+            lines.push((end, left, right));
+        }
+        rng.shuffle(&mut lines);
+        let name = |node: &Node| node.iter().collect::<String>();
+        let mut input = format!("{}\n\n", instructions);
+        for (node, left, right) in &lines {
+            input.push_str(&format!(
+                "{} = ({}, {})\n",
+                name(node),
+                name(left),
+                name(right)
+            ));
+        }
+        input
+    }
+
+    /// the nodes sorted by name
+    fn render(input: &Self::Input) -> String {
+        let (instructions, graph) = input;
+        let name = |node: &Node| node.iter().collect::<String>();
+        let mut nodes: Vec<(&Node, &(Node, Node))> = graph.iter().collect();
+        nodes.sort();
+        let mut text: String = instructions.iter().collect();
+        text.push_str("\n\n");
+        for (node, (left, right)) in nodes {
+            text.push_str(&format!(
+                "{} = ({}, {})\n",
+                name(node),
+                name(left),
+                name(right)
+            ));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+    const EXAMPLE_3: &str = include_str!("../example_3.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day08>(EXAMPLE, 1, 2);
+        assert_example::<Day08>(EXAMPLE_2, 1, 6);
+        assert_example::<Day08>(EXAMPLE_3, 2, 6);
+    }
+
+    #[test]
+    fn test_network_must_be_whole() {
+        let error = parse_input("L\n\nAAA = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 13));
+        let error = parse_input("L\nR\n\nAAA = (AAA, AAA)\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(parse_input("LR\n").is_err());
+
+        let (instructions, graph) = parse_input("L\n\nBBZ = (BBZ, BBZ)\n").unwrap();
+        assert_eq!(part_one(&instructions, &graph), None);
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+pub fn parse_entries(input: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    let mut entries: Vec<Vec<isize>> = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        let parsed: Vec<isize> = line.numbers()?;
+        if parsed.is_empty() {
+            return Err(line.error("a number"));
+        }
+        line.end()?;
+        entries.push(parsed);
+    }
+    Ok(entries)
+}
+
+pub fn history(entry: &[isize]) -> Vec<Vec<isize>> {
+    let mut history = vec![entry.to_vec()];
+    while !history.last().unwrap().iter().all(|x| *x == 0) {
+        let last = history.last().unwrap();
+        let mut differences: Vec<isize> = Vec::new();
+        for i in 0..(last.len() - 1) {
+            differences.push(last[i + 1] - last[i])
+        }
+        history.push(differences);
+    }
+    history
+}
+
+pub fn part_one(entries: &[Vec<isize>]) -> isize {
+    let mut rsum = 0;
+    for entry in entries {
+        let history = history(entry);
+        let mut rdiff = 0;
+        for i in (0..history.len() - 1).rev() {
+            rdiff += history[i].last().unwrap();
+        }
+        rsum += rdiff;
+    }
+    rsum
+}
+
+pub fn part_two(entries: &[Vec<isize>]) -> isize {
+    let mut lsum = 0;
+    for entry in entries {
+        let history = history(entry);
+        let mut ldiff = 0;
+        for i in (0..history.len() - 1).rev() {
+            ldiff = history[i][0] - ldiff;
+        }
+        lsum += ldiff;
+    }
+    lsum
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_entries(input)
+    }
+
+    fn part1(entries: &Self::Input) -> Answer {
+        part_one(entries).into()
+    }
+
+    fn part2(entries: &Self::Input) -> Answer {
+        part_two(entries).into()
+    }
+}
+
+impl Generate for Day09 {
+    /// `size` histories of 21 values, each a polynomial of degree up to 5
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut values = vec![rng.range(-5, 5); 21];
+            for _ in 0..rng.range(0, 5) {
+                let mut integrated = vec![rng.range(-10, 10)];
+                for (i, difference) in values[..20].iter().enumerate() {
+                    integrated.push(integrated[i] + difference);
+                }
+                values = integrated;
+            }
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            input.push_str(&values.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+
+    /// The differences taken all the way down extend the one polynomial
+    /// through every value, so the next value is theirs weighted by a row
+    /// of Pascal's triangle with alternating signs, and the one before
+    /// likewise.
+    fn brute_force(entries: &Self::Input, part: u8) -> Option<Answer> {
+        let extrapolate = |entry: &Vec<isize>| -> i128 {
+            let n = entry.len();
+            let mut binomial = 1i128;
+            let mut total = 0;
+            for (i, &value) in entry.iter().enumerate() {
+                // C(n, i) for the next value, C(n, i + 1) for the previous
+                let (weight, sign) = match part {
+                    1 => (binomial, if (n - 1 - i).is_multiple_of(2) { 1 } else { -1 }),
+                    _ => (
+                        binomial * (n - i) as i128 / (i + 1) as i128,
+                        if i.is_multiple_of(2) { 1 } else { -1 },
+                    ),
+                };
+                total += sign * weight * value as i128;
+                binomial = binomial * (n - i) as i128 / (i + 1) as i128;
+            }
+            total
+        };
+        let answer: i128 = entries.iter().map(extrapolate).sum();
+        Some(i64::try_from(answer).map_or(Answer::None, Answer::from))
+    }
+
+    fn render(entries: &Self::Input) -> String {
+        entries
+            .iter()
+            .map(|entry| {
+                let numbers: Vec<String> = entry.iter().map(isize::to_string).collect();
+                format!("{}\n", numbers.join(" "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day09>(EXAMPLE, 1, 114);
+        assert_example::<Day09>(EXAMPLE, 2, 2);
+        let entries = parse_entries(EXAMPLE).unwrap();
+        assert_eq!(Day09::brute_force(&entries, 1), Some(Answer::from(114)));
+        assert_eq!(Day09::brute_force(&entries, 2), Some(Answer::from(2)));
+    }
+}
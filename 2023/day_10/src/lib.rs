@@ -0,0 +1,230 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug, info};
+use aoc_grid::{Direction, Grid, Position};
+use aoc_math::{interior_points, shoelace};
+
+pub struct Sketch {
+    tiles: Grid<char>,
+    start: Position,
+}
+
+impl Sketch {
+    pub fn from_text(text: &str) -> Result<Sketch, ParseError> {
+        let tiles = Grid::parse(text, "a pipe, '.' or 'S'", |c| {
+            "|-LJ7F.S".contains(c).then_some(c)
+        })?;
+        let start = tiles
+            .find(|&c| c == 'S')
+            .ok_or_else(|| aoc_core::unexpected_end(text, "a start tile 'S'"))?;
+        Ok(Sketch { tiles, start })
+    }
+
+    fn next(&self, pos: Position, from: Direction) -> Option<Direction> {
+        let tile = self.tiles[pos];
+
+        match tile {
+            '.' => None,
+            '|' => match from {
+                Direction::North => Some(Direction::South),
+                Direction::South => Some(Direction::North),
+                _ => None,
+            },
+            '-' => match from {
+                Direction::East => Some(Direction::West),
+                Direction::West => Some(Direction::East),
+                _ => None,
+            },
+            'J' => match from {
+                Direction::North => Some(Direction::West),
+                Direction::West => Some(Direction::North),
+                _ => None,
+            },
+            'L' => match from {
+                Direction::North => Some(Direction::East),
+                Direction::East => Some(Direction::North),
+                _ => None,
+            },
+            'F' => match from {
+                Direction::South => Some(Direction::East),
+                Direction::East => Some(Direction::South),
+                _ => None,
+            },
+            '7' => match from {
+                Direction::South => Some(Direction::West),
+                Direction::West => Some(Direction::South),
+                _ => None,
+            },
+            'S' => None,
+            _ => panic!("Unrecognized tile"),
+        }
+    }
+
+    fn find_first_direction(&self) -> Option<Direction> {
+        for dir in Direction::ALL {
+            if let Some(next) = self.tiles.step(self.start, dir)
+                && self.next(next, dir.opposite()).is_some()
+            {
+                return Some(dir);
+            }
+        }
+        None
+    }
+}
+
+/// walks the loop starting at `S`, returning its vertices and its length,
+/// or `None` if the pipes from `S` don't lead back to it
+pub fn find_loop(sketch: &Sketch) -> Option<(Vec<Position>, usize)> {
+    let to = sketch.find_first_direction()?;
+    let mut position = sketch.tiles.step(sketch.start, to)?;
+    let mut from = to.opposite();
+
+    let mut polygon: Vec<Position> = vec![sketch.start, position];
+
+    let mut steps = 1;
+
+    while sketch.tiles[position] != 'S' {
+        let to = sketch.next(position, from)?;
+        position = sketch.tiles.step(position, to)?;
+        polygon.push(position);
+        from = to.opposite();
+        steps += 1;
+    }
+    Some((polygon, steps))
+}
+
+/// furthest point from the start along the loop
+pub fn part1(sketch: &Sketch) -> Option<usize> {
+    debug!("{}Start: {:?}", sketch.tiles, sketch.start);
+
+    let (_, steps) = find_loop(sketch)?;
+    info!("Total loop length: {}", steps);
+    Some(steps / 2)
+}
+
+/// points enclosed by the loop
+pub fn part2(sketch: &Sketch) -> Option<usize> {
+    let (polygon, steps) = find_loop(sketch)?;
+
+    let vertices: Vec<(i64, i64)> = polygon.iter().map(|p| (p.x as i64, p.y as i64)).collect();
+    // every tile of the loop is a lattice point on the polygon's boundary
+    Some(interior_points(shoelace(&vertices), steps as i64) as usize)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sketch;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Sketch::from_text(input)
+    }
+
+    fn part1(sketch: &Self::Input) -> Answer {
+        part1(sketch).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(sketch: &Self::Input) -> Answer {
+        part2(sketch).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day10 {
+    /// a loop around a blob about `size` cells high and wide, in a field of
+    /// loose pipes
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        // rows of cells, each overlapping the one above so the blob has no
+        // holes or pinches and its outline is a single loop
+        let (width, height) = (size.max(1), size.max(1));
+        let mut rows: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..height {
+            let row = loop {
+                let left = rng.below(width);
+                let right = left + rng.below(width - left);
+                match rows.last() {
+                    Some(&(l, r)) if right < l || left > r => continue,
+                    _ => break (left, right),
+                }
+            };
+            rows.push(row);
+        }
+        let inside = |x: isize, y: isize| {
+            y >= 0
+                && (y as usize) < height
+                && rows[y as usize].0 as isize <= x
+                && x <= rows[y as usize].1 as isize
+        };
+        // corners of cells are at even positions, the edges between them odd
+        let on_edge = |x: isize, y: isize| match (x % 2 == 0, y % 2 == 0) {
+            _ if x < 0 || y < 0 => false,
+            (false, true) => inside(x / 2, y / 2 - 1) != inside(x / 2, y / 2),
+            (true, false) => inside(x / 2 - 1, y / 2) != inside(x / 2, y / 2),
+            _ => false,
+        };
+        let mut tiles = Grid::new(2 * width + 1, 2 * height + 1, '.');
+        let mut loop_tiles = Vec::new();
+        for pos in tiles.positions() {
+            let (x, y) = (pos.x as isize, pos.y as isize);
+            let tile = match (x % 2 == 0, y % 2 == 0) {
+                (true, true) => match (
+                    on_edge(x, y - 1),
+                    on_edge(x, y + 1),
+                    on_edge(x + 1, y),
+                    on_edge(x - 1, y),
+                ) {
+                    (true, false, true, false) => 'L',
+                    (true, false, false, true) => 'J',
+                    (false, true, false, true) => '7',
+                    (false, true, true, false) => 'F',
+                    (true, true, false, false) => '|',
+                    (false, false, true, true) => '-',
+                    _ => '.',
+                },
+                (false, true) if on_edge(x, y) => '-',
+                (true, false) if on_edge(x, y) => '|',
+                _ => '.',
+            };
+            if tile != '.' {
+                loop_tiles.push(pos);
+            }
+            tiles[pos] = tile;
+        }
+        let start = *rng.pick(&loop_tiles);
+        tiles[start] = 'S';
+        // loose pipes, none of them next to the start where they could be
+        // mistaken for the loop
+        for pos in tiles.positions() {
+            let near_start = pos.x.abs_diff(start.x) + pos.y.abs_diff(start.y) == 1;
+            if tiles[pos] == '.' && !near_start && rng.chance(0.5) {
+                tiles[pos] = *rng.pick(&['|', '-', 'L', 'J', '7', 'F']);
+            }
+        }
+        tiles.render(|_, &tile| tile)
+    }
+
+    fn render(sketch: &Self::Input) -> String {
+        sketch.tiles.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day10>(EXAMPLE, 1, 8);
+        assert_example::<Day10>(EXAMPLE_2, 2, 4);
+    }
+
+    #[test]
+    fn test_start_must_be_on_a_loop() {
+        for text in ["S\n", "S-\n", ".F-\n-S|\n.L-\n"] {
+            let sketch = Day10::parse(text).unwrap();
+            assert_eq!(Day10::part1(&sketch), Answer::None);
+            assert_eq!(Day10::part2(&sketch), Answer::None);
+        }
+    }
+}
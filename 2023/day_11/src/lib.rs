@@ -0,0 +1,175 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug};
+use aoc_grid::Grid;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+#[derive(Clone, Debug)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    data: Vec<Vec<char>>,
+    empty_rows: HashSet<usize>,
+    empty_cols: HashSet<usize>,
+}
+
+impl Map {
+    pub fn from_text(text: &str) -> Result<Map, ParseError> {
+        let grid = Grid::parse(text, "'.' or '#'", |c| ".#".contains(c).then_some(c))?;
+        let mut data: Vec<Vec<char>> = Vec::new();
+        let mut empty_rows: HashSet<usize> = HashSet::new();
+        let mut empty_cols: HashSet<usize> = HashSet::new();
+        for (y, row) in grid.rows().enumerate() {
+            let chars: Vec<char> = row.to_vec();
+            if chars.iter().all(|c| *c == '.') {
+                empty_rows.insert(y);
+            }
+            data.push(chars);
+        }
+
+        for x in 0..grid.width() {
+            let col: Vec<char> = data.iter().map(|r| r[x]).collect();
+            if col.iter().all(|c| *c == '.') {
+                empty_cols.insert(x);
+            }
+        }
+        Ok(Map {
+            data,
+            empty_rows,
+            empty_cols,
+        })
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for row in &self.data {
+            text.extend(row);
+            text.push('\n');
+        }
+        text
+    }
+
+    fn get_galaxy_coords(&self) -> Vec<Coord> {
+        let mut coords: Vec<Coord> = Vec::new();
+        for (y, row) in self.data.iter().enumerate() {
+            for (x, val) in row.iter().enumerate() {
+                if *val == '#' {
+                    coords.push(Coord { x, y });
+                }
+            }
+        }
+        coords
+    }
+
+    fn get_galaxy_pairs(&self) -> Vec<(Coord, Coord)> {
+        let coords = self.get_galaxy_coords();
+
+        let n_galaxies = coords.len();
+        let mut pairs: Vec<(Coord, Coord)> = Vec::new();
+        for i in 0..n_galaxies {
+            for j in (i + 1)..n_galaxies {
+                pairs.push((coords[i].clone(), coords[j].clone()))
+            }
+        }
+        pairs
+    }
+}
+
+pub fn solve(map: &Map, expansion_factor: usize) -> usize {
+    let pairs = map.get_galaxy_pairs();
+    let mut total_distance = 0;
+    for pair in pairs {
+        let x_min = min(pair.0.x, pair.1.x);
+        let x_max = max(pair.0.x, pair.1.x);
+        let y_min = min(pair.0.y, pair.1.y);
+        let y_max = max(pair.0.y, pair.1.y);
+
+        let mut jumps = 0;
+        for row in map.empty_rows.iter() {
+            if y_min < *row && *row < y_max {
+                jumps += 1;
+            }
+        }
+        for col in map.empty_cols.iter() {
+            if x_min < *col && *col < x_max {
+                jumps += 1;
+            }
+        }
+        total_distance += pair.0.x.abs_diff(pair.1.x);
+        total_distance += pair.0.y.abs_diff(pair.1.y);
+        total_distance += jumps * (expansion_factor - 1);
+    }
+    total_distance
+}
+
+pub fn part1(map: &Map) -> usize {
+    debug!("Initial map:\n{}", map.render());
+    debug!("Empty rows: {:?}", map.empty_rows);
+    debug!("Empty cols: {:?}", map.empty_cols);
+    solve(map, 2)
+}
+
+pub fn part2(map: &Map) -> usize {
+    solve(map, 1000000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_text(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part2(map).into()
+    }
+}
+
+impl Generate for Day11 {
+    /// a `size` by `size` image, with some rows and columns left empty
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+        let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.2)).collect();
+        let mut image = Grid::new(size, size, '.');
+        for pos in image.positions() {
+            if !empty_rows[pos.y] && !empty_cols[pos.x] && rng.chance(0.1) {
+                image[pos] = '#';
+            }
+        }
+        image.render(|_, &c| c)
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.render()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day11>(EXAMPLE, 1, 374);
+    }
+
+    #[test]
+    fn test_expansion_factors() {
+        let map = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&map, 10), 1030);
+        assert_eq!(solve(&map, 100), 8410);
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct Record {
+    springs: Vec<char>,
+    groups: Vec<u8>,
+}
+
+impl Record {
+    pub fn from_text(text: &str) -> Result<Vec<Record>, ParseError> {
+        let mut springs: Vec<Record> = Vec::new();
+        for mut line in aoc_core::lines(text) {
+            let mut data: Vec<char> = Vec::new();
+            while !line.rest().starts_with(' ') {
+                data.push(line.char_as("'.', '#' or '?'", |c| ".#?".contains(c).then_some(c))?);
+            }
+            let groups: Vec<u8> = line.separated(",", |s| s.number())?;
+            line.end()?;
+
+            let field = Record {
+                springs: data,
+                groups,
+            };
+            springs.push(field);
+        }
+        Ok(springs)
+    }
+
+    pub fn unfold(&self, by: usize) -> Record {
+        let mut spring_clones: Vec<Vec<char>> = Vec::new();
+        let mut groups: Vec<u8> = Vec::new();
+        for _ in 0..by {
+            spring_clones.push(self.springs.clone());
+            groups.extend(self.groups.clone());
+        }
+        Record {
+            springs: spring_clones.join(&'?'),
+            groups,
+        }
+    }
+
+    pub fn arrangements(&self) -> usize {
+        Record::_arrangements(
+            self.springs.clone(),
+            self.groups.clone(),
+            &mut HashMap::new(),
+        )
+    }
+
+    fn _arrangements(
+        springs: Vec<char>,
+        groups: Vec<u8>,
+        memo: &mut HashMap<(Vec<char>, Vec<u8>), usize>,
+    ) -> usize {
+        let result = if groups.is_empty() {
+            if springs.is_empty() || springs.iter().all(|&x| x != '#') {
+                return 1;
+            }
+            return 0;
+        } else if springs.is_empty() {
+            return 0;
+        } else if let Some(&cached) = memo.get(&(springs.clone(), groups.clone())) {
+            return cached;
+        } else {
+            match springs[0] {
+                '.' => Record::_arrangements(springs[1..].to_vec(), groups.clone(), memo),
+                '#' => {
+                    let needed = groups[0] as usize;
+
+                    if springs.len() < needed || springs[..needed].contains(&'.') {
+                        return 0;
+                    }
+                    if springs.len() == needed {
+                        if groups.len() == 1 {
+                            return 1;
+                        }
+                        return 0;
+                    }
+                    if springs[needed] == '#' {
+                        return 0;
+                    }
+                    Record::_arrangements(
+                        springs[needed + 1..].to_vec(),
+                        groups[1..].to_vec(),
+                        memo,
+                    )
+                }
+                '?' => {
+                    let mut with_dmg = springs.clone();
+                    with_dmg[0] = '#';
+                    let mut with_dot = springs.clone();
+                    with_dot[0] = '.';
+                    Self::_arrangements(with_dmg, groups.clone(), memo)
+                        + Self::_arrangements(with_dot, groups.clone(), memo)
+                }
+                _ => unreachable!(),
+            }
+        };
+        memo.insert((springs, groups), result);
+        result
+    }
+}
+
+pub fn part1(records: &[Record]) -> usize {
+    let mut sum = 0;
+    for record in records {
+        let arrangements = record.arrangements();
+        sum += arrangements;
+    }
+    sum
+}
+
+pub fn part2(records: &[Record]) -> usize {
+    let mut sum = 0;
+    for record in records {
+        let record = record.unfold(5);
+        let arrangements = record.arrangements();
+        sum += arrangements;
+    }
+    sum
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Record::from_text(input)
+    }
+
+    fn part1(records: &Self::Input) -> Answer {
+        part1(records).into()
+    }
+
+    fn part2(records: &Self::Input) -> Answer {
+        part2(records).into()
+    }
+}
+
+impl Generate for Day12 {
+    /// `size` records of up to 20 springs
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut springs = ".".repeat(rng.below(3));
+            let mut groups = Vec::new();
+            loop {
+                let group = rng.range(1, 4) as usize;
+                springs.push_str(&"#".repeat(group));
+                groups.push(group.to_string());
+                if springs.len() > 14 || rng.chance(0.3) {
+                    break;
+                }
+                springs.push_str(&".".repeat(rng.range(1, 2) as usize));
+            }
+            springs.push_str(&".".repeat(rng.below(3)));
+            let springs: String = springs
+                .chars()
+                .map(|c| if rng.chance(0.5) { '?' } else { c })
+                .collect();
+            input.push_str(&format!("{} {}\n", springs, groups.join(",")));
+        }
+        input
+    }
+
+    fn render(records: &Self::Input) -> String {
+        records
+            .iter()
+            .map(|record| {
+                let groups: Vec<String> = record.groups.iter().map(u8::to_string).collect();
+                format!(
+                    "{} {}\n",
+                    record.springs.iter().collect::<String>(),
+                    groups.join(",")
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day12>(EXAMPLE, 1, 21);
+        assert_example::<Day12>(EXAMPLE, 2, 525152);
+    }
+}
@@ -0,0 +1,226 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug};
+use aoc_grid::Grid;
+use std::cmp::min;
+
+#[derive(Clone, Debug)]
+struct Pattern {
+    blocks: Vec<char>,
+}
+
+impl Pattern {
+    fn matches(&self, other: &Pattern) -> bool {
+        let length = self.blocks.len();
+        if length != other.blocks.len() {
+            return false;
+        }
+        for i in 0..length {
+            if self.blocks[i] != other.blocks[i] {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Mirror {
+    patterns: Vec<Pattern>,
+}
+
+impl Mirror {
+    fn find_reflection_rows(&self) -> Vec<usize> {
+        Mirror::_find_reflection_idx(&self.patterns)
+    }
+
+    fn find_reflection_cols(&self) -> Vec<usize> {
+        // rearrange into columns
+        let height = self.patterns.len();
+        let mut columns: Vec<Pattern> = Vec::new();
+
+        // assume width is constant so we take the first row
+        for i in 0..self.patterns[0].blocks.len() {
+            let blocks: Vec<char> = (0..height).map(|j| self.patterns[j].blocks[i]).collect();
+            columns.push(Pattern { blocks })
+        }
+
+        Mirror::_find_reflection_idx(&columns)
+    }
+
+    fn _find_reflection_idx(patterns: &[Pattern]) -> Vec<usize> {
+        let height = patterns.len();
+        let mut indexes = Vec::new();
+        'outer: for i in 0..(height - 1) {
+            let reflection_size = min(i + 1, height - i - 1);
+            for j in 0..reflection_size {
+                if !patterns[i - j].matches(&patterns[i + 1 + j]) {
+                    continue 'outer;
+                }
+            }
+            indexes.push(i);
+        }
+        indexes
+    }
+
+    fn toggle(&mut self, x: usize, y: usize) {
+        match self.patterns[y].blocks[x] {
+            '.' => self.patterns[y].blocks[x] = '#',
+            '#' => self.patterns[y].blocks[x] = '.',
+            _ => unreachable!(),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for pattern in &self.patterns {
+            text.extend(&pattern.blocks);
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[derive(Debug)]
+pub struct Mirrors {
+    inner: Vec<Mirror>,
+}
+
+impl Mirrors {
+    pub fn from_text(s: &str) -> Result<Mirrors, ParseError> {
+        let mut mirrors: Vec<Mirror> = Vec::new();
+        for lines in aoc_core::sections(s) {
+            let grid = Grid::from_lines(&lines, "'.' or '#'", |c| ".#".contains(c).then_some(c))?;
+            let patterns = grid
+                .rows()
+                .map(|row| Pattern {
+                    blocks: row.to_vec(),
+                })
+                .collect();
+            mirrors.push(Mirror { patterns });
+        }
+        Ok(Mirrors { inner: mirrors })
+    }
+}
+
+pub fn part1(mirrors: &Mirrors) -> usize {
+    for mirror in &mirrors.inner {
+        debug!("{}", mirror.render());
+    }
+    let mut sum = 0;
+    for mirror in &mirrors.inner {
+        let reflection_rows = mirror.find_reflection_rows();
+        if !reflection_rows.is_empty() {
+            sum += (reflection_rows[0] + 1) * 100
+        }
+
+        let reflection_cols = mirror.find_reflection_cols();
+        if !reflection_cols.is_empty() {
+            sum += reflection_cols[0] + 1
+        }
+    }
+    sum
+}
+
+pub fn part2(mirrors: &Mirrors) -> usize {
+    let mut sum = 0;
+    for mirror in &mirrors.inner {
+        let original_rows = mirror.find_reflection_rows();
+        let original_cols = mirror.find_reflection_cols();
+
+        'outer: for (y, pattern) in mirror.patterns.iter().enumerate() {
+            for x in 0..pattern.blocks.len() {
+                let mut cloned = mirror.clone();
+                cloned.toggle(x, y);
+
+                let mut new_reflection_rows: Vec<usize> = cloned.find_reflection_rows();
+                if !original_rows.is_empty() {
+                    new_reflection_rows.retain(|&r| r != original_rows[0]);
+                }
+                if !new_reflection_rows.is_empty() {
+                    sum += (new_reflection_rows[0] + 1) * 100;
+                    break 'outer;
+                }
+
+                let mut new_reflection_cols: Vec<usize> = cloned.find_reflection_cols();
+                if !original_cols.is_empty() {
+                    new_reflection_cols.retain(|&c| c != original_cols[0]);
+                }
+                if !new_reflection_cols.is_empty() {
+                    sum += new_reflection_cols[0] + 1;
+                    break 'outer;
+                }
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Mirrors;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Mirrors::from_text(input)
+    }
+
+    fn part1(mirrors: &Self::Input) -> Answer {
+        part1(mirrors).into()
+    }
+
+    fn part2(mirrors: &Self::Input) -> Answer {
+        part2(mirrors).into()
+    }
+}
+
+impl Generate for Day13 {
+    /// `size` patterns, each mirrored across one row or column
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut patterns = Vec::new();
+        for _ in 0..size {
+            let (across, along) = (rng.range(5, 17) as usize, rng.range(5, 17) as usize);
+            let mirror = rng.range(1, across as i64 - 1) as usize;
+            let mut lines: Vec<Vec<char>> = Vec::new();
+            for i in 0..across {
+                let line = match i.checked_sub(mirror).map(|j| mirror.checked_sub(j + 1)) {
+                    Some(Some(reflected)) => lines[reflected].clone(),
+                    _ => (0..along).map(|_| *rng.pick(&['.', '#'])).collect(),
+                };
+                lines.push(line);
+            }
+            let rows = rng.chance(0.5);
+            let (width, height) = if rows {
+                (along, across)
+            } else {
+                (across, along)
+            };
+            let mut pattern = Grid::new(width, height, '.');
+            for pos in pattern.positions() {
+                pattern[pos] = match rows {
+                    true => lines[pos.y][pos.x],
+                    false => lines[pos.x][pos.y],
+                };
+            }
+            patterns.push(pattern.render(|_, &c| c));
+        }
+        patterns.join("\n")
+    }
+
+    fn render(mirrors: &Self::Input) -> String {
+        let patterns: Vec<String> = mirrors.inner.iter().map(Mirror::render).collect();
+        patterns.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day13>(EXAMPLE, 1, 405);
+        assert_example::<Day13>(EXAMPLE, 2, 400);
+    }
+}
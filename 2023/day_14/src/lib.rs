@@ -0,0 +1,164 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug, info};
+use aoc_grid::{Direction, Grid, Position};
+use aoc_search::find_cycle;
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+#[derive(PartialEq, Clone, Hash, Eq)]
+enum Tile {
+    RoundedRock,
+    SquareRock,
+    Empty,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            'O' => Some(Tile::RoundedRock),
+            '#' => Some(Tile::SquareRock),
+            '.' => Some(Tile::Empty),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::RoundedRock => 'O',
+            Tile::SquareRock => '#',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Platform {
+    grid: Grid<Tile>,
+}
+
+impl Platform {
+    pub fn from_text(s: &str) -> Result<Platform, ParseError> {
+        Ok(Platform {
+            grid: Grid::parse(s, "'O', '#' or '.'", Tile::from_char)?,
+        })
+    }
+
+    pub fn spin_cycle(&mut self) {
+        self.roll(Direction::North);
+        self.roll(Direction::West);
+        self.roll(Direction::South);
+        self.roll(Direction::East);
+    }
+
+    pub fn roll(&mut self, direction: Direction) {
+        // rocks closest to the edge we're rolling towards have to move first
+        let mut positions: Vec<Position> = self.grid.positions().collect();
+        if matches!(direction, Direction::South | Direction::East) {
+            positions.reverse();
+        }
+
+        for position in positions {
+            if self.grid[position] != Tile::RoundedRock {
+                continue;
+            }
+
+            let mut curr = position;
+            while let Some(next) = self.grid.step(curr, direction)
+                && self.grid[next] == Tile::Empty
+            {
+                self.grid[next] = Tile::RoundedRock;
+                self.grid[curr] = Tile::Empty;
+                curr = next;
+            }
+        }
+    }
+
+    pub fn weigh(&self) -> usize {
+        let n_rows = self.grid.height();
+        let mut sum = 0;
+        for (i, row) in self.grid.rows().enumerate() {
+            let n_rounded = row.iter().filter(|&x| *x == Tile::RoundedRock).count();
+            sum += n_rounded * (n_rows - i);
+        }
+        sum
+    }
+}
+
+pub fn part1(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    debug!("{}", platform.grid.render(|_, tile| tile.to_char()));
+    platform.roll(Direction::North);
+    debug!(
+        "Rolled north:\n{}",
+        platform.grid.render(|_, tile| tile.to_char())
+    );
+
+    platform.weigh()
+}
+
+pub fn part2(platform: &Platform) -> usize {
+    // spinning settles into a loop long before a billion cycles, so only
+    // the way into it and once round it are simulated
+    let spins = find_cycle(platform.clone(), |platform| {
+        let mut platform = platform.clone();
+        platform.spin_cycle();
+        platform
+    });
+    info!(
+        "Spinning repeats every {} cycles, after the first {}",
+        spins.period(),
+        spins.prefix()
+    );
+    spins.state_at(SPIN_CYCLES).weigh()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Platform::from_text(input)
+    }
+
+    fn part1(platform: &Self::Input) -> Answer {
+        part1(platform).into()
+    }
+
+    fn part2(platform: &Self::Input) -> Answer {
+        part2(platform).into()
+    }
+}
+
+impl Generate for Day14 {
+    /// a `size` by `size` platform
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut platform = Grid::new(size, size, '.');
+        for pos in platform.positions() {
+            platform[pos] = match rng.below(10) {
+                0..=1 => 'O',
+                2 => '#',
+                _ => '.',
+            };
+        }
+        platform.render(|_, &c| c)
+    }
+
+    fn render(platform: &Self::Input) -> String {
+        platform.grid.render(|_, tile| tile.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day14>(EXAMPLE, 1, 136);
+        assert_example::<Day14>(EXAMPLE, 2, 64);
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::fmt;
+
+pub fn hash(s: &str) -> usize {
+    let mut current = 0;
+
+    for c in s.chars() {
+        current += (c as u8) as usize;
+        current *= 17;
+        current %= 256;
+    }
+    current
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add(u8),
+    Remove,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    label: String,
+    operation: Operation,
+}
+
+/// written back the way it appears in the input
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Operation::Add(focal_length) => write!(f, "{}={}", self.label, focal_length),
+            Operation::Remove => write!(f, "{}-", self.label),
+        }
+    }
+}
+
+impl Instruction {
+    fn box_idx(&self) -> usize {
+        hash(&self.label)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+#[derive(Debug, Clone)]
+struct LensBox {
+    lenses: Vec<Lens>,
+}
+impl LensBox {
+    fn new() -> LensBox {
+        LensBox { lenses: Vec::new() }
+    }
+
+    fn contains(&self, label: &str) -> bool {
+        for lens in &self.lenses {
+            if lens.label == label {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn remove(&mut self, label: &str) {
+        self.lenses.retain(|l| l.label != label);
+    }
+
+    fn replace(&mut self, label: &str, new_focal_length: u8) {
+        for lens in &mut self.lenses {
+            if lens.label == label {
+                lens.focal_length = new_focal_length;
+                return;
+            }
+        }
+    }
+}
+
+fn parse_instruction(step: &mut Scanner) -> Result<Instruction, ParseError> {
+    let label = step.word()?.to_string();
+    let operation = match step.char_as("'-' or '='", |c| "-=".contains(c).then_some(c))? {
+        '-' => Operation::Remove,
+        _ => Operation::Add(step.number()?),
+    };
+    Ok(Instruction { label, operation })
+}
+
+pub fn parse_instructions(s: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut line = aoc_core::first_line(s)?;
+    let instructions = line.separated(",", parse_instruction)?;
+    line.end()?;
+    Ok(instructions)
+}
+
+pub fn part1(instructions: &[Instruction]) -> usize {
+    let mut sum = 0;
+    for instruction in instructions {
+        sum += hash(&instruction.to_string());
+    }
+    sum
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    let mut boxes: Vec<LensBox> = vec![LensBox::new(); 256];
+
+    for instruction in instructions {
+        let box_idx = instruction.box_idx();
+
+        match instruction.operation {
+            Operation::Remove => boxes[box_idx].remove(&instruction.label),
+            Operation::Add(fl) => {
+                if boxes[box_idx].contains(&instruction.label) {
+                    boxes[box_idx].replace(&instruction.label, fl)
+                } else {
+                    boxes[box_idx].lenses.push(Lens {
+                        label: instruction.label.clone(),
+                        focal_length: fl,
+                    })
+                }
+            }
+        }
+    }
+
+    let mut focusing_power = 0;
+    for (box_idx, b) in boxes.iter().enumerate() {
+        for (lens_idx, lens) in b.lenses.iter().enumerate() {
+            focusing_power += (1 + box_idx) * (1 + lens_idx) * lens.focal_length as usize
+        }
+    }
+    focusing_power
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+impl Generate for Day15 {
+    /// `size` steps over a few dozen labels
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let labels: Vec<String> = (0..rng.range(1, 40))
+            .map(|_| {
+                (0..rng.range(1, 6))
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect()
+            })
+            .collect();
+        let steps: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let label = rng.pick(&labels);
+                match rng.chance(0.3) {
+                    true => format!("{}-", label),
+                    false => format!("{}={}", label, rng.range(1, 9)),
+                }
+            })
+            .collect();
+        format!("{}\n", steps.join(","))
+    }
+
+    fn render(instructions: &Self::Input) -> String {
+        let steps: Vec<String> = instructions.iter().map(Instruction::to_string).collect();
+        format!("{}\n", steps.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day15>(EXAMPLE, 1, 1320);
+        assert_example::<Day15>(EXAMPLE, 2, 145);
+    }
+}
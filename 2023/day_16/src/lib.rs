@@ -0,0 +1,352 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug};
+use aoc_grid::view::{self, Cell, Color, Visualize};
+use aoc_grid::{Direction, Grid, Position};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+enum Device {
+    MirrorForward,
+    MirrorBackward,
+    SplitterVertical,
+    SplitterHorizontal,
+    Empty,
+}
+
+impl Device {
+    fn to_char(&self) -> char {
+        match self {
+            Device::MirrorForward => '/',
+            Device::MirrorBackward => '\\',
+            Device::SplitterVertical => '|',
+            Device::SplitterHorizontal => '-',
+            Device::Empty => '.',
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Tile {
+    device: Device,
+    energized: bool,
+}
+
+impl Tile {
+    fn init_from_char(c: char) -> Option<Tile> {
+        let device = match c {
+            '/' => Device::MirrorForward,
+            '\\' => Device::MirrorBackward,
+            '|' => Device::SplitterVertical,
+            '-' => Device::SplitterHorizontal,
+            '.' => Device::Empty,
+            _ => return None,
+        };
+        Some(Tile {
+            device,
+            energized: false,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Contraption {
+    tiles: Grid<Tile>,
+}
+
+impl Contraption {
+    pub fn init_from_str(s: &str) -> Result<Contraption, ParseError> {
+        Ok(Contraption {
+            tiles: Grid::parse(s, "a mirror, a splitter or '.'", Tile::init_from_char)?,
+        })
+    }
+
+    pub fn count_energized(&self) -> usize {
+        self.tiles.iter().filter(|(_, tile)| tile.energized).count()
+    }
+
+    pub fn energize_from(&mut self, initial_beam: Beam) {
+        let mut beams = vec![initial_beam];
+        let mut visited: HashSet<Beam> = HashSet::new();
+
+        while !beams.is_empty() {
+            let beam = beams.remove(0);
+            self.tiles[beam.coord].energized = true;
+            let reflecting_beams = beam.reflect_against(&self.tiles[beam.coord].device);
+
+            for beam in reflecting_beams {
+                let Some(next) = self.tiles.step(beam.coord, beam.direction) else {
+                    continue;
+                };
+
+                let moved = Beam {
+                    coord: next,
+                    direction: beam.direction,
+                };
+
+                if !visited.contains(&moved) {
+                    visited.insert(moved.clone());
+                    beams.push(moved);
+                }
+            }
+        }
+    }
+}
+
+impl Visualize for Contraption {
+    /// energized tiles lit up, devices drawn over them
+    fn view(&self) -> Grid<Cell> {
+        self.tiles.map(|tile| {
+            let glyph = match (&tile.device, tile.energized) {
+                (Device::Empty, true) => '#',
+                (device, _) => device.to_char(),
+            };
+            match tile.energized {
+                true => Cell::new(glyph, Color::YELLOW),
+                false => Cell::new(glyph, Color::GREY),
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Beam {
+    pub coord: Position,
+    pub direction: Direction,
+}
+
+impl Beam {
+    fn reflect_against(&self, device: &Device) -> Vec<Beam> {
+        match self.direction {
+            Direction::North => self.reflect_north(device),
+            Direction::East => self.reflect_east(device),
+            Direction::South => self.reflect_south(device),
+            Direction::West => self.reflect_west(device),
+        }
+    }
+
+    fn reflect_north(&self, device: &Device) -> Vec<Beam> {
+        let mut beams = Vec::new();
+        match device {
+            Device::MirrorForward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::East,
+            }),
+            Device::MirrorBackward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::West,
+            }),
+            Device::SplitterHorizontal => {
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::East,
+                });
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::West,
+                });
+            }
+            _ => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::North,
+            }),
+        }
+        beams
+    }
+
+    fn reflect_east(&self, device: &Device) -> Vec<Beam> {
+        let mut beams = Vec::new();
+        match device {
+            Device::MirrorForward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::North,
+            }),
+            Device::MirrorBackward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::South,
+            }),
+            Device::SplitterVertical => {
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::North,
+                });
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::South,
+                });
+            }
+            _ => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::East,
+            }),
+        }
+        beams
+    }
+
+    fn reflect_south(&self, device: &Device) -> Vec<Beam> {
+        let mut beams = Vec::new();
+        match device {
+            Device::MirrorForward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::West,
+            }),
+            Device::MirrorBackward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::East,
+            }),
+            Device::SplitterHorizontal => {
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::West,
+                });
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::East,
+                });
+            }
+            _ => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::South,
+            }),
+        }
+        beams
+    }
+
+    fn reflect_west(&self, device: &Device) -> Vec<Beam> {
+        let mut beams = Vec::new();
+        match device {
+            Device::MirrorForward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::South,
+            }),
+            Device::MirrorBackward => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::North,
+            }),
+            Device::SplitterVertical => {
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::South,
+                });
+                beams.push(Beam {
+                    coord: self.coord,
+                    direction: Direction::North,
+                });
+            }
+            _ => beams.push(Beam {
+                coord: self.coord,
+                direction: Direction::West,
+            }),
+        }
+        beams
+    }
+}
+
+pub fn parse_contraption(input: &str) -> Result<Contraption, ParseError> {
+    Contraption::init_from_str(input)
+}
+
+pub fn part1(contraption: &Contraption) -> usize {
+    let mut contraption = contraption.clone();
+    debug!(
+        "{}",
+        contraption.tiles.render(|_, tile| tile.device.to_char())
+    );
+
+    let initial_beam = Beam {
+        coord: Position::new(0, 0),
+        direction: Direction::East,
+    };
+
+    contraption.energize_from(initial_beam);
+    debug!("{}", view::text(&contraption.view()));
+    view::show(&contraption);
+    contraption.count_energized()
+}
+
+pub fn part2(contraption: &Contraption) -> usize {
+    let height = contraption.tiles.height();
+    let width = contraption.tiles.width();
+
+    let mut initial_beams = Vec::new();
+    for y in 0..height {
+        initial_beams.push(Beam {
+            coord: Position::new(0, y),
+            direction: Direction::East,
+        });
+        initial_beams.push(Beam {
+            coord: Position::new(width - 1, y),
+            direction: Direction::West,
+        });
+    }
+    for x in 0..width {
+        initial_beams.push(Beam {
+            coord: Position::new(x, 0),
+            direction: Direction::South,
+        });
+        initial_beams.push(Beam {
+            coord: Position::new(x, height - 1),
+            direction: Direction::North,
+        });
+    }
+
+    let mut max_energized = 0;
+    for initial_beam in initial_beams {
+        let mut cloned = contraption.clone();
+        cloned.energize_from(initial_beam);
+        view::show(&cloned);
+        let energized_tiles = cloned.count_energized();
+        if energized_tiles > max_energized {
+            max_energized = energized_tiles;
+        }
+    }
+    max_energized
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_contraption(input)
+    }
+
+    fn part1(contraption: &Self::Input) -> Answer {
+        part1(contraption).into()
+    }
+
+    fn part2(contraption: &Self::Input) -> Answer {
+        part2(contraption).into()
+    }
+}
+
+impl Generate for Day16 {
+    /// a `size` by `size` contraption
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut tiles = Grid::new(size, size, '.');
+        for pos in tiles.positions() {
+            if rng.chance(0.1) {
+                tiles[pos] = *rng.pick(&['/', '\\', '|', '-']);
+            }
+        }
+        tiles.render(|_, &c| c)
+    }
+
+    fn render(contraption: &Self::Input) -> String {
+        contraption.tiles.render(|_, tile| tile.device.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day16>(EXAMPLE, 1, 46);
+        assert_example::<Day16>(EXAMPLE, 2, 51);
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Direction, Grid, Position};
+use aoc_search::dijkstra;
+
+pub fn parse_city(s: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(s, "a digit", |c| c.to_digit(10).map(|d| d as usize))
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct State {
+    pos: Position,
+    last_dir: Option<Direction>,
+    consecutive: usize,
+}
+
+impl State {
+    fn start() -> State {
+        State {
+            pos: Position::new(0, 0),
+            last_dir: None,
+            consecutive: 0,
+        }
+    }
+}
+
+pub fn solve(city: &Grid<usize>, min_consec: usize, max_consec: usize) -> usize {
+    let end_pos = Position::new(city.width() - 1, city.height() - 1);
+
+    let successors = |curr: &State| {
+        // gather all possible next directions
+        let valid_dirs = Direction::ALL.into_iter().filter(|d| {
+            if let Some(last) = curr.last_dir {
+                if *d == last.opposite() {
+                    return false;
+                }
+                if curr.consecutive < min_consec && *d != last {
+                    return false;
+                }
+                if curr.consecutive >= max_consec && *d == last {
+                    return false;
+                }
+            }
+            true
+        });
+
+        let mut next = Vec::new();
+        for dir in valid_dirs {
+            let Some(pos) = city.step(curr.pos, dir) else {
+                continue;
+            };
+            let consecutive = if curr.last_dir == Some(dir) {
+                curr.consecutive + 1
+            } else {
+                1
+            };
+            let state = State {
+                pos,
+                last_dir: Some(dir),
+                consecutive,
+            };
+            next.push((state, city[pos]));
+        }
+        next
+    };
+
+    // the crucible can only stop at the end once it has moved far enough
+    let is_end = |state: &State| state.pos == end_pos && state.consecutive >= min_consec;
+
+    dijkstra(State::start(), successors, is_end).map_or(0, |(_, heat_loss)| heat_loss)
+}
+
+pub fn part1(city: &Grid<usize>) -> usize {
+    solve(city, 0, 3)
+}
+
+/// with ultra crucibles
+pub fn part2(city: &Grid<usize>) -> usize {
+    solve(city, 4, 10)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_city(input)
+    }
+
+    fn part1(city: &Self::Input) -> Answer {
+        part1(city).into()
+    }
+
+    fn part2(city: &Self::Input) -> Answer {
+        part2(city).into()
+    }
+}
+
+impl Generate for Day17 {
+    /// a `size` by `size` city
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut city = Grid::new(size, size, 1);
+        for pos in city.positions() {
+            city[pos] = rng.range(1, 9) as u32;
+        }
+        city.render(|_, &heat_loss| char::from_digit(heat_loss, 10).unwrap())
+    }
+
+    fn render(city: &Self::Input) -> String {
+        city.render(|_, &loss| char::from_digit(loss as u32, 10).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day17>(EXAMPLE, 1, 102);
+        assert_example::<Day17>(EXAMPLE, 2, 94);
+        assert_example::<Day17>(EXAMPLE_2, 2, 71);
+    }
+}
@@ -0,0 +1,278 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_math::{interior_points, shoelace};
+
+struct Coord {
+    x: isize,
+    y: isize,
+}
+
+impl Coord {
+    fn step(&self, dir: &Direction, count: usize) -> Coord {
+        match dir {
+            Direction::Up => Coord {
+                x: self.x,
+                y: self.y - count as isize,
+            },
+            Direction::Right => Coord {
+                x: self.x + count as isize,
+                y: self.y,
+            },
+            Direction::Down => Coord {
+                x: self.x,
+                y: self.y + count as isize,
+            },
+            Direction::Left => Coord {
+                x: self.x - count as isize,
+                y: self.y,
+            },
+        }
+    }
+}
+
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+pub struct Instruction {
+    direction: Direction,
+    steps: usize,
+    color: String,
+}
+
+impl Instruction {
+    pub fn from_string(s: &str) -> Result<Vec<Instruction>, ParseError> {
+        let mut instructions: Vec<Instruction> = Vec::new();
+
+        for mut line in aoc_core::lines(s) {
+            let direction = line.char_as("'U', 'R', 'D' or 'L'", |c| match c {
+                'U' => Some(Direction::Up),
+                'R' => Some(Direction::Right),
+                'D' => Some(Direction::Down),
+                'L' => Some(Direction::Left),
+                _ => None,
+            })?;
+            let steps: usize = line.number()?;
+            line.expect(" (#")?;
+            let mut color = String::from("#");
+            for _ in 0..5 {
+                color.push(line.char_as("a hex digit", |c| c.is_ascii_hexdigit().then_some(c))?);
+            }
+            // the last digit is the direction once converted
+            color.push(line.char_as("a digit from 0 to 3", |c| "0123".contains(c).then_some(c))?);
+            line.expect(")")?;
+            line.end()?;
+            instructions.push(Instruction {
+                direction,
+                steps,
+                color,
+            });
+        }
+        Ok(instructions)
+    }
+
+    pub fn convert(&self) -> Instruction {
+        let steps = usize::from_str_radix(&self.color[1..6], 16).unwrap();
+        let direction = match self.color.chars().last().unwrap() {
+            '0' => Direction::Right,
+            '1' => Direction::Down,
+            '2' => Direction::Left,
+            '3' => Direction::Up,
+            _ => unreachable!(),
+        };
+
+        Instruction {
+            direction,
+            steps,
+            color: self.color.clone(),
+        }
+    }
+}
+
+fn area(coords: &[Coord]) -> usize {
+    let vertices: Vec<(i64, i64)> = coords.iter().map(|c| (c.x as i64, c.y as i64)).collect();
+    let perimeter: usize = coords
+        .windows(2)
+        .map(|w| w[0].x.abs_diff(w[1].x) + w[0].y.abs_diff(w[1].y))
+        .sum();
+    // the trench itself is dug out too, not only what it encloses
+    let inside = interior_points(shoelace(&vertices), perimeter as i64);
+    inside as usize + perimeter
+}
+
+fn compute_coords(instructions: &[Instruction]) -> Vec<Coord> {
+    let mut coords = vec![Coord { x: 0, y: 0 }];
+
+    for instruction in instructions {
+        let next = coords
+            .last()
+            .unwrap()
+            .step(&instruction.direction, instruction.steps);
+        coords.push(next);
+    }
+    coords
+}
+
+/// The runs of a trench around a blob of `height` rows, clockwise as 'R',
+/// 'D', 'L' or 'U' and a length. Each row overlaps the one above so the
+/// trench never touches itself, and every row and column of the blob is
+/// stretched to a random length in `min..=max`.
+fn blob_outline(height: usize, min: i64, max: i64, rng: &mut Rng) -> Vec<(char, usize)> {
+    let width = height;
+    let mut rows: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..height {
+        let row = loop {
+            let left = rng.below(width);
+            let right = left + rng.below(width - left);
+            match rows.last() {
+                Some(&(l, r)) if right < l || left > r => continue,
+                _ => break (left, right),
+            }
+        };
+        rows.push(row);
+    }
+
+    // corners of the blob's cells, clockwise from the top left
+    let mut corners = vec![(rows[0].0, 0), (rows[0].1 + 1, 0)];
+    for y in 0..height {
+        corners.push((rows[y].1 + 1, y + 1));
+        if y + 1 < height {
+            corners.push((rows[y + 1].1 + 1, y + 1));
+        }
+    }
+    corners.push((rows[height - 1].0, height));
+    for y in (0..height).rev() {
+        corners.push((rows[y].0, y));
+        if y > 0 {
+            corners.push((rows[y - 1].0, y));
+        }
+    }
+
+    let mut stretch = |cells: usize| -> Vec<i64> {
+        (0..=cells)
+            .scan(0, |at, _| {
+                let start = *at;
+                *at += rng.range(min, max);
+                Some(start)
+            })
+            .collect()
+    };
+    let (xs, ys) = (stretch(width), stretch(height));
+    let mut runs = Vec::new();
+    for pair in corners.windows(2) {
+        let (dx, dy) = (xs[pair[1].0] - xs[pair[0].0], ys[pair[1].1] - ys[pair[0].1]);
+        let run = match (dx.signum(), dy.signum()) {
+            (1, 0) => ('R', dx),
+            (0, 1) => ('D', dy),
+            (-1, 0) => ('L', -dx),
+            (0, -1) => ('U', -dy),
+            _ => continue,
+        };
+        runs.push((run.0, run.1 as usize));
+    }
+    runs
+}
+
+/// cuts the longest run in two, which digs the same trench
+fn split_longest(runs: &mut Vec<(char, usize)>, rng: &mut Rng) {
+    let (i, &(direction, steps)) = runs
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, run)| run.1)
+        .unwrap();
+    let cut = rng.range(1, steps as i64 - 1) as usize;
+    runs[i].1 = cut;
+    runs.insert(i + 1, (direction, steps - cut));
+}
+
+pub fn part1(instructions: &[Instruction]) -> usize {
+    let coords = compute_coords(instructions);
+    area(&coords)
+}
+
+pub fn part2(instructions: &[Instruction]) -> usize {
+    let converted: Vec<Instruction> = instructions.iter().map(|i| i.convert()).collect();
+    let coords = compute_coords(&converted);
+    area(&coords)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Instruction::from_string(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        part1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        part2(instructions).into()
+    }
+}
+
+impl Generate for Day18 {
+    /// trenches around blobs of `size` rows, a small one in the instructions
+    /// and a much larger one hidden in the colors
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut small = blob_outline(size, 4, 9, rng);
+        // the colors only have five hex digits for the length
+        let mut large = blob_outline(size, 2, (500_000 / (size as i64 + 1)).max(2), rng);
+        // both need the same number of instructions
+        while small.len() < large.len() {
+            split_longest(&mut small, rng);
+        }
+        while large.len() < small.len() {
+            split_longest(&mut large, rng);
+        }
+        small
+            .iter()
+            .zip(&large)
+            .map(|(&(direction, steps), &(color_direction, color_steps))| {
+                let color_direction = "RDLU".find(color_direction).unwrap();
+                format!(
+                    "{} {} (#{:05x}{})\n",
+                    direction, steps, color_steps, color_direction
+                )
+            })
+            .collect()
+    }
+
+    fn render(instructions: &Self::Input) -> String {
+        instructions
+            .iter()
+            .map(|instruction| {
+                let direction = match instruction.direction {
+                    Direction::Up => 'U',
+                    Direction::Right => 'R',
+                    Direction::Down => 'D',
+                    Direction::Left => 'L',
+                };
+                format!(
+                    "{} {} ({})\n",
+                    direction, instruction.steps, instruction.color
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day18>(EXAMPLE, 1, 62);
+        assert_example::<Day18>(EXAMPLE, 2, 952408144115u64);
+    }
+}
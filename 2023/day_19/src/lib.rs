@@ -0,0 +1,518 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::collections::{HashMap, HashSet};
+
+const FIRST: &str = "in";
+const MAX: usize = 4000;
+
+#[derive(Debug)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+enum Op {
+    GreaterThan,
+    LessThan,
+}
+
+#[derive(Debug, Clone)]
+enum Destination {
+    Accepted,
+    Rejected,
+    Workflow(String),
+}
+
+impl Destination {
+    fn from_name(name: &str) -> Destination {
+        match name {
+            "A" => Destination::Accepted,
+            "R" => Destination::Rejected,
+            wf => Destination::Workflow(wf.to_string()),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Destination::Accepted => "A",
+            Destination::Rejected => "R",
+            Destination::Workflow(wf) => wf,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    category: Category,
+    op: Op,
+    amount: usize,
+    destination: Destination,
+}
+
+impl Rule {
+    fn applies(&self, part: &Part) -> bool {
+        let value = match self.category {
+            Category::X => part.x,
+            Category::M => part.m,
+            Category::A => part.a,
+            Category::S => part.s,
+        };
+
+        match self.op {
+            Op::GreaterThan => value > self.amount,
+            Op::LessThan => value < self.amount,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+    default: Destination,
+}
+
+impl Workflow {
+    /// Also keeps every workflow the rules send parts to, with where it
+    /// was read, for `parse_input` to check.
+    fn from_line<'a>(
+        line: &mut Scanner<'a>,
+        targets: &mut Vec<(Scanner<'a>, String)>,
+    ) -> Result<Workflow, ParseError> {
+        let name = line.word()?.to_string();
+        line.expect("{")?;
+
+        let mut rules: Vec<Rule> = Vec::new();
+        let default = loop {
+            line.skip_spaces();
+            let at = line.clone();
+            let word = line.word()?;
+            if !line.rest().starts_with(['<', '>']) {
+                // the last one has no condition
+                targets.push((at, word.to_string()));
+                break Destination::from_name(word);
+            }
+            let category = match word {
+                "x" => Category::X,
+                "m" => Category::M,
+                "a" => Category::A,
+                "s" => Category::S,
+                _ => return Err(at.error("'x', 'm', 'a' or 's'")),
+            };
+            let op = line.char_as("'>' or '<'", |c| match c {
+                '>' => Some(Op::GreaterThan),
+                '<' => Some(Op::LessThan),
+                _ => None,
+            })?;
+            let amount: usize = line.number()?;
+            line.expect(":")?;
+            line.skip_spaces();
+            let at = line.clone();
+            let name = line.word()?;
+            targets.push((at, name.to_string()));
+            let destination = Destination::from_name(name);
+            line.expect(",")?;
+
+            let rule = Rule {
+                category,
+                op,
+                amount,
+                destination,
+            };
+            rules.push(rule)
+        };
+        line.expect("}")?;
+        line.end()?;
+
+        Ok(Workflow {
+            name,
+            rules,
+            default,
+        })
+    }
+
+    fn process(&self, part: &Part) -> Destination {
+        for rule in &self.rules {
+            if rule.applies(part) {
+                return rule.destination.clone();
+            }
+        }
+        self.default.clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn from_line(line: &mut Scanner) -> Result<Part, ParseError> {
+        // assumes the order is alwaws x , m , a , s
+        line.expect("{x=")?;
+        let x: usize = line.number()?;
+        line.expect(",m=")?;
+        let m: usize = line.number()?;
+        line.expect(",a=")?;
+        let a: usize = line.number()?;
+        line.expect(",s=")?;
+        let s: usize = line.number()?;
+        line.expect("}")?;
+        line.end()?;
+        Ok(Part { x, m, a, s })
+    }
+
+    fn sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+/// Parts start at `FIRST` and only go to workflows there are, never coming
+/// back to one they've been through.
+fn check_workflows(
+    workflows: &[Workflow],
+    lines: &[Scanner],
+    targets: &[(Scanner, String)],
+) -> Result<(), ParseError> {
+    let wf_map: HashMap<&str, &Workflow> = workflows.iter().map(|w| (w.name.as_str(), w)).collect();
+    if !wf_map.contains_key(FIRST) {
+        return Err(ParseError {
+            line: lines[lines.len() - 1].line() + 1,
+            column: 1,
+            expected: format!("a workflow named '{}'", FIRST),
+            found: "a blank line".to_string(),
+        });
+    }
+    for (at, target) in targets {
+        if let Destination::Workflow(name) = Destination::from_name(target)
+            && !wf_map.contains_key(name.as_str())
+        {
+            return Err(at.error("'A', 'R' or a workflow there is"));
+        }
+    }
+
+    // a workflow is done with once everywhere it sends parts is
+    let mut done: HashSet<&str> = HashSet::new();
+    let mut pending: Vec<usize> = (0..workflows.len()).collect();
+    loop {
+        let before = pending.len();
+        pending.retain(|&i| {
+            let workflow = &workflows[i];
+            let ready = workflow
+                .rules
+                .iter()
+                .map(|rule| &rule.destination)
+                .chain([&workflow.default])
+                .all(|destination| match destination {
+                    Destination::Workflow(name) => done.contains(name.as_str()),
+                    _ => true,
+                });
+            if ready {
+                done.insert(&workflow.name);
+            }
+            !ready
+        });
+        match pending.first() {
+            None => return Ok(()),
+            Some(&i) if pending.len() == before => {
+                return Err(lines[i].error("a workflow that doesn't lead back to itself"));
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Workflow>, Vec<Part>), ParseError> {
+    let [workflows, parts] = aoc_core::two_sections(input, "parts")?;
+
+    let mut targets = Vec::new();
+    let workflow_lines = workflows;
+    let workflows: Vec<Workflow> = workflow_lines
+        .iter()
+        .map(|line| Workflow::from_line(&mut line.clone(), &mut targets))
+        .collect::<Result<_, _>>()?;
+    check_workflows(&workflows, &workflow_lines, &targets)?;
+    let parts = parts
+        .iter()
+        .map(|line| Part::from_line(&mut line.clone()))
+        .collect::<Result<_, _>>()?;
+    Ok((workflows, parts))
+}
+
+pub fn part1(workflows: &[Workflow], parts: &[Part]) -> usize {
+    let wf_map: HashMap<&str, &Workflow> = workflows.iter().map(|w| (w.name.as_str(), w)).collect();
+
+    let mut sum = 0;
+    for part in parts {
+        let mut dest = wf_map.get(FIRST).unwrap().process(part);
+        while let Destination::Workflow(name) = dest {
+            dest = wf_map.get(name.as_str()).unwrap().process(part);
+        }
+        match dest {
+            Destination::Accepted => sum += part.sum(),
+            Destination::Rejected => {}
+            _ => unreachable!(),
+        }
+    }
+    sum
+}
+
+// ranges are both inclusive
+#[derive(Clone, Debug)]
+struct PartRange {
+    x: (usize, usize),
+    m: (usize, usize),
+    a: (usize, usize),
+    s: (usize, usize),
+}
+
+impl PartRange {
+    fn new() -> PartRange {
+        PartRange {
+            x: (1, MAX),
+            m: (1, MAX),
+            a: (1, MAX),
+            s: (1, MAX),
+        }
+    }
+
+    // returns tuple (valid, invalid)
+    fn split_for(&self, rule: &Rule) -> (Option<PartRange>, Option<PartRange>) {
+        let range = self.get_range(&rule.category);
+
+        let (valid, invalid) = match rule.op {
+            Op::GreaterThan => {
+                if rule.amount >= range.1 {
+                    (None, Some(range))
+                } else if rule.amount < range.0 {
+                    (Some(range), None)
+                } else {
+                    (
+                        Some((rule.amount + 1, range.1)),
+                        Some((range.0, rule.amount)),
+                    )
+                }
+            }
+            Op::LessThan => {
+                if rule.amount <= range.0 {
+                    (None, Some(range))
+                } else if rule.amount > range.1 {
+                    (Some(range), None)
+                } else {
+                    (
+                        Some((range.0, rule.amount - 1)),
+                        Some((rule.amount, range.1)),
+                    )
+                }
+            }
+        };
+
+        let valid = valid.map(|r| self.with_range(&rule.category, r));
+        let invalid = invalid.map(|r| self.with_range(&rule.category, r));
+
+        (valid, invalid)
+    }
+
+    fn get_range(&self, category: &Category) -> (usize, usize) {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+
+    fn with_range(&self, category: &Category, range: (usize, usize)) -> PartRange {
+        let mut cloned = self.clone();
+        match category {
+            Category::X => cloned.x = range,
+            Category::M => cloned.m = range,
+            Category::A => cloned.a = range,
+            Category::S => cloned.s = range,
+        }
+        cloned
+    }
+
+    fn combinations(&self) -> usize {
+        (self.x.1 - self.x.0 + 1)
+            * (self.m.1 - self.m.0 + 1)
+            * (self.a.1 - self.a.0 + 1)
+            * (self.s.1 - self.s.0 + 1)
+    }
+}
+
+fn get_accepted_ranges(
+    workflows: &HashMap<&str, &Workflow>,
+    workflow: &str,
+    mut range: PartRange,
+) -> Vec<PartRange> {
+    let workflow = workflows.get(workflow).unwrap();
+
+    let mut accepted: Vec<PartRange> = Vec::new();
+    for rule in &workflow.rules {
+        let (pass, reject) = range.split_for(rule);
+        if let Some(s) = pass {
+            match &rule.destination {
+                Destination::Accepted => accepted.push(s),
+                Destination::Rejected => {}
+                Destination::Workflow(w) => accepted.extend(get_accepted_ranges(workflows, w, s)),
+            }
+        }
+
+        match reject {
+            None => return Vec::new(),
+            Some(k) => range = k,
+        }
+    }
+
+    match &workflow.default {
+        Destination::Accepted => accepted.push(range),
+        Destination::Rejected => {}
+        Destination::Workflow(w) => accepted.extend(get_accepted_ranges(workflows, w, range)),
+    }
+
+    accepted
+}
+
+pub fn part2(workflows: &[Workflow]) -> usize {
+    let wf_map: HashMap<&str, &Workflow> = workflows.iter().map(|w| (w.name.as_str(), w)).collect();
+    let accepted = get_accepted_ranges(&wf_map, FIRST, PartRange::new());
+    let mut sum = 0;
+    for range in &accepted {
+        sum += range.combinations();
+    }
+    sum
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (workflows, parts) = input;
+        part1(workflows, parts).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (workflows, _) = input;
+        part2(workflows).into()
+    }
+}
+
+impl Generate for Day19 {
+    /// `size` workflows, each sending parts only to the ones after it, and
+    /// `size` parts
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut names = vec![FIRST.to_string()];
+        while names.len() < size {
+            let name: String = (0..rng.range(2, 3))
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let mut input = String::new();
+        for (i, name) in names.iter().enumerate() {
+            let destination = |rng: &mut Rng| match rng.below(size - i + 1) {
+                0 => "A".to_string(),
+                1 => "R".to_string(),
+                later => names[i + later - 1].clone(),
+            };
+            let rules: Vec<String> = (0..rng.range(1, 4))
+                .map(|_| {
+                    let category = *rng.pick(&['x', 'm', 'a', 's']);
+                    let op = *rng.pick(&['<', '>']);
+                    let amount = rng.range(1, MAX as i64);
+                    format!("{}{}{}:{}", category, op, amount, destination(rng))
+                })
+                .collect();
+            input.push_str(&format!(
+                "{}{{{},{}}}\n",
+                name,
+                rules.join(","),
+                destination(rng)
+            ));
+        }
+        input.push('\n');
+        for _ in 0..size {
+            let [x, m, a, s] = [(); 4].map(|_| rng.range(1, MAX as i64));
+            input.push_str(&format!("{{x={},m={},a={},s={}}}\n", x, m, a, s));
+        }
+        input
+    }
+
+    fn render(input: &Self::Input) -> String {
+        let (workflows, parts) = input;
+        let mut text = String::new();
+        for workflow in workflows {
+            text.push_str(&format!("{}{{", workflow.name));
+            for rule in &workflow.rules {
+                let category = match rule.category {
+                    Category::X => 'x',
+                    Category::M => 'm',
+                    Category::A => 'a',
+                    Category::S => 's',
+                };
+                let op = match rule.op {
+                    Op::GreaterThan => '>',
+                    Op::LessThan => '<',
+                };
+                text.push_str(&format!(
+                    "{}{}{}:{},",
+                    category,
+                    op,
+                    rule.amount,
+                    rule.destination.name()
+                ));
+            }
+            text.push_str(&format!("{}}}\n", workflow.default.name()));
+        }
+        text.push('\n');
+        for part in parts {
+            text.push_str(&format!(
+                "{{x={},m={},a={},s={}}}\n",
+                part.x, part.m, part.a, part.s
+            ));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day19>(EXAMPLE, 1, 19114);
+        assert_example::<Day19>(EXAMPLE, 2, 167409079868000u64);
+    }
+
+    #[test]
+    fn test_workflows_lead_somewhere() {
+        let error = |workflows: &str| {
+            let input = format!("{}\n\n{{x=1,m=2,a=3,s=4}}\n", workflows);
+            parse_input(&input).err().map(|e| (e.line, e.column))
+        };
+        assert_eq!(error("px{a<2006:A,R}"), Some((2, 1)));
+        assert_eq!(error("in{a<2006:qkq,R}"), Some((1, 11)));
+        assert_eq!(error("in{a<2006:A,px}"), Some((1, 13)));
+        assert_eq!(error("in{a<2006:px,A}\npx{in}"), Some((1, 1)));
+        assert_eq!(error("in{a<2006:px,A}\npx{x>5:R,A}"), None);
+    }
+}
@@ -0,0 +1,422 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, info};
+use aoc_math::lcm_all;
+use std::collections::{HashMap, VecDeque};
+
+const OUTPUT: &str = "rx";
+const BUTTON_PRESSES: u16 = 1000;
+
+pub type ModuleName = String;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum PulseFrequency {
+    Low,
+    High,
+}
+
+#[derive(Debug)]
+struct Pulse {
+    freq: PulseFrequency,
+    src: ModuleName,
+    dst: ModuleName,
+}
+
+impl Pulse {
+    fn initial() -> Pulse {
+        Pulse {
+            freq: PulseFrequency::Low,
+            src: "button".to_string(),
+            dst: "broadcaster".to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum ModuleType {
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+    Broadcaster(Broadcaster),
+    Output(ModuleName),
+}
+
+impl ModuleType {
+    fn name(&self) -> String {
+        match self {
+            ModuleType::FlipFlop(f) => f.name.to_string(),
+            ModuleType::Conjunction(c) => c.name.to_string(),
+            ModuleType::Broadcaster(b) => b.name.to_string(),
+            ModuleType::Output(o) => o.to_string(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FlipFlop {
+    name: ModuleName,
+    state: bool,
+    connects_to: Vec<ModuleName>,
+}
+
+impl FlipFlop {
+    fn new(name: ModuleName, connects_to: Vec<ModuleName>) -> FlipFlop {
+        FlipFlop {
+            name,
+            state: false,
+            connects_to,
+        }
+    }
+
+    fn toggle(&mut self) {
+        match self.state {
+            true => self.state = false,
+            false => self.state = true,
+        }
+    }
+
+    fn process(&mut self, pulse: &Pulse) -> Vec<Pulse> {
+        match pulse.freq {
+            PulseFrequency::Low => {
+                self.toggle();
+                let frequency = match self.state {
+                    false => PulseFrequency::Low,
+                    true => PulseFrequency::High,
+                };
+                self.connects_to
+                    .iter()
+                    .map(|mn| Pulse {
+                        freq: frequency,
+                        src: self.name.clone(),
+                        dst: mn.clone(),
+                    })
+                    .collect()
+            }
+            PulseFrequency::High => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Conjunction {
+    name: ModuleName,
+    memory: HashMap<ModuleName, PulseFrequency>,
+    connects_to: Vec<ModuleName>,
+}
+
+impl Conjunction {
+    fn new(name: ModuleName, connects_to: Vec<ModuleName>) -> Conjunction {
+        Conjunction {
+            name,
+            memory: HashMap::new(),
+            connects_to,
+        }
+    }
+
+    fn init_inputs(&mut self, inputs: Vec<ModuleName>) {
+        for input in inputs {
+            self.memory.insert(input.clone(), PulseFrequency::Low);
+        }
+    }
+
+    fn all_high(&self) -> bool {
+        self.memory.values().all(|m| *m == PulseFrequency::High)
+    }
+
+    fn process(&mut self, pulse: &Pulse) -> Vec<Pulse> {
+        self.memory.insert(pulse.src.clone(), pulse.freq);
+
+        let freq = if self.all_high() {
+            PulseFrequency::Low
+        } else {
+            PulseFrequency::High
+        };
+
+        self.connects_to
+            .iter()
+            .map(|mn| Pulse {
+                freq,
+                src: self.name.clone(),
+                dst: mn.clone(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Broadcaster {
+    name: ModuleName,
+    connects_to: Vec<ModuleName>,
+}
+
+impl Broadcaster {
+    fn new(name: ModuleName, connects_to: Vec<ModuleName>) -> Broadcaster {
+        Broadcaster { name, connects_to }
+    }
+
+    fn process(&mut self, pulse: &Pulse) -> Vec<Pulse> {
+        let mut pulses: Vec<Pulse> = Vec::new();
+        for dest in &self.connects_to {
+            pulses.push(Pulse {
+                freq: pulse.freq,
+                src: self.name.clone(),
+                dst: dest.to_string(),
+            })
+        }
+        pulses
+    }
+}
+
+pub fn parse_modules(input: &str) -> Result<HashMap<ModuleName, ModuleType>, ParseError> {
+    let mut modules: HashMap<ModuleName, ModuleType> = HashMap::new();
+    let mut connections: HashMap<ModuleName, Vec<ModuleName>> = HashMap::new();
+    for mut line in aoc_core::lines(input) {
+        let kind = line.rest().chars().next().filter(|c| "%&".contains(*c));
+        if kind.is_some() {
+            line.char()?;
+        }
+        let at = line.clone();
+        let name = line.word()?.to_string();
+        line.expect(" -> ")?;
+        let dests = line.separated(",", |s| Ok(s.word()?.to_string()))?;
+        line.end()?;
+
+        for dest in &dests {
+            connections
+                .entry(dest.to_string())
+                .or_default()
+                .push(name.clone());
+        }
+
+        let module: ModuleType = match kind {
+            Some('%') => ModuleType::FlipFlop(FlipFlop::new(name, dests)),
+            Some('&') => ModuleType::Conjunction(Conjunction::new(name, dests)),
+            _ if name == "broadcaster" => ModuleType::Broadcaster(Broadcaster::new(name, dests)),
+            _ => return Err(at.error("'%', '&' or 'broadcaster'")),
+        };
+        modules.insert(module.name(), module);
+    }
+
+    // every button press starts at the broadcaster
+    if !matches!(modules.get("broadcaster"), Some(ModuleType::Broadcaster(_))) {
+        return Err(aoc_core::unexpected_end(input, "a 'broadcaster' module"));
+    }
+
+    // insert outputs
+    for dest in connections.keys() {
+        if !modules.contains_key(dest) {
+            modules.insert(dest.to_string(), ModuleType::Output(dest.to_string()));
+        }
+    }
+
+    for module in modules.values_mut() {
+        if let ModuleType::Conjunction(conj) = module {
+            let inputs = connections.get(&conj.name).cloned().unwrap_or_default();
+            conj.init_inputs(inputs);
+        }
+    }
+
+    Ok(modules)
+}
+
+pub fn part1(modules: &HashMap<ModuleName, ModuleType>) -> usize {
+    let modules = &mut modules.clone();
+    let mut lows = 0;
+    let mut highs = 0;
+    for _ in 0..BUTTON_PRESSES {
+        let mut pulses = VecDeque::from(vec![Pulse::initial()]);
+        lows += 1;
+        while let Some(pulse) = pulses.pop_front() {
+            let module = modules.get_mut(&pulse.dst).unwrap();
+            let new_pulses = match module {
+                ModuleType::Broadcaster(b) => b.process(&pulse),
+                ModuleType::FlipFlop(f) => f.process(&pulse),
+                ModuleType::Conjunction(c) => c.process(&pulse),
+                ModuleType::Output(_) => Vec::new(),
+            };
+            for pulse in &new_pulses {
+                match pulse.freq {
+                    PulseFrequency::Low => lows += 1,
+                    PulseFrequency::High => highs += 1,
+                }
+            }
+            pulses.extend(new_pulses);
+        }
+    }
+    info!("Low pulses sent: {lows}");
+    info!("High pulses sent: {highs}");
+    lows * highs
+}
+
+/// the conjunction feeding `OUTPUT`, if there's one
+fn find_last_conjunction(modules: &HashMap<ModuleName, ModuleType>) -> Option<Conjunction> {
+    for mod_ in modules.values() {
+        if let ModuleType::Conjunction(c) = mod_
+            && c.connects_to.contains(&OUTPUT.to_string())
+        {
+            return Some(c.clone());
+        }
+    }
+    None
+}
+
+fn find_lowest_presses(modules: &mut HashMap<ModuleName, ModuleType>) -> Option<usize> {
+    let last_conjunction = find_last_conjunction(modules)?;
+    let num_inputs = last_conjunction.memory.len();
+    if num_inputs == 0 {
+        return None;
+    }
+    let mut last_high_pulses: HashMap<ModuleName, usize> = HashMap::new();
+    let mut cycles: HashMap<ModuleName, usize> = HashMap::new();
+
+    let mut presses = 0;
+    loop {
+        presses += 1;
+        let mut pulses = VecDeque::from(vec![Pulse::initial()]);
+        while let Some(pulse) = pulses.pop_front() {
+            let module = modules.get_mut(&pulse.dst).unwrap();
+            let new_pulses = match module {
+                ModuleType::Broadcaster(b) => b.process(&pulse),
+                ModuleType::FlipFlop(f) => f.process(&pulse),
+                ModuleType::Conjunction(c) => c.process(&pulse),
+                ModuleType::Output(_) => Vec::new(),
+            };
+            for pulse in &new_pulses {
+                if pulse.dst == last_conjunction.name && pulse.freq == PulseFrequency::High {
+                    match last_high_pulses.get(&pulse.src) {
+                        Some(v) => cycles.insert(pulse.src.to_string(), presses - v),
+                        None => last_high_pulses.insert(pulse.src.to_string(), presses),
+                    };
+
+                    if cycles.len() == num_inputs {
+                        // we found the cycles for all the last conjunction inputs
+                        return Some(lcm_all(cycles.values().copied()));
+                    }
+                }
+            }
+            pulses.extend(new_pulses);
+        }
+    }
+}
+
+/// presses until a low pulse reaches `OUTPUT`, `None` when no conjunction
+/// feeds it, as in the examples
+pub fn part2(modules: &HashMap<ModuleName, ModuleType>) -> Option<usize> {
+    find_lowest_presses(&mut modules.clone())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<ModuleName, ModuleType>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_modules(input)
+    }
+
+    fn part1(modules: &Self::Input) -> Answer {
+        part1(modules).into()
+    }
+
+    fn part2(modules: &Self::Input) -> Answer {
+        part2(modules).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day20 {
+    /// a few counters of `size` flip-flops each (from 2 to 16), wired like
+    /// the puzzle's so that each rolls over at its own period and `OUTPUT`
+    /// only gets a low pulse once they all do at the same press
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        fn fresh_name(names: &mut Vec<String>, rng: &mut Rng) -> String {
+            loop {
+                let name: String = (0..2)
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect();
+                if !names.contains(&name) {
+                    names.push(name.clone());
+                    return name;
+                }
+            }
+        }
+
+        let bits = size.clamp(2, 16);
+        let mut names = vec![OUTPUT.to_string()];
+        let last = fresh_name(&mut names, rng);
+        let mut lines = vec![format!("&{} -> {}", last, OUTPUT)];
+        let mut firsts = Vec::new();
+        for _ in 0..rng.range(2, 4) {
+            let flip_flops: Vec<String> = (0..bits).map(|_| fresh_name(&mut names, rng)).collect();
+            let hub = fresh_name(&mut names, rng);
+            let inverter = fresh_name(&mut names, rng);
+            // the hub sees the bits set in the period and, once they all are,
+            // sets the others to carry the counter back round to zero
+            let period = rng.range(1 << (bits - 1), (1 << bits) - 1) | 1;
+            let mut hub_to = vec![flip_flops[0].clone()];
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut to: Vec<String> = flip_flops.get(bit + 1).cloned().into_iter().collect();
+                match period >> bit & 1 {
+                    1 => to.push(hub.clone()),
+                    _ => hub_to.push(flip_flop.clone()),
+                }
+                lines.push(format!("%{} -> {}", flip_flop, to.join(", ")));
+            }
+            hub_to.push(inverter.clone());
+            lines.push(format!("&{} -> {}", hub, hub_to.join(", ")));
+            lines.push(format!("&{} -> {}", inverter, last));
+            firsts.push(flip_flops[0].clone());
+        }
+        lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+        rng.shuffle(&mut lines);
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+
+    /// the modules sorted by name, leaving out the outputs nothing defines
+    fn render(modules: &Self::Input) -> String {
+        let mut lines: Vec<String> = modules
+            .values()
+            .filter_map(|module| {
+                let (kind, name, connects_to) = match module {
+                    ModuleType::FlipFlop(f) => ("%", &f.name, &f.connects_to),
+                    ModuleType::Conjunction(c) => ("&", &c.name, &c.connects_to),
+                    ModuleType::Broadcaster(b) => ("", &b.name, &b.connects_to),
+                    ModuleType::Output(_) => return None,
+                };
+                Some(format!("{}{} -> {}\n", kind, name, connects_to.join(", ")))
+            })
+            .collect();
+        lines.sort_by(|a, b| {
+            a.trim_start_matches(['%', '&'])
+                .cmp(b.trim_start_matches(['%', '&']))
+        });
+        lines.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day20>(EXAMPLE, 1, 32000000);
+        assert_example::<Day20>(EXAMPLE_2, 1, 11687500);
+    }
+
+    #[test]
+    fn test_broadcaster_is_needed() {
+        let error = |input: &str| parse_modules(input).err().map(|e| (e.line, e.column));
+        assert_eq!(error("%a -> b\n&b -> a\n"), Some((3, 1)));
+        assert_eq!(error(""), Some((1, 1)));
+        assert_eq!(error("broadcaster -> a\n%a -> b\n"), None);
+    }
+
+    #[test]
+    fn test_examples_have_no_part_2() {
+        for example in [EXAMPLE, EXAMPLE_2] {
+            let modules = Day20::parse(example).unwrap();
+            assert_eq!(Day20::part2(&modules), Answer::None);
+        }
+    }
+}
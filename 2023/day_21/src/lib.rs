@@ -0,0 +1,174 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug};
+use aoc_grid::{Direction, Grid, Position};
+use aoc_math::Quadratic;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const STEPS_P1: u8 = 64;
+const STEPS_P2: usize = 26501365;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Plot,
+    Rock,
+}
+
+impl Tile {
+    fn to_char(self) -> char {
+        match self {
+            Tile::Plot => '.',
+            Tile::Rock => '#',
+        }
+    }
+}
+
+pub struct Garden {
+    tiles: Grid<Tile>,
+    start: Position,
+}
+
+impl Garden {
+    pub fn from_text(s: &str) -> Result<Garden, ParseError> {
+        let chars = Grid::parse(s, "'.', '#' or 'S'", |c| ".#S".contains(c).then_some(c))?;
+        let start = chars
+            .find(|&c| c == 'S')
+            .ok_or_else(|| aoc_core::unexpected_end(s, "a start tile 'S'"))?;
+        let tiles = chars.map(|&c| match c {
+            '#' => Tile::Rock,
+            _ => Tile::Plot,
+        });
+        Ok(Garden { tiles, start })
+    }
+}
+
+pub fn reachable_plots(garden: &Garden, steps: u8) -> usize {
+    let mut positions = HashSet::new();
+    positions.insert(garden.start);
+    for _ in 0..steps {
+        let mut new_positions = HashSet::new();
+        for pos in positions {
+            for neighbor in garden.tiles.neighbours4(pos) {
+                if garden.tiles[neighbor] == Tile::Plot {
+                    new_positions.insert(neighbor);
+                }
+            }
+        }
+        positions = new_positions;
+    }
+    positions.len()
+}
+
+pub fn part1(garden: &Garden) -> usize {
+    debug!(
+        "{}Start: x{} y{}",
+        garden.tiles.render(|_, tile| tile.to_char()),
+        garden.start.x,
+        garden.start.y
+    );
+    reachable_plots(garden, STEPS_P1)
+}
+
+pub fn part2(garden: &Garden) -> usize {
+    let w = garden.tiles.width();
+    let half = w / 2;
+    let sample_at = [half, half + w, half + 2 * w];
+
+    let max_steps = *sample_at.last().unwrap();
+    // the garden repeats infinitely, so positions here are unbounded
+    let mut distances: HashMap<(isize, isize), usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    let start = (garden.start.x as isize, garden.start.y as isize);
+
+    queue.push_back((start, 0));
+    distances.insert(start, 0);
+
+    while let Some(((x, y), dist)) = queue.pop_front()
+        && dist < max_steps
+    {
+        for dir in Direction::ALL {
+            let (dx, dy) = dir.delta();
+            let neighbor = (x + dx, y + dy);
+            if *garden.tiles.get_wrapping(neighbor.0, neighbor.1) == Tile::Plot
+                && !distances.contains_key(&neighbor)
+            {
+                distances.insert(neighbor, dist + 1);
+                queue.push_back((neighbor, dist + 1));
+            }
+        }
+    }
+
+    let mut samples = Vec::new();
+    for &target in &sample_at {
+        let count = distances
+            .values()
+            .filter(|&&d| d <= target && d % 2 == target % 2)
+            .count();
+        samples.push((target as i128, count as i128));
+    }
+
+    // the reachable plots grow quadratically every time a full garden is crossed
+    let quadratic = Quadratic::through(samples.try_into().unwrap()).unwrap();
+    let plots = quadratic.at(STEPS_P2 as i128);
+    (plots.numerator() / plots.denominator()) as usize
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Garden::from_text(input)
+    }
+
+    fn part1(garden: &Self::Input) -> Answer {
+        part1(garden).into()
+    }
+
+    fn part2(garden: &Self::Input) -> Answer {
+        part2(garden).into()
+    }
+}
+
+impl Generate for Day21 {
+    /// a garden of odd side near `size`, starting in the middle with its row,
+    /// column and edges clear of rocks. Part 2's count only holds on sides
+    /// where its fixed number of steps ends half a garden past a whole one,
+    /// like the puzzle's 131.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let side = size.max(3) | 1;
+        let middle = side / 2;
+        let mut garden = Grid::new(side, side, '.');
+        for pos in garden.positions() {
+            let clear = [pos.x, pos.y]
+                .iter()
+                .any(|&at| at == 0 || at == middle || at == side - 1);
+            if !clear && rng.chance(0.15) {
+                garden[pos] = '#';
+            }
+        }
+        garden[Position::new(middle, middle)] = 'S';
+        garden.render(|_, &c| c)
+    }
+
+    fn render(garden: &Self::Input) -> String {
+        garden.tiles.render(|position, &tile| match tile {
+            _ if position == garden.start => 'S',
+            Tile::Plot => '.',
+            Tile::Rock => '#',
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(reachable_plots(&garden, 6), 16);
+    }
+}
@@ -0,0 +1,371 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Coord {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Coord {
+    fn from_line(line: &mut Scanner) -> Result<Coord, ParseError> {
+        let x = line.number()?;
+        line.expect(",")?;
+        let y = line.number()?;
+        line.expect(",")?;
+        let at = line.clone();
+        let z = line.number()?;
+        if z == 0 {
+            // the ground is at 0
+            return Err(at.error("a height of at least 1"));
+        }
+        Ok(Coord { x, y, z })
+    }
+
+    fn above(&self) -> Coord {
+        Coord {
+            x: self.x,
+            y: self.y,
+            z: self.z + 1,
+        }
+    }
+
+    fn below(&self) -> Coord {
+        Coord {
+            x: self.x,
+            y: self.y,
+            z: self.z - 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Brick {
+    a: Coord,
+    b: Coord,
+}
+
+impl Brick {
+    fn from_line(line: &mut Scanner) -> Result<Brick, ParseError> {
+        let a = Coord::from_line(line)?;
+        line.expect("~")?;
+        let b = Coord::from_line(line)?;
+        line.end()?;
+        Ok(Brick { a, b })
+    }
+
+    fn all_coords(&self) -> Vec<Coord> {
+        let mut occupied = HashSet::new();
+        occupied.insert(self.a.clone());
+        occupied.insert(self.b.clone());
+        for x in self.a.x..self.b.x {
+            occupied.insert(Coord {
+                x,
+                y: self.a.y,
+                z: self.a.z,
+            });
+        }
+        for y in self.a.y..self.b.y {
+            occupied.insert(Coord {
+                x: self.a.x,
+                y,
+                z: self.a.z,
+            });
+        }
+        for z in self.a.z..self.b.z {
+            occupied.insert(Coord {
+                x: self.a.x,
+                y: self.a.y,
+                z,
+            });
+        }
+        occupied.into_iter().collect()
+    }
+
+    fn can_drop(&self, space: &Space) -> bool {
+        let occupied = self.all_coords();
+        if occupied.iter().any(|o| o.z == 1) {
+            return false;
+        }
+        let mut below = occupied.iter().map(|o| space.get(&o.below()));
+        if !below.all(|b| b.is_none()) {
+            return false;
+        }
+        true
+    }
+
+    fn drop(&mut self) {
+        self.a.z -= 1;
+        self.b.z -= 1;
+    }
+}
+
+pub fn parse_bricks(s: &str) -> Result<Vec<Brick>, ParseError> {
+    let mut bricks = Vec::new();
+    for mut line in aoc_core::lines(s) {
+        bricks.push(Brick::from_line(&mut line)?);
+    }
+    Ok(bricks)
+}
+
+type BrickId = usize;
+
+struct Layer {
+    cubes: Vec<Vec<Option<BrickId>>>,
+}
+
+impl Layer {
+    fn new_empty(x_len: usize, y_len: usize) -> Layer {
+        let mut rows = Vec::new();
+        for _ in 0..y_len {
+            let cubes = (0..x_len).map(|_| None).collect();
+            rows.push(cubes);
+        }
+        Layer { cubes: rows }
+    }
+}
+
+struct Space {
+    layers: Vec<Layer>,
+}
+
+impl Space {
+    fn new_empty(x_len: usize, y_len: usize, z_len: usize) -> Space {
+        let layers = (0..z_len).map(|_| Layer::new_empty(x_len, y_len)).collect();
+        Space { layers }
+    }
+
+    fn get(&self, coord: &Coord) -> Option<BrickId> {
+        self.layers[coord.z].cubes[coord.y][coord.x]
+    }
+
+    fn insert(&mut self, brick_id: &BrickId, brick: &Brick) {
+        let occupied = brick.all_coords();
+        for coord in occupied {
+            self.layers[coord.z].cubes[coord.y][coord.x] = Some(*brick_id);
+        }
+    }
+
+    fn compute_supports_map(&self) -> HashMap<BrickId, Vec<BrickId>> {
+        let mut map: HashMap<BrickId, Vec<BrickId>> = HashMap::new();
+        for (z, layer) in self.layers[..self.layers.len() - 1].iter().enumerate() {
+            for (y, row) in layer.cubes.iter().enumerate() {
+                for (x, current) in row.iter().enumerate() {
+                    let coord = Coord { x, y, z };
+                    let above = self.get(&coord.above());
+
+                    if let Some(c) = current {
+                        if !map.contains_key(c) {
+                            map.insert(*c, Vec::new());
+                        };
+                        if let Some(b) = above
+                            && b != *c
+                        {
+                            map.get_mut(c).unwrap().push(b);
+                        }
+                    }
+                }
+            }
+        }
+        map
+    }
+}
+
+fn get_bricks_bounds(bricks: &[Brick]) -> (usize, usize, usize) {
+    let (mut max_x, mut max_y, mut max_z) = (0, 0, 0);
+    for brick in bricks {
+        let x = max(brick.a.x, brick.b.x);
+        if x > max_x {
+            max_x = x;
+        }
+
+        let y = max(brick.a.y, brick.b.y);
+        if y > max_y {
+            max_y = y;
+        }
+
+        let z = max(brick.a.z, brick.b.z);
+        if z > max_z {
+            max_z = z;
+        }
+    }
+    (max_x, max_y, max_z)
+}
+
+fn invert_map(map: &HashMap<BrickId, Vec<BrickId>>) -> HashMap<BrickId, Vec<BrickId>> {
+    let mut inverted: HashMap<BrickId, Vec<BrickId>> = HashMap::new();
+    for (k, v) in map {
+        for value in v {
+            inverted.entry(*value).or_default().push(*k);
+        }
+    }
+    inverted
+}
+
+type BrickMap = HashMap<BrickId, Brick>;
+
+fn simulate_fall(bricks: &mut [Brick]) -> Space {
+    bricks.sort_by_key(|a| a.a.z);
+    let mut brickmap: BrickMap = bricks
+        .iter()
+        .enumerate()
+        .map(|(i, b)| (i, b.clone()))
+        .collect();
+    let (max_x, max_y, max_z) = get_bricks_bounds(bricks);
+    let mut space = Space::new_empty(max_x + 1, max_y + 1, max_z + 1);
+
+    for brick_id in 0..bricks.len() {
+        let brick = brickmap.get_mut(&brick_id).unwrap();
+        while brick.can_drop(&space) {
+            brick.drop();
+        }
+        space.insert(&brick_id, brick);
+    }
+    space
+}
+
+fn count_affected_bricks(removed: BrickId, supports: &HashMap<BrickId, Vec<BrickId>>) -> usize {
+    fn _count_affected_bricks(
+        removed: &mut HashSet<BrickId>,
+        supports: &HashMap<BrickId, Vec<BrickId>>,
+        supported_by: &HashMap<BrickId, Vec<BrickId>>,
+    ) -> usize {
+        let mut bricks_above = HashSet::new();
+        for rem in removed.iter() {
+            let supported = supports.get(rem).unwrap();
+            for supp in supported {
+                if !removed.contains(supp) {
+                    bricks_above.insert(supp);
+                }
+            }
+        }
+
+        let mut new_removed = HashSet::new();
+        for brick_above in bricks_above {
+            let supports = supported_by.get(brick_above).unwrap();
+            if supports.iter().all(|s| removed.contains(s)) {
+                new_removed.insert(brick_above);
+            }
+        }
+
+        if !new_removed.is_empty() {
+            removed.extend(new_removed);
+            return _count_affected_bricks(removed, supports, supported_by);
+        }
+        removed.len()
+    }
+
+    let supported_by = invert_map(supports);
+    let mut removed_set = HashSet::new();
+    removed_set.insert(removed);
+    _count_affected_bricks(&mut removed_set, supports, &supported_by) - 1
+}
+
+fn supports_map(bricks: &[Brick]) -> HashMap<BrickId, Vec<BrickId>> {
+    let mut bricks = bricks.to_vec();
+    let space = simulate_fall(&mut bricks);
+    space.compute_supports_map()
+}
+
+pub fn part1(bricks: &[Brick]) -> usize {
+    let supports_map = supports_map(bricks);
+    let supported_by_map = invert_map(&supports_map);
+
+    let mut sum = 0;
+    'outer: for (brick, bricks_above) in &supports_map {
+        for brick_above in bricks_above {
+            let supports = supported_by_map.get(brick_above).unwrap();
+            if supports.iter().all(|b| b == brick) {
+                continue 'outer;
+            }
+        }
+        sum += 1;
+    }
+    sum
+}
+
+pub fn part2(bricks: &[Brick]) -> usize {
+    let supports_map = supports_map(bricks);
+    let mut sum = 0;
+    for brick_id in supports_map.keys() {
+        sum += count_affected_bricks(*brick_id, &supports_map);
+    }
+    sum
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_bricks(input)
+    }
+
+    fn part1(bricks: &Self::Input) -> Answer {
+        part1(bricks).into()
+    }
+
+    fn part2(bricks: &Self::Input) -> Answer {
+        part2(bricks).into()
+    }
+}
+
+impl Generate for Day22 {
+    /// `size` bricks over a 10 by 10 area, floating at random heights
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut taken = HashSet::new();
+        let mut input = String::new();
+        let mut placed = 0;
+        while placed < size {
+            let (x, y) = (rng.range(0, 9), rng.range(0, 9));
+            let z = rng.range(1, 3 * size as i64);
+            let length = rng.range(0, 3);
+            let (dx, dy, dz) = match rng.below(3) {
+                0 => (1, 0, 0),
+                1 => (0, 1, 0),
+                _ => (0, 0, 1),
+            };
+            if x + dx * length > 9 || y + dy * length > 9 {
+                continue;
+            }
+            let cubes: Vec<(i64, i64, i64)> = (0..=length)
+                .map(|i| (x + dx * i, y + dy * i, z + dz * i))
+                .collect();
+            if cubes.iter().any(|cube| taken.contains(cube)) {
+                continue;
+            }
+            taken.extend(cubes);
+            let (x2, y2, z2) = (x + dx * length, y + dy * length, z + dz * length);
+            input.push_str(&format!("{},{},{}~{},{},{}\n", x, y, z, x2, y2, z2));
+            placed += 1;
+        }
+        input
+    }
+
+    fn render(bricks: &Self::Input) -> String {
+        bricks
+            .iter()
+            .map(|brick| {
+                let (a, b) = (&brick.a, &brick.b);
+                format!("{},{},{}~{},{},{}\n", a.x, a.y, a.z, b.x, b.y, b.z)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day22>(EXAMPLE, 1, 5);
+        assert_example::<Day22>(EXAMPLE, 2, 7);
+    }
+}
@@ -0,0 +1,190 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Direction, Grid, Position};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(PartialEq, Clone)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+
+impl Map {
+    pub fn from_text(s: &str) -> Result<Map, ParseError> {
+        let tiles = Grid::parse(s, "'#', '.' or a slope", |c| match c {
+            '#' => Some(Tile::Forest),
+            '.' => Some(Tile::Path),
+            _ => Direction::from_arrow(c).map(Tile::Slope),
+        })?;
+        let missing = || aoc_core::unexpected_end(s, "a path in the first and last rows");
+        let path_in = |y: usize| tiles.row(y).iter().position(|x| *x == Tile::Path);
+        let last = tiles.height().checked_sub(1).ok_or_else(missing)?;
+        let start = Position::new(path_in(0).ok_or_else(missing)?, 0);
+        let end = Position::new(path_in(last).ok_or_else(missing)?, last);
+
+        Ok(Map { tiles, start, end })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Path {
+    pos: Position,
+    visited: HashSet<Position>,
+    len: usize,
+}
+
+pub fn part1(map: &Map) -> usize {
+    let starting_path = Path {
+        pos: map.start,
+        visited: HashSet::from([map.start]),
+        len: 0,
+    };
+
+    let mut paths: VecDeque<Path> = VecDeque::from(vec![starting_path.clone()]);
+    let mut longest = starting_path;
+
+    while let Some(path) = paths.pop_front() {
+        if path.pos == map.end && path.len > longest.len {
+            longest = path;
+            continue;
+        }
+
+        let mut valid = Vec::new();
+        if let Tile::Slope(dir) = map.tiles[path.pos] {
+            let neighbor = map.tiles.step(path.pos, dir).unwrap();
+            if !path.visited.contains(&neighbor) {
+                valid.push(neighbor);
+            }
+        } else {
+            for neighbor in map.tiles.neighbours4(path.pos) {
+                if map.tiles[neighbor] != Tile::Forest && !path.visited.contains(&neighbor) {
+                    valid.push(neighbor);
+                }
+            }
+        }
+
+        for neigh in valid {
+            let mut new = path.clone();
+            new.visited.insert(neigh);
+            new.pos = neigh;
+            new.len += 1;
+            paths.push_back(new);
+        }
+    }
+
+    longest.len
+}
+
+fn longest_path(
+    map: &Map,
+    pos: Position,
+    visited: &mut HashSet<Position>,
+    len: usize,
+    best: &mut usize,
+) {
+    if pos == map.end && len > *best {
+        *best = len;
+    }
+
+    for next in map.tiles.neighbours4(pos) {
+        if map.tiles[next] == Tile::Forest || visited.contains(&next) {
+            continue;
+        }
+
+        visited.insert(next);
+        longest_path(map, next, visited, len + 1, best);
+        visited.remove(&next);
+    }
+}
+
+/// longest hike ignoring the slopes
+pub fn part2(map: &Map) -> usize {
+    let start = map.start;
+    let mut visited = HashSet::from([start]);
+    let mut best = 0;
+    longest_path(map, start, &mut visited, 0, &mut best);
+    best
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_text(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part2(map).into()
+    }
+}
+
+impl Generate for Day23 {
+    /// a `size` by `size` lattice of crossings joined by trails, with slopes
+    /// leading right or down out of each. Part 2 walks every path through
+    /// it, so `size` stops at the real input's 6.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let crossings = size.clamp(1, 6);
+        let spacing = 4;
+        let side = spacing * (crossings - 1) + 3;
+        let at = |i: usize| 1 + spacing * i;
+        let mut map = Grid::new(side, side, '#');
+        map[Position::new(at(0), 0)] = '.';
+        map[Position::new(at(crossings - 1), side - 1)] = '.';
+        let trail = |step: usize, slope: char| match step {
+            1 => slope,
+            step if step == spacing - 1 => slope,
+            _ => '.',
+        };
+        for y in 0..crossings {
+            for x in 0..crossings {
+                map[Position::new(at(x), at(y))] = '.';
+                // along the top and down the right side there's always a way
+                if x + 1 < crossings && (y == 0 || rng.chance(0.8)) {
+                    for step in 1..spacing {
+                        map[Position::new(at(x) + step, at(y))] = trail(step, '>');
+                    }
+                }
+                if y + 1 < crossings && (x + 1 == crossings || rng.chance(0.8)) {
+                    for step in 1..spacing {
+                        map[Position::new(at(x), at(y) + step)] = trail(step, 'v');
+                    }
+                }
+            }
+        }
+        map.render(|_, &c| c)
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.tiles.render(|_, tile| match tile {
+            Tile::Path => '.',
+            Tile::Forest => '#',
+            Tile::Slope(direction) => direction.to_arrow(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day23>(EXAMPLE, 1, 94);
+        assert_example::<Day23>(EXAMPLE, 2, 154);
+    }
+}
@@ -0,0 +1,282 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution, info};
+use aoc_math::least_squares;
+
+const AREA_MIN: f64 = 200000000000000.0;
+const AREA_MAX: f64 = 400000000000000.0;
+
+const N: usize = 6;
+/// part 2 lines the rock up against the first of these and the next three
+const MIN_HAILSTONES: usize = 4;
+const EPS: f64 = 1e-8;
+
+#[derive(Debug)]
+struct Position {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Position {
+    fn is_in_area(&self, min: f64, max: f64) -> bool {
+        self.x > min && self.x < max && self.y > min && self.y < max
+    }
+}
+
+#[derive(Debug)]
+struct Velocity {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+#[derive(Debug)]
+pub struct Hailstone {
+    position: Position,
+    velocity: Velocity,
+}
+
+impl Hailstone {
+    fn xy_intersection_with(&self, other: &Hailstone) -> Option<Position> {
+        let m_a = self.velocity.y / self.velocity.x;
+        let m_b = other.velocity.y / other.velocity.x;
+
+        //parallel
+        if (m_a - m_b).abs() < EPS {
+            return None;
+        }
+
+        let b_a = -self.position.x / self.velocity.x * self.velocity.y + self.position.y;
+        let b_b = -other.position.x / other.velocity.x * other.velocity.y + other.position.y;
+
+        let x = (b_b - b_a) / (m_a - m_b);
+        let y = m_a * x + b_a;
+
+        Some(Position { x, y, z: 0.0 })
+    }
+
+    fn is_in_future(&self, pos: &Position) -> bool {
+        if self.velocity.x < 0.0 && self.position.x < pos.x {
+            return false;
+        }
+        if self.velocity.x > 0.0 && self.position.x > pos.x {
+            return false;
+        }
+        if self.velocity.y < 0.0 && self.position.y < pos.y {
+            return false;
+        }
+        if self.velocity.y > 0.0 && self.position.y > pos.y {
+            return false;
+        }
+        true
+    }
+
+    fn equations_with(&self, other: &Hailstone) -> [Equation; 3] {
+        let xy = Equation {
+            a: [
+                self.velocity.y - other.velocity.y,
+                other.velocity.x - self.velocity.x,
+                0.0,
+                self.position.y - other.position.y,
+                other.position.x - self.position.x,
+                0.0,
+            ],
+            b: (self.position.x * self.velocity.y)
+                - (self.position.y * self.velocity.x)
+                - (other.position.x * other.velocity.y)
+                + (other.position.y * other.velocity.x),
+        };
+        let yz = Equation {
+            a: [
+                0.0,
+                self.velocity.z - other.velocity.z,
+                other.velocity.y - self.velocity.y,
+                0.0,
+                self.position.z - other.position.z,
+                other.position.y - self.position.y,
+            ],
+            b: (self.position.y * self.velocity.z)
+                - (self.position.z * self.velocity.y)
+                - (other.position.y * other.velocity.z)
+                + (other.position.z * other.velocity.y),
+        };
+        let xz = Equation {
+            a: [
+                self.velocity.z - other.velocity.z,
+                0.0,
+                other.velocity.x - self.velocity.x,
+                self.position.z - other.position.z,
+                0.0,
+                other.position.x - self.position.x,
+            ],
+            b: (self.position.x * self.velocity.z)
+                - (self.position.z * self.velocity.x)
+                - (other.position.x * other.velocity.z)
+                + (other.position.z * other.velocity.x),
+        };
+        [xy, yz, xz]
+    }
+}
+
+fn parse_triple(line: &mut Scanner) -> Result<[f64; 3], ParseError> {
+    let x = line.number()?;
+    line.expect(",")?;
+    let y = line.number()?;
+    line.expect(",")?;
+    let z = line.number()?;
+    Ok([x, y, z])
+}
+
+pub fn parse_hailstones(s: &str) -> Result<Vec<Hailstone>, ParseError> {
+    let mut hailstones = Vec::new();
+    for mut line in aoc_core::lines(s) {
+        let [x, y, z] = parse_triple(&mut line)?;
+        line.skip_spaces();
+        line.expect("@")?;
+        let [vx, vy, vz] = parse_triple(&mut line)?;
+        line.end()?;
+        let hailstone = Hailstone {
+            position: Position { x, y, z },
+            velocity: Velocity {
+                x: vx,
+                y: vy,
+                z: vz,
+            },
+        };
+        hailstones.push(hailstone);
+    }
+    if hailstones.len() < MIN_HAILSTONES {
+        return Err(aoc_core::unexpected_end(
+            s,
+            format!("at least {} hailstones", MIN_HAILSTONES),
+        ));
+    }
+    Ok(hailstones)
+}
+
+/// pairs of paths that cross in the future inside the `min..max` test area
+pub fn crossings_in_area(hailstones: &[Hailstone], min: f64, max: f64) -> usize {
+    let mut sum = 0;
+    for (i, a) in hailstones[..hailstones.len() - 1].iter().enumerate() {
+        for b in &hailstones[i + 1..] {
+            if let Some(i) = a.xy_intersection_with(b)
+                && i.is_in_area(min, max)
+                && a.is_in_future(&i)
+                && b.is_in_future(&i)
+            {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+pub fn part1(hailstones: &[Hailstone]) -> usize {
+    crossings_in_area(hailstones, AREA_MIN, AREA_MAX)
+}
+
+#[derive(Clone)]
+struct Equation {
+    // x, y, z, vx, vy, vz
+    a: [f64; N],
+    b: f64,
+}
+
+pub fn part2(hailstones: &[Hailstone]) -> Option<i64> {
+    let mut equations = Vec::new();
+
+    equations.extend(hailstones[0].equations_with(&hailstones[1]));
+    equations.extend(hailstones[0].equations_with(&hailstones[2]));
+    equations.extend(hailstones[0].equations_with(&hailstones[3]));
+
+    // more equations than unknowns, so take the least squares fit
+    let matrix: Vec<Vec<f64>> = equations.iter().map(|eq| eq.a.to_vec()).collect();
+    let rhs: Vec<f64> = equations.iter().map(|eq| eq.b).collect();
+    let [px, py, pz, vx, vy, vz]: [f64; N] = least_squares(&matrix, &rhs)?.try_into().ok()?;
+    info!("Rock position: ({}, {}, {})", px, py, pz);
+    info!("Rock velocity: ({}, {}, {})", vx, vy, vz);
+    Some((px + py + pz).round() as i64)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_hailstones(input)
+    }
+
+    fn part1(hailstones: &Self::Input) -> Answer {
+        part1(hailstones).into()
+    }
+
+    fn part2(hailstones: &Self::Input) -> Answer {
+        part2(hailstones).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day24 {
+    /// `size` hailstones (at least 4), each hit at a whole time by a rock
+    /// thrown from a whole position with a whole velocity
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let velocity = |rng: &mut Rng| {
+            [(); 3].map(|_| rng.range(1, 300) * if rng.chance(0.5) { 1 } else { -1 })
+        };
+        let rock = [(); 3].map(|_| rng.range(AREA_MIN as i64, AREA_MAX as i64));
+        let rock_velocity = velocity(rng);
+        let mut input = String::new();
+        for _ in 0..size.max(4) {
+            let time = rng.range(10_000_000_000, 1_000_000_000_000);
+            let v = velocity(rng);
+            let p: Vec<i64> = (0..3)
+                .map(|i| rock[i] + time * (rock_velocity[i] - v[i]))
+                .collect();
+            input.push_str(&format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                p[0], p[1], p[2], v[0], v[1], v[2]
+            ));
+        }
+        input
+    }
+
+    fn render(hailstones: &Self::Input) -> String {
+        hailstones
+            .iter()
+            .map(
+                |Hailstone {
+                     position: p,
+                     velocity: v,
+                 }| {
+                    format!("{}, {}, {} @ {}, {}, {}\n", p.x, p.y, p.z, v.x, v.y, v.z)
+                },
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(crossings_in_area(&hailstones, 7.0, 27.0), 2);
+        assert_example::<Day24>(EXAMPLE, 2, 47);
+    }
+
+    #[test]
+    fn test_at_least_four_hailstones() {
+        let three: String = EXAMPLE
+            .lines()
+            .take(3)
+            .map(|l| format!("{}\n", l))
+            .collect();
+        let error = parse_hailstones(&three).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert!(parse_hailstones("").is_err());
+    }
+}
@@ -0,0 +1,287 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, info};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+type Component = u16;
+
+#[derive(Clone, Debug)]
+pub struct Wire {
+    a: Component,
+    b: Component,
+}
+
+fn get_wire_from_name(map: &mut HashMap<String, Component>, name: &str) -> Component {
+    match map.get(name) {
+        Some(w) => *w,
+        None => {
+            let next = map.len() as Component;
+            map.insert(name.to_string(), next);
+            next
+        }
+    }
+}
+
+pub fn parse_connections(input: &str) -> Result<Vec<Wire>, ParseError> {
+    let mut map = HashMap::new();
+    let mut connections = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        let first_name = line.word()?.to_string();
+        line.expect(":")?;
+        let first = get_wire_from_name(&mut map, &first_name);
+
+        // at least one connection per line
+        let mut part = line.word()?;
+        loop {
+            if part == first_name {
+                return Err(line.error(format!("a component other than '{}'", first_name)));
+            }
+            let wire = get_wire_from_name(&mut map, part);
+            connections.push(Wire { a: first, b: wire });
+            line.skip_spaces();
+            if line.is_done() {
+                break;
+            }
+            part = line.word()?;
+        }
+    }
+    Ok(connections)
+}
+
+fn rand_idx(max: usize) -> usize {
+    let t = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    t.as_nanos() as usize % max
+}
+
+#[derive(Clone)]
+struct Graph {
+    wires: Vec<Wire>,
+    nodes: usize,
+    counts: HashMap<Component, usize>,
+}
+
+impl Graph {
+    fn from_wires(wires: &[Wire]) -> Graph {
+        let mut counts = HashMap::new();
+        for wire in wires {
+            counts.insert(wire.a, 1);
+            counts.insert(wire.b, 1);
+        }
+        Graph {
+            wires: wires.to_vec(),
+            nodes: counts.len(),
+            counts,
+        }
+    }
+
+    fn kargers_once(&mut self) {
+        while self.nodes > 2 {
+            let idx = rand_idx(self.wires.len());
+            let wire = self.wires.remove(idx);
+
+            let keep = wire.a;
+            let remove = wire.b;
+
+            let removed_count = self.counts.remove(&remove).unwrap();
+            *self.counts.get_mut(&keep).unwrap() += removed_count;
+
+            for wire in self.wires.iter_mut() {
+                if wire.a == remove {
+                    wire.a = keep;
+                }
+                if wire.b == remove {
+                    wire.b = keep;
+                }
+            }
+
+            self.wires.retain(|w| w.a != w.b);
+            self.nodes -= 1;
+        }
+    }
+}
+
+/// the number of wire-disjoint paths from `source` to `sink`, counting no
+/// further than `limit`
+fn disjoint_paths(
+    neighbours: &[Vec<(usize, usize)>],
+    source: usize,
+    sink: usize,
+    limit: usize,
+) -> usize {
+    // each wire can carry one path either way; `used[w]` is +1 when a path
+    // runs from its lower to its higher end and -1 the other way
+    let wires = neighbours.iter().flatten().map(|&(_, w)| w + 1).max();
+    let mut used = vec![0i8; wires.unwrap_or(0)];
+    let mut paths = 0;
+    while paths < limit {
+        let mut came_from = vec![None; neighbours.len()];
+        came_from[source] = Some((source, usize::MAX));
+        let mut queue = VecDeque::from([source]);
+        while let Some(at) = queue.pop_front()
+            && came_from[sink].is_none()
+        {
+            for &(next, wire) in &neighbours[at] {
+                let direction = if at < next { 1 } else { -1 };
+                if came_from[next].is_none() && used[wire] != direction {
+                    came_from[next] = Some((at, wire));
+                    queue.push_back(next);
+                }
+            }
+        }
+        if came_from[sink].is_none() {
+            break;
+        }
+        let mut at = sink;
+        while let Some((previous, wire)) = came_from[at].filter(|_| at != source) {
+            used[wire] += if previous < at { 1 } else { -1 };
+            at = previous;
+        }
+        paths += 1;
+    }
+    paths
+}
+
+/// whether the fewest wires that split the components in two is exactly
+/// three, which is what the random contractions below look for
+fn has_cut_of_three(graph: &Graph) -> bool {
+    let mut neighbours = vec![Vec::new(); graph.nodes];
+    for (index, wire) in graph.wires.iter().enumerate() {
+        neighbours[wire.a as usize].push((wire.b as usize, index));
+        neighbours[wire.b as usize].push((wire.a as usize, index));
+    }
+    // any cut separates the first component from some other one
+    (1..graph.nodes)
+        .map(|sink| disjoint_paths(&neighbours, 0, sink, 4))
+        .min()
+        == Some(3)
+}
+
+/// product of the two group sizes, if three wires split the components in two
+pub fn part1(connections: &[Wire]) -> Option<usize> {
+    let graph = Graph::from_wires(connections);
+    if graph.nodes < 2 || !has_cut_of_three(&graph) {
+        return None;
+    }
+
+    loop {
+        let mut cloned = graph.clone();
+        cloned.kargers_once();
+
+        if cloned.wires.len() == 3 {
+            let a = cloned.counts.get(&cloned.wires[0].a).unwrap();
+            let b = cloned.counts.get(&cloned.wires[0].b).unwrap();
+            info!("Remaining group sizes: {} & {}", a, b);
+            return Some(a * b);
+        }
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Wire>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_connections(input)
+    }
+
+    fn part1(connections: &Self::Input) -> Answer {
+        part1(connections).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day25 {
+    /// two groups of at least `size` components, each wired to four or more
+    /// others in its group, and just three wires between them
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let first = size.max(5);
+        let groups = [0..first, first..first + first + rng.below(first)];
+        let mut names: Vec<String> = Vec::new();
+        while names.len() < groups[1].end {
+            let name: String = (0..3)
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let mut wires = BTreeSet::new();
+        let mut degrees = vec![0; names.len()];
+        let mut wire = |a: usize, b: usize, degrees: &mut Vec<usize>| {
+            if a != b && wires.insert((a.min(b), a.max(b))) {
+                degrees[a] += 1;
+                degrees[b] += 1;
+            }
+        };
+        for group in &groups {
+            for a in group.clone() {
+                while degrees[a] < 4 {
+                    wire(
+                        a,
+                        rng.range(group.start as i64, group.end as i64 - 1) as usize,
+                        &mut degrees,
+                    );
+                }
+            }
+        }
+        let mut crossing = 0;
+        while crossing < 3 {
+            let (a, b) = (
+                rng.range(0, first as i64 - 1),
+                rng.range(first as i64, names.len() as i64 - 1),
+            );
+            let before = degrees[a as usize];
+            wire(a as usize, b as usize, &mut degrees);
+            crossing += degrees[a as usize] - before;
+        }
+
+        // each wire is listed once, on either end's line
+        let mut connected: Vec<Vec<&str>> = vec![Vec::new(); names.len()];
+        for (a, b) in wires {
+            let (from, to) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            connected[from].push(&names[to]);
+        }
+        let mut input = String::new();
+        for (name, to) in names.iter().zip(&connected) {
+            if !to.is_empty() {
+                input.push_str(&format!("{}: {}\n", name, to.join(" ")));
+            }
+        }
+        input
+    }
+
+    /// a line per wire, naming the components by the order they first
+    /// showed up in
+    fn render(connections: &Self::Input) -> String {
+        connections
+            .iter()
+            .map(|wire| format!("c{}: c{}\n", wire.a, wire.b))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day25>(EXAMPLE, 1, 54);
+    }
+
+    #[test]
+    fn test_three_wires_must_split_the_graph() {
+        let error = Day25::parse("abc: def abc\n").err().unwrap();
+        assert_eq!(error.expected, "a component other than 'abc'");
+
+        // nothing, a single wire, and a ring of four that only splits
+        // by cutting two wires
+        for text in ["", "abc: def\n", "a: b\nb: c\nc: d\nd: a\n"] {
+            let connections = Day25::parse(text).unwrap();
+            assert_eq!(Day25::part1(&connections), Answer::None);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+pub fn parse_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut left_list: Vec<i32> = Vec::new();
+    let mut right_list: Vec<i32> = Vec::new();
+
+    for mut line in aoc_core::lines(input) {
+        left_list.push(line.number()?);
+        right_list.push(line.number()?);
+        line.end()?;
+    }
+
+    left_list.sort();
+    right_list.sort();
+    Ok((left_list, right_list))
+}
+
+pub fn part_one(left_list: &[i32], right_list: &[i32]) -> i32 {
+    let mut total_distance: i32 = 0;
+    for (l, r) in left_list.iter().zip(right_list.iter()) {
+        let distance = l - r;
+        total_distance += distance.abs();
+    }
+    total_distance
+}
+
+pub fn part_two(left_list: &[i32], right_list: &[i32]) -> i32 {
+    let right_list_len = right_list.len();
+    let mut total_similarity_score: i32 = 0;
+    let mut r_idx: usize = 0;
+    for l in left_list {
+        while r_idx < right_list_len && &right_list[r_idx] < l {
+            r_idx += 1;
+        }
+
+        // left_list is sorted too, so a repeated number counts the same
+        // appearances again
+        let mut n_appearances = 0;
+        while r_idx + n_appearances < right_list_len && &right_list[r_idx + n_appearances] == l {
+            n_appearances += 1;
+        }
+        total_similarity_score += l * n_appearances as i32;
+    }
+
+    total_similarity_score
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lists(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (left_list, right_list) = input;
+        part_one(left_list, right_list).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (left_list, right_list) = input;
+        part_two(left_list, right_list).into()
+    }
+}
+
+impl Generate for Day01 {
+    /// two lists of `size` location IDs, sharing some
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let ids: Vec<i64> = (0..size.max(1))
+            .map(|_| rng.range(10_000, 99_999))
+            .collect();
+        (0..size)
+            .map(|_| format!("{}   {}\n", rng.pick(&ids), rng.pick(&ids)))
+            .collect()
+    }
+
+    /// pairing off the smallest IDs left on both lists one at a time, and
+    /// counting each left ID's appearances on the right all over again
+    fn brute_force((left_list, right_list): &Self::Input, part: u8) -> Option<Answer> {
+        let answer: i64 = match part {
+            1 => {
+                let (mut left, mut right) = (left_list.clone(), right_list.clone());
+                let mut total = 0;
+                while let (Some(l), Some(r)) = (left.iter().min(), right.iter().min()) {
+                    total += (*l as i64 - *r as i64).abs();
+                    let (l, r) = (*l, *r);
+                    left.remove(left.iter().position(|&x| x == l).unwrap());
+                    right.remove(right.iter().position(|&x| x == r).unwrap());
+                }
+                total
+            }
+            _ => left_list
+                .iter()
+                .map(|&l| l as i64 * right_list.iter().filter(|&&r| r == l).count() as i64)
+                .sum(),
+        };
+        Some(answer.into())
+    }
+
+    fn render((left_list, right_list): &Self::Input) -> String {
+        left_list
+            .iter()
+            .zip(right_list)
+            .map(|(left, right)| format!("{}   {}\n", left, right))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day01>(EXAMPLE, 1, 11);
+        assert_example::<Day01>(EXAMPLE, 2, 31);
+        let lists = parse_lists(EXAMPLE).unwrap();
+        assert_eq!(Day01::brute_force(&lists, 1), Some(Answer::from(11)));
+        assert_eq!(Day01::brute_force(&lists, 2), Some(Answer::from(31)));
+    }
+}
@@ -0,0 +1,156 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<i16>>, ParseError> {
+    let mut reports: Vec<Vec<i16>> = Vec::new();
+
+    for mut line in aoc_core::lines(input) {
+        let levels: Vec<i16> = line.numbers()?;
+        line.end()?;
+        reports.push(levels);
+    }
+    Ok(reports)
+}
+
+pub fn part_one(reports: &[Vec<i16>]) -> usize {
+    let mut safe_reports: usize = 0;
+    for levels in reports {
+        if is_safe_report(levels) {
+            safe_reports += 1;
+        }
+    }
+    safe_reports
+}
+
+pub fn part_two(reports: &[Vec<i16>]) -> usize {
+    let mut safe_reports: usize = 0;
+    for levels in reports {
+        for l in 0..levels.len() {
+            let mut dampened_levels = levels.clone();
+            dampened_levels.remove(l);
+
+            if is_safe_report(&dampened_levels) {
+                safe_reports += 1;
+                break;
+            }
+        }
+    }
+    safe_reports
+}
+
+/// a report of a single level has nothing to go wrong
+pub fn is_safe_report(report: &[i16]) -> bool {
+    if report.len() < 2 {
+        return true;
+    }
+    // in i32, as levels far apart don't fit their difference in an i16
+    let is_ascending: bool = report[1] as i32 - report[0] as i32 > 0;
+    for i in 1..report.len() {
+        let diff = report[i] as i32 - report[i - 1] as i32;
+        let ascends: bool = diff > 0;
+
+        if (is_ascending != ascends) || diff.abs() < 1 || diff.abs() > 3 {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i16>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        part_one(reports).into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        part_two(reports).into()
+    }
+}
+
+impl Generate for Day02 {
+    /// `size` reports of 5 to 8 levels, mostly steady with the odd jump
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let direction = *rng.pick(&[-1, 1]);
+            let mut level = rng.range(20, 70);
+            let mut levels = Vec::new();
+            for _ in 0..rng.range(5, 8) {
+                levels.push(level.to_string());
+                level += match rng.chance(0.1) {
+                    true => rng.range(-5, 5),
+                    false => direction * rng.range(1, 3),
+                };
+            }
+            input.push_str(&levels.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+
+    /// each report checked as it is and then with every level left out in
+    /// turn, steps all going up by 1 to 3 or all going down by 1 to 3
+    fn brute_force(reports: &Self::Input, part: u8) -> Option<Answer> {
+        let steady = |levels: &[i16]| {
+            let steps: Vec<i32> = levels
+                .windows(2)
+                .map(|pair| pair[1] as i32 - pair[0] as i32)
+                .collect();
+            steps.iter().all(|step| (1..=3).contains(step))
+                || steps.iter().all(|step| (-3..=-1).contains(step))
+        };
+        let safe = |levels: &&Vec<i16>| match part {
+            1 => steady(levels),
+            _ => {
+                steady(levels)
+                    || (0..levels.len()).any(|skip| {
+                        let mut dampened = levels.to_vec();
+                        dampened.remove(skip);
+                        steady(&dampened)
+                    })
+            }
+        };
+        Some(reports.iter().filter(safe).count().into())
+    }
+
+    fn render(reports: &Self::Input) -> String {
+        reports
+            .iter()
+            .map(|levels| {
+                let levels: Vec<String> = levels.iter().map(i16::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day02>(EXAMPLE, 1, 2);
+        assert_example::<Day02>(EXAMPLE, 2, 4);
+        let reports = parse_reports(EXAMPLE).unwrap();
+        assert_eq!(Day02::brute_force(&reports, 1), Some(Answer::from(2)));
+        assert_eq!(Day02::brute_force(&reports, 2), Some(Answer::from(4)));
+    }
+
+    #[test]
+    fn test_short_and_far_apart_levels() {
+        assert!(is_safe_report(&[1]));
+        assert!(is_safe_report(&[]));
+        assert!(!is_safe_report(&[i16::MIN, i16::MAX]));
+        assert_eq!(part_two(&[vec![1, 9]]), 1);
+    }
+}
@@ -0,0 +1,207 @@
+// doing it without the regex crate
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const MAX_SIZE: usize = 12; // mul(123,123)
+
+fn parse_number(bytes: &[u8]) -> Option<u32> {
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+/// corrupted memory can hold anything, there is nothing to reject
+pub fn parse_memory(input: &str) -> Result<String, ParseError> {
+    Ok(input.to_string())
+}
+
+pub fn part_one(input: &str) -> u32 {
+    let lines: Vec<&str> = input.trim().split("\n").collect();
+    let mut sum: u32 = 0;
+    for line in &lines {
+        // bytes rather than characters, corrupted memory needn't be ASCII
+        let line = line.as_bytes();
+        let line_len = line.len();
+        let mut start = 0;
+
+        while start + 4 < line_len {
+            if &line[start..start + 4] != b"mul(" {
+                start += 1;
+                continue;
+            }
+            start += 4;
+
+            // find the closing parenthesis
+            let slice_end = std::cmp::min(start + MAX_SIZE - 4, line_len);
+            let end = match line[start..slice_end].iter().position(|&b| b == b')') {
+                Some(idx) => start + idx,
+                None => continue,
+            };
+
+            let contents = &line[start..end];
+
+            // find the comma
+            let comma_idx = match contents.iter().position(|&b| b == b',') {
+                Some(idx) => idx,
+                None => continue,
+            };
+
+            // parse numbers
+            let first_number = match parse_number(&contents[..comma_idx]) {
+                Some(num) => num,
+                None => continue,
+            };
+            let second_number = match parse_number(&contents[comma_idx + 1..]) {
+                Some(num) => num,
+                None => continue,
+            };
+
+            sum += first_number * second_number;
+            start += 5;
+        }
+    }
+    sum
+}
+
+pub fn part_two(input: &str) -> u32 {
+    let lines: Vec<&str> = input.trim().split("\n").collect();
+    let mut sum: u32 = 0;
+    let mut is_enabled = true;
+
+    for line in &lines {
+        // bytes rather than characters, corrupted memory needn't be ASCII
+        let line = line.as_bytes();
+        let line_len = line.len();
+        let mut start = 0;
+
+        while start + 4 < line_len {
+            if &line[start..start + 4] == b"do()" {
+                is_enabled = true;
+                start += 4;
+                continue;
+            }
+
+            if !is_enabled {
+                start += 1;
+                continue;
+            }
+
+            if &line[start..std::cmp::min(start + 7, line_len)] == b"don't()" {
+                is_enabled = false;
+                start += 7;
+                continue;
+            }
+
+            if &line[start..start + 4] != b"mul(" {
+                start += 1;
+                continue;
+            }
+            start += 4;
+
+            // find the closing parenthesis
+            let slice_end = std::cmp::min(start + MAX_SIZE - 4, line_len);
+            let end = match line[start..slice_end].iter().position(|&b| b == b')') {
+                Some(idx) => start + idx,
+                None => continue,
+            };
+
+            let contents = &line[start..end];
+
+            // find the comma
+            let comma_idx = match contents.iter().position(|&b| b == b',') {
+                Some(idx) => idx,
+                None => continue,
+            };
+
+            // parse numbers
+            let first_number = match parse_number(&contents[..comma_idx]) {
+                Some(num) => num,
+                None => continue,
+            };
+            let second_number = match parse_number(&contents[comma_idx + 1..]) {
+                Some(num) => num,
+                None => continue,
+            };
+
+            sum += first_number * second_number;
+            start += 5;
+        }
+    }
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_memory(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_two(input).into()
+    }
+}
+
+impl Generate for Day03 {
+    /// `size` lines of corrupted memory, with real and broken instructions
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let junk = [
+            'x', '%', '&', '!', '@', '^', '[', ']', '(', ')', ',', ' ', '+', '*', '<', '>', '?',
+        ];
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..rng.range(10, 40) {
+                let token = match rng.below(8) {
+                    0 => format!("mul({},{})", rng.range(1, 999), rng.range(1, 999)),
+                    1 => format!("mul({},{}]", rng.range(1, 999), rng.range(1, 999)),
+                    2 => format!("mul ( {},{} )", rng.range(1, 999), rng.range(1, 999)),
+                    3 => "do()".to_string(),
+                    4 => "don't()".to_string(),
+                    5 => "mul(".to_string(),
+                    _ => rng.pick(&junk).to_string(),
+                };
+                input.push_str(&token);
+            }
+            input.push('\n');
+        }
+        input
+    }
+
+    fn render(input: &Self::Input) -> String {
+        input.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{assert_example, fuzz};
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day03>(EXAMPLE, 1, 161);
+        assert_example::<Day03>(EXAMPLE_2, 2, 48);
+    }
+
+    #[test]
+    fn test_corrupted_memory_never_panics() {
+        for memory in ["", "mul", "mul(", "do()", "ééémul(2,3)é", "mul(2,é)"] {
+            part_one(memory);
+            part_two(memory);
+        }
+        let mut rng = Rng::new(0);
+        for _ in 0..1000 {
+            let original = *rng.pick(&[EXAMPLE, EXAMPLE_2]);
+            let memory = fuzz::mutate(original, &mut rng);
+            part_one(&memory);
+            part_two(&memory);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::Grid;
+
+const WORD: &str = "XMAS";
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),   // right
+    (-1, 0),  // left
+    (0, 1),   // down
+    (0, -1),  //up
+    (1, 1),   // right down
+    (1, -1),  // right up
+    (-1, 1),  // left down
+    (-1, -1), // left up
+];
+
+pub struct Matrix {
+    data: Vec<Vec<char>>,
+    height: usize,
+    width: usize,
+}
+
+pub fn parse_matrix(input: &str) -> Result<Matrix, ParseError> {
+    let grid = Grid::parse(input, "'X', 'M', 'A' or 'S'", |c| {
+        "XMAS".contains(c).then_some(c)
+    })?;
+    Ok(Matrix {
+        data: grid.rows().map(|row| row.to_vec()).collect(),
+        height: grid.height(),
+        width: grid.width(),
+    })
+}
+
+pub fn part_one(matrix: &Matrix) -> u32 {
+    let mut sum: u32 = 0;
+    for y in 0..matrix.height {
+        for x in 0..matrix.width {
+            for direction in DIRECTIONS {
+                sum += search(WORD, matrix, x, y, direction.0, direction.1) as u32;
+            }
+        }
+    }
+
+    sum
+}
+
+fn search(word: &str, matrix: &Matrix, x: usize, y: usize, dx: isize, dy: isize) -> bool {
+    // searches for 'word' in the matrix, given the current position (x, y) and the direction
+    // (dx, dy). Returns true if found.
+    let len = word.len();
+    let (width, height) = (matrix.width as isize, matrix.height as isize);
+
+    // check boundaries
+    if (0..len).any(|i| {
+        let nx = x as isize + dx * i as isize;
+        let ny = y as isize + dy * i as isize;
+        nx < 0 || nx >= width || ny < 0 || ny >= height
+    }) {
+        return false;
+    }
+
+    let found: String = (0..len)
+        .map(|i| {
+            let nx = (x as isize + dx * i as isize) as usize;
+            let ny = (y as isize + dy * i as isize) as usize;
+            matrix.data[ny][nx]
+        })
+        .collect();
+
+    found == word
+}
+
+pub fn part_two(matrix: &Matrix) -> u32 {
+    let mut sum: u32 = 0;
+    for y in 1..matrix.height - 1 {
+        for x in 1..matrix.width - 1 {
+            if matrix.data[y][x] != 'A' {
+                continue;
+            }
+
+            let tl = matrix.data[y - 1][x - 1];
+            let tr = matrix.data[y - 1][x + 1];
+            let bl = matrix.data[y + 1][x - 1];
+            let br = matrix.data[y + 1][x + 1];
+
+            if ((tl == 'M' || tl == 'S') && (br == 'M' || br == 'S') && tl != br)
+                && ((tr == 'M' || tr == 'S') && (bl == 'M' || bl == 'S') && tr != bl)
+            {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Matrix;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_matrix(input)
+    }
+
+    fn part1(matrix: &Self::Input) -> Answer {
+        part_one(matrix).into()
+    }
+
+    fn part2(matrix: &Self::Input) -> Answer {
+        part_two(matrix).into()
+    }
+}
+
+impl Generate for Day04 {
+    /// a `size` by `size` word search
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(3);
+        let mut letters = Grid::new(size, size, 'X');
+        for pos in letters.positions() {
+            letters[pos] = *rng.pick(&['X', 'M', 'A', 'S']);
+        }
+        letters.render(|_, &c| c)
+    }
+
+    fn render(matrix: &Self::Input) -> String {
+        matrix
+            .data
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day04>(EXAMPLE, 1, 18);
+        assert_example::<Day04>(EXAMPLE, 2, 9);
+    }
+}
@@ -0,0 +1,153 @@
+// basic selection sort solution
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::collections::HashSet;
+
+pub type Rules = HashSet<(u8, u8)>;
+
+pub fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<u8>>), ParseError> {
+    let [rule_lines, update_lines] = aoc_core::two_sections(input, "updates")?;
+
+    let mut rules: Rules = HashSet::new();
+    for mut line in rule_lines {
+        let before = line.number()?;
+        line.expect("|")?;
+        let after = line.number()?;
+        line.end()?;
+        rules.insert((before, after));
+    }
+
+    let mut updates: Vec<Vec<u8>> = Vec::new();
+    for mut line in update_lines {
+        let values: Vec<u8> = line.separated(",", |s| s.number())?;
+        line.end()?;
+        updates.push(values);
+    }
+
+    Ok((rules, updates))
+}
+
+pub fn is_correct_update(update: &[u8], rules: &Rules) -> bool {
+    for i in 0..update.len() {
+        for j in i + 1..update.len() {
+            if rules.contains(&(update[j], update[i])) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn part_one(rules: &Rules, updates: &[Vec<u8>]) -> u32 {
+    let mut sum: u32 = 0;
+    for update in updates {
+        if is_correct_update(update, rules) {
+            let middle = update.len() / 2;
+            sum += update[middle] as u32;
+        }
+    }
+    sum
+}
+
+pub fn part_two(rules: &Rules, updates: &[Vec<u8>]) -> u32 {
+    let mut sum: u32 = 0;
+    for update in updates {
+        if is_correct_update(update, rules) {
+            continue;
+        }
+
+        let mut fixed = update.clone();
+        for i in 0..fixed.len() {
+            for j in i + 1..fixed.len() {
+                if rules.contains(&(fixed[j], fixed[i])) {
+                    fixed.swap(j, i);
+                }
+            }
+        }
+        assert!(is_correct_update(&fixed, rules));
+        let middle = fixed.len() / 2;
+        sum += fixed[middle] as u32;
+    }
+    sum
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Rules, Vec<Vec<u8>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (rules, updates) = input;
+        part_one(rules, updates).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (rules, updates) = input;
+        part_two(rules, updates).into()
+    }
+}
+
+impl Generate for Day05 {
+    /// rules ordering every pair of 49 pages, and `size` updates
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut pages: Vec<u8> = (10..100).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+        let mut rules: Vec<String> = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in &pages[i + 1..] {
+                rules.push(format!("{}|{}", before, after));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let mut input = rules.join("\n");
+        input.push_str("\n\n");
+        for _ in 0..size {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(2 * rng.range(2, 11) as usize + 1);
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|p| p == page));
+            }
+            let update: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+            input.push_str(&update.join(","));
+            input.push('\n');
+        }
+        input
+    }
+
+    /// the rules sorted, as the set doesn't keep their order
+    fn render((rules, updates): &Self::Input) -> String {
+        let mut rules: Vec<&(u8, u8)> = rules.iter().collect();
+        rules.sort();
+        let mut text: String = rules
+            .iter()
+            .map(|(before, after)| format!("{}|{}\n", before, after))
+            .collect();
+        text.push('\n');
+        for update in updates {
+            let pages: Vec<String> = update.iter().map(u8::to_string).collect();
+            text.push_str(&(pages.join(",") + "\n"));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day05>(EXAMPLE, 1, 143);
+        assert_example::<Day05>(EXAMPLE, 2, 123);
+    }
+}
@@ -0,0 +1,161 @@
+// I implemented part 2 in two different ways:
+// - Using a HashSet to check if a (x, y, dx, dy) ever repeats.
+// - Counting steps and exiting if we surpass the max
+//   possible steps (the area of the map).
+// Counting steps ended up being much faster.
+
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Direction, Grid, Position};
+
+const OBSTACLE: char = '#';
+const VISITED: char = 'X';
+const UNVISITED: char = '.';
+const START: char = '^';
+const START_DIRECTION: Direction = Direction::North;
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<char>,
+    start: Position,
+}
+
+impl Map {
+    fn new(data: Grid<char>) -> Option<Map> {
+        let start = data.find(|&c| c == START)?;
+        Some(Map { data, start })
+    }
+
+    /// The guard's next position and direction, or `None` once they leave the map.
+    fn step(&self, position: Position, mut direction: Direction) -> Option<(Position, Direction)> {
+        let mut next = self.data.step(position, direction)?;
+        while self.data[next] == OBSTACLE {
+            direction = direction.turn_right();
+            next = self.data.step(position, direction)?;
+        }
+        Some((next, direction))
+    }
+}
+
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let data = Grid::parse(input, "'.', '#' or '^'", |c| {
+        [UNVISITED, OBSTACLE, START].contains(&c).then_some(c)
+    })?;
+    Map::new(data).ok_or_else(|| aoc_core::unexpected_end(input, "the guard '^'"))
+}
+
+pub fn part_one(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut count = 1;
+
+    let mut position = map.start;
+    let mut direction = START_DIRECTION;
+
+    while let Some(next) = map.step(position, direction) {
+        (position, direction) = next;
+        if map.data[position] == UNVISITED {
+            map.data[position] = VISITED;
+            count += 1;
+        }
+    }
+    count
+}
+
+pub fn part_two(map: &Map) -> usize {
+    let mut count = 0;
+    for position in map.data.positions() {
+        if map.data[position] == OBSTACLE || map.data[position] == START {
+            continue;
+        }
+
+        let mut obstacle_map = map.clone();
+        obstacle_map.data[position] = OBSTACLE;
+
+        if is_map_endless_loop(obstacle_map) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn is_map_endless_loop(mut map: Map) -> bool {
+    // finds endless loop by exiting if we surpass the max amount of possible
+    // steps (area of the map).
+    let mut position = map.start;
+    let mut direction = START_DIRECTION;
+
+    let max_steps = map.data.width() * map.data.height() - 1;
+
+    let mut steps = 1;
+    while let Some(next) = map.step(position, direction) {
+        (position, direction) = next;
+        if map.data[position] == UNVISITED {
+            map.data[position] = VISITED;
+        }
+        steps += 1;
+        if steps > max_steps {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
+
+impl Generate for Day06 {
+    /// a `size` by `size` lab the guard walks out of, with nothing right
+    /// around them so an added obstruction can't box them in
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        loop {
+            let mut lab = Grid::new(size, size, UNVISITED);
+            for pos in lab.positions() {
+                if rng.chance(0.08) {
+                    lab[pos] = OBSTACLE;
+                }
+            }
+            let start = Position::new(rng.below(size), rng.below(size));
+            for neighbour in lab.neighbours4(start).collect::<Vec<_>>() {
+                lab[neighbour] = UNVISITED;
+            }
+            lab[start] = START;
+            let map = Map::new(lab).unwrap();
+            if !is_map_endless_loop(map.clone()) {
+                return map.data.render(|_, &c| c);
+            }
+        }
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.data.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day06>(EXAMPLE, 1, 41);
+        assert_example::<Day06>(EXAMPLE, 2, 6);
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+pub struct Equation {
+    values: Vec<u64>,
+    result: u64,
+}
+
+impl Equation {
+    pub fn has_solution_for(&self, operators: Vec<char>) -> bool {
+        let ops = Equation::generate_permutations(operators, self.values.len() - 1);
+        for op in ops {
+            let mut result = self.values[0];
+            for (i, operator) in op.iter().enumerate() {
+                result = apply_operation(result, self.values[i + 1], *operator);
+
+                // number will only keep on increasing so we can break early
+                if result > self.result {
+                    break;
+                }
+            }
+            if result == self.result {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn generate_permutations(chars: Vec<char>, n: usize) -> Vec<Vec<char>> {
+        let mut permutations = Vec::new();
+        let base: u64 = chars.len() as u64;
+        let max = base.pow(n as u32);
+
+        for i in 0..max {
+            let mut permutation: Vec<char> = Vec::new();
+
+            for j in 0..n {
+                let digit = (i / base.pow(j as u32)) % base;
+                permutation.push(chars[digit as usize]);
+            }
+            permutations.push(permutation);
+        }
+        permutations
+    }
+}
+
+fn apply_operation(a: u64, b: u64, op: char) -> u64 {
+    match op {
+        '*' => a * b,
+        '+' => a + b,
+        '|' => (a.to_string() + &b.to_string()).parse::<u64>().unwrap(),
+        _ => panic!(),
+    }
+}
+
+pub fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut equations: Vec<Equation> = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        let result: u64 = line.number()?;
+        line.expect(":")?;
+        let values: Vec<u64> = line.numbers()?;
+        if values.is_empty() {
+            return Err(line.error("a number"));
+        }
+        line.end()?;
+        equations.push(Equation { result, values });
+    }
+    Ok(equations)
+}
+
+pub fn part_one(equations: &[Equation]) -> u64 {
+    let mut sum = 0;
+
+    for equation in equations {
+        if equation.has_solution_for(vec!['*', '+']) {
+            sum += equation.result;
+        }
+    }
+
+    sum
+}
+
+pub fn part_two(equations: &[Equation]) -> u64 {
+    let mut sum = 0;
+
+    for equation in equations {
+        if equation.has_solution_for(vec!['*', '+', '|']) {
+            sum += equation.result;
+        }
+    }
+
+    sum
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_equations(input)
+    }
+
+    fn part1(equations: &Self::Input) -> Answer {
+        part_one(equations).into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        part_two(equations).into()
+    }
+}
+
+impl Generate for Day07 {
+    /// `size` equations of up to 12 numbers, about half of them solvable
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let values: Vec<u64> = (0..rng.range(2, 12))
+                .map(|_| rng.range(1, 999) as u64)
+                .collect();
+            let mut result = values[0];
+            for &value in &values[1..] {
+                let next = apply_operation(result, value, *rng.pick(&['*', '+', '|']));
+                // keep clear of overflowing when the solver tries concatenating
+                result = match next > 10_000_000_000_000 {
+                    true => result + value,
+                    false => next,
+                };
+            }
+            if rng.chance(0.5) {
+                result += 1;
+            }
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            input.push_str(&format!("{}: {}\n", result, values.join(" ")));
+        }
+        input
+    }
+
+    fn render(equations: &Self::Input) -> String {
+        equations
+            .iter()
+            .map(|equation| {
+                let values: Vec<String> = equation.values.iter().map(u64::to_string).collect();
+                format!("{}: {}\n", equation.result, values.join(" "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day07>(EXAMPLE, 1, 3749);
+        assert_example::<Day07>(EXAMPLE, 2, 11387);
+    }
+}
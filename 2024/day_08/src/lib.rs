@@ -0,0 +1,161 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Grid, Position};
+
+const EMPTY: char = '.';
+const ANTINODE: char = '#';
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<char>,
+    antinodes: Grid<char>,
+    antennas: Vec<char>,
+}
+
+impl Map {
+    fn from_data(data: Grid<char>) -> Map {
+        let antinodes = Grid::new(data.width(), data.height(), EMPTY);
+        let mut antennas: Vec<char> = Vec::new();
+        for (_, c) in data.iter().filter(|&(_, x)| *x != EMPTY) {
+            if !antennas.contains(c) {
+                antennas.push(*c)
+            }
+        }
+        Map {
+            data,
+            antinodes,
+            antennas,
+        }
+    }
+
+    fn compute_antinodes(&mut self) {
+        for antenna in &self.antennas {
+            let antennas = &self.get_antenna_positions(*antenna);
+            for i in 0..antennas.len() - 1 {
+                for j in i + 1..antennas.len() {
+                    let (ax, ay) = (antennas[i].x as isize, antennas[i].y as isize);
+                    let (bx, by) = (antennas[j].x as isize, antennas[j].y as isize);
+                    let (dx, dy) = (bx - ax, by - ay);
+                    if let Some(antinode) = self.data.position(ax - dx, ay - dy) {
+                        self.antinodes[antinode] = ANTINODE;
+                    }
+                    if let Some(antinode) = self.data.position(bx + dx, by + dy) {
+                        self.antinodes[antinode] = ANTINODE;
+                    }
+                }
+            }
+        }
+    }
+
+    fn compute_antinodes_with_resonant_frequencies(&mut self) {
+        for antenna in &self.antennas {
+            let antennas = &self.get_antenna_positions(*antenna);
+            for i in 0..antennas.len() - 1 {
+                for j in i + 1..antennas.len() {
+                    let (ax, ay) = (antennas[i].x as isize, antennas[i].y as isize);
+                    let (bx, by) = (antennas[j].x as isize, antennas[j].y as isize);
+                    let (dx, dy) = (bx - ax, by - ay);
+
+                    let mut k = 0;
+                    while let Some(antinode) = self.data.position(ax - (k * dx), ay - (k * dy)) {
+                        self.antinodes[antinode] = ANTINODE;
+                        k += 1;
+                    }
+                    k = 0;
+                    while let Some(antinode) = self.data.position(bx + (k * dx), by + (k * dy)) {
+                        self.antinodes[antinode] = ANTINODE;
+                        k += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_antenna_positions(&self, antenna: char) -> Vec<Position> {
+        self.data
+            .iter()
+            .filter(|&(_, c)| *c == antenna)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    fn count_antinodes(&self) -> usize {
+        self.antinodes
+            .iter()
+            .filter(|&(_, x)| *x == ANTINODE)
+            .count()
+    }
+}
+
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let data = Grid::parse(input, "'.' or an antenna", |c| {
+        (c == EMPTY || c.is_ascii_alphanumeric()).then_some(c)
+    })?;
+    Ok(Map::from_data(data))
+}
+
+pub fn part_one(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.compute_antinodes();
+    map.count_antinodes()
+}
+
+pub fn part_two(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.compute_antinodes_with_resonant_frequencies();
+    map.count_antinodes()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
+
+impl Generate for Day08 {
+    /// a `size` by `size` map with a few antennas on each of several
+    /// frequencies
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(2);
+        let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+        let mut map = Grid::new(size, size, EMPTY);
+        for &frequency in &frequencies[..(size / 3).clamp(1, frequencies.len())] {
+            for _ in 0..rng.range(2, 4) {
+                let pos = Position::new(rng.below(size), rng.below(size));
+                if map[pos] == EMPTY {
+                    map[pos] = frequency;
+                }
+            }
+        }
+        map.render(|_, &c| c)
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.data.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day08>(EXAMPLE, 1, 14);
+        assert_example::<Day08>(EXAMPLE, 2, 34);
+    }
+}
@@ -0,0 +1,166 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+const EMPTY: &str = ".";
+
+pub fn parse_disk_map(input: &str) -> Result<Vec<u8>, ParseError> {
+    let mut line = aoc_core::first_line(input)?;
+    if line.is_done() {
+        return Err(line.error("a digit"));
+    }
+    let mut disk_map = Vec::new();
+    while !line.is_done() {
+        disk_map.push(line.char_as("a digit", |c| c.to_digit(10).map(|d| d as u8))?);
+    }
+    Ok(disk_map)
+}
+
+pub fn part_one(disk_map: &[u8]) -> u64 {
+    let mut expanded_map: Vec<String> = expand_disk_map(disk_map);
+
+    let mut l = 0;
+    let mut r = expanded_map.len() - 1;
+    loop {
+        while l < r && expanded_map[l] != EMPTY {
+            l += 1;
+        }
+        while r > l && expanded_map[r] == EMPTY {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        expanded_map[l] = expanded_map[r].clone();
+        expanded_map[r] = EMPTY.to_string();
+        l += 1;
+        r -= 1;
+    }
+
+    calculate_checksum(&expanded_map)
+}
+
+pub fn part_two(disk_map: &[u8]) -> u64 {
+    let mut expanded_map = expand_disk_map(disk_map);
+
+    let mut r = expanded_map.len() - 1;
+    while r > 0 {
+        // find location of next file (r + file_len)
+        while r > 1 && expanded_map[r] == EMPTY {
+            r -= 1;
+        }
+
+        let file_id = &expanded_map[r];
+        let mut file_len = 1;
+        while r > 1 && expanded_map[r - 1] == *file_id {
+            r -= 1;
+            file_len += 1;
+        }
+
+        // find next empty block of disk (e + empty_len)
+        let mut e = 0;
+        let mut empty_len = 0;
+        while empty_len < file_len && e < r {
+            if expanded_map[e] == EMPTY {
+                empty_len += 1;
+            } else {
+                empty_len = 0;
+            }
+            e += 1;
+        }
+        e -= empty_len;
+
+        // if spot found, move the block
+        if empty_len >= file_len {
+            for i in 0..file_len {
+                expanded_map[e + i] = expanded_map[r + i].clone();
+                expanded_map[r + i] = EMPTY.to_string();
+            }
+        }
+
+        r -= 1;
+    }
+
+    calculate_checksum(&expanded_map)
+}
+
+fn expand_disk_map(disk_map: &[u8]) -> Vec<String> {
+    let mut expanded_map: Vec<String> = Vec::new();
+
+    let mut is_file = 1;
+    let mut file_id = 0;
+    for value in disk_map {
+        if is_file == 1 {
+            for _ in 0..*value {
+                expanded_map.push(file_id.to_string());
+            }
+            file_id += 1;
+        } else {
+            for _ in 0..*value {
+                expanded_map.push(EMPTY.to_string());
+            }
+        }
+        is_file *= -1;
+    }
+    expanded_map
+}
+
+fn calculate_checksum(disk_map: &[String]) -> u64 {
+    let mut checksum: u64 = 0;
+    for (i, block) in disk_map.iter().enumerate() {
+        if block == EMPTY {
+            continue;
+        }
+        checksum += i as u64 * block.parse::<u64>().unwrap();
+    }
+    checksum
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_disk_map(input)
+    }
+
+    fn part1(disk_map: &Self::Input) -> Answer {
+        part_one(disk_map).into()
+    }
+
+    fn part2(disk_map: &Self::Input) -> Answer {
+        part_two(disk_map).into()
+    }
+}
+
+impl Generate for Day09 {
+    /// a disk map of `size` files
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut disk_map = String::new();
+        for file in 0..size.max(1) {
+            if file > 0 {
+                disk_map.push_str(&rng.range(0, 9).to_string());
+            }
+            disk_map.push_str(&rng.range(1, 9).to_string());
+        }
+        disk_map.push('\n');
+        disk_map
+    }
+
+    fn render(disk_map: &Self::Input) -> String {
+        disk_map.iter().map(u8::to_string).collect::<String>() + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day09>(EXAMPLE, 1, 1928);
+        assert_example::<Day09>(EXAMPLE, 2, 2858);
+    }
+}
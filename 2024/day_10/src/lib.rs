@@ -0,0 +1,124 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Grid, Position};
+use std::collections::HashSet;
+
+struct Trail {
+    data: Vec<Position>,
+}
+
+fn find_trails(map: &Grid<u8>, start: Position) -> Vec<Trail> {
+    let current = map[start];
+    if current == 9 {
+        return vec![Trail { data: vec![start] }];
+    }
+
+    let mut trails: Vec<Trail> = Vec::new();
+    for adjacent in map.neighbours4(start) {
+        if map[adjacent] == current + 1 {
+            let new_trails = find_trails(map, adjacent);
+            for mut new_trail in new_trails {
+                new_trail.data.insert(0, start);
+                trails.push(new_trail);
+            }
+        }
+    }
+    trails
+}
+
+fn filter_unique_trails(trails: Vec<Trail>) -> Vec<Trail> {
+    // keep only trails that start and end at different positions
+    let mut unique = Vec::new();
+    let mut seen: HashSet<(Position, Position)> = HashSet::new();
+
+    for trail in trails {
+        let first = trail.data[0];
+        let last = trail.data[trail.data.len() - 1];
+
+        if seen.insert((first, last)) {
+            unique.push(trail);
+        }
+    }
+    unique
+}
+
+pub fn parse_map(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, "a height from 0 to 9", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
+}
+
+fn find_all_trails(map: &Grid<u8>) -> Vec<Trail> {
+    let mut trails: Vec<Trail> = Vec::new();
+    for (position, &height) in map.iter() {
+        if height == 0 {
+            trails.extend(find_trails(map, position));
+        }
+    }
+    trails
+}
+
+pub fn part_one(map: &Grid<u8>) -> usize {
+    let trails = filter_unique_trails(find_all_trails(map));
+    trails.len()
+}
+
+pub fn part_two(map: &Grid<u8>) -> usize {
+    let trails = find_all_trails(map);
+    trails.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
+
+impl Generate for Day10 {
+    /// a `size` by `size` map with hiking trails wandering over it
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(2);
+        let mut map = Grid::new(size, size, 0);
+        for pos in map.positions() {
+            map[pos] = rng.range(0, 9) as u32;
+        }
+        for _ in 0..size {
+            let mut pos = Position::new(rng.below(size), rng.below(size));
+            for height in 0..=9 {
+                map[pos] = height;
+                let next: Vec<Position> = map.neighbours4(pos).collect();
+                pos = *rng.pick(&next);
+            }
+        }
+        map.render(|_, &height| char::from_digit(height, 10).unwrap())
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.render(|_, &height| char::from(b'0' + height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day10>(EXAMPLE, 1, 36);
+        assert_example::<Day10>(EXAMPLE, 2, 81);
+    }
+}
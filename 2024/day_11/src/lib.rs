@@ -0,0 +1,148 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+const MULTIPLIER: u64 = 2024;
+const BLINKS_25: u8 = 25;
+const BLINKS_75: u8 = 75;
+
+pub fn blink(number: u64) -> Vec<u64> {
+    if number == 0 {
+        return vec![1];
+    }
+    if number.to_string().len().is_multiple_of(2) {
+        let mut tmp = number;
+        let mut digits = 0;
+        while tmp > 0 {
+            tmp /= 10;
+            digits += 1;
+        }
+
+        let divisor = 10_u64.pow(digits / 2);
+        let left = number / divisor;
+        let right = number % divisor;
+        return vec![left, right];
+    }
+    vec![number * MULTIPLIER]
+}
+
+pub fn get_resulting_stones(number: u64, blinks: u8, memo: &mut HashMap<(u64, u8), u64>) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+    if let Some(&cached) = memo.get(&(number, blinks)) {
+        return cached;
+    }
+
+    let mut count = 0;
+    for stone in blink(number) {
+        count += get_resulting_stones(stone, blinks - 1, memo);
+    }
+
+    memo.insert((number, blinks), count);
+    count
+}
+
+pub fn parse_numbers(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut line = aoc_core::first_line(input)?;
+    let numbers: Vec<u64> = line.numbers()?;
+    if numbers.is_empty() {
+        return Err(line.error("a number"));
+    }
+    line.end()?;
+    Ok(numbers)
+}
+
+pub fn part_one(numbers: &[u64]) -> u64 {
+    let mut stones = 0;
+    let mut memo: HashMap<(u64, u8), u64> = HashMap::new();
+    for number in numbers {
+        stones += get_resulting_stones(*number, BLINKS_25, &mut memo);
+    }
+    stones
+}
+
+pub fn part_two(numbers: &[u64]) -> u64 {
+    let mut stones = 0;
+    let mut memo: HashMap<(u64, u8), u64> = HashMap::new();
+    for number in numbers {
+        stones += get_resulting_stones(*number, BLINKS_75, &mut memo);
+    }
+    stones
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        part_one(numbers).into()
+    }
+
+    fn part2(numbers: &Self::Input) -> Answer {
+        part_two(numbers).into()
+    }
+}
+
+impl Generate for Day11 {
+    /// `size` stones
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let stones: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let digits = rng.range(1, 7) as u32;
+                rng.range(0, 10_i64.pow(digits) - 1).to_string()
+            })
+            .collect();
+        format!("{}\n", stones.join(" "))
+    }
+
+    /// every stone kept in a row and blinked at 25 times; the row grows far
+    /// too long to blink at 75 times
+    fn brute_force(numbers: &Self::Input, part: u8) -> Option<Answer> {
+        if part != 1 {
+            return None;
+        }
+        let mut stones = numbers.clone();
+        for _ in 0..BLINKS_25 {
+            let mut row = Vec::new();
+            for stone in stones {
+                let digits = stone.to_string();
+                if stone == 0 {
+                    row.push(1);
+                } else if digits.len().is_multiple_of(2) {
+                    let (left, right) = digits.split_at(digits.len() / 2);
+                    row.push(left.parse().unwrap());
+                    row.push(right.parse().unwrap());
+                } else {
+                    row.push(stone * MULTIPLIER);
+                }
+            }
+            stones = row;
+        }
+        Some(stones.len().into())
+    }
+
+    fn render(numbers: &Self::Input) -> String {
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        numbers.join(" ") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day11>(EXAMPLE, 1, 55312);
+        let numbers = parse_numbers(EXAMPLE).unwrap();
+        assert_eq!(Day11::brute_force(&numbers, 1), Some(Answer::from(55312)));
+    }
+}
@@ -0,0 +1,202 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::{Grid, Position};
+
+const EAST: (isize, isize) = (1, 0);
+const WEST: (isize, isize) = (-1, 0);
+const NORTH: (isize, isize) = (0, -1);
+const SOUTH: (isize, isize) = (0, 1);
+const NORTH_EAST: (isize, isize) = (1, -1);
+const NORTH_WEST: (isize, isize) = (-1, -1);
+const SOUTH_EAST: (isize, isize) = (1, 1);
+const SOUTH_WEST: (isize, isize) = (-1, 1);
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<char>,
+    visited: Grid<bool>,
+}
+
+impl Map {
+    pub fn from_text(text: &str) -> Result<Map, ParseError> {
+        let data = Grid::parse(text, "a plant letter", |c| {
+            c.is_ascii_uppercase().then_some(c)
+        })?;
+        let visited = Grid::new(data.width(), data.height(), false);
+        Ok(Map { data, visited })
+    }
+}
+
+struct Region {
+    coordinates: Vec<Position>,
+}
+
+impl Region {
+    fn calculate_perimeter(&self) -> usize {
+        let mut perimeter = 0;
+        let map = self.to_padded_bool_map();
+        for (pos, &in_region) in map.iter() {
+            if in_region {
+                perimeter += 4 - map.neighbours4(pos).filter(|&n| map[n]).count();
+            }
+        }
+        perimeter
+    }
+
+    fn calculate_sides(&self) -> usize {
+        // sides == corners, so we count total corners
+        let map = self.to_padded_bool_map();
+
+        let mut corners = 0;
+        for (pos, &in_region) in map.iter() {
+            if !in_region {
+                continue;
+            }
+
+            corners += Self::is_corner(&map, pos, NORTH_WEST) as usize;
+            corners += Self::is_corner(&map, pos, NORTH_EAST) as usize;
+            corners += Self::is_corner(&map, pos, SOUTH_WEST) as usize;
+            corners += Self::is_corner(&map, pos, SOUTH_EAST) as usize;
+        }
+        corners
+    }
+
+    fn is_corner(map: &Grid<bool>, pos: Position, corner: (isize, isize)) -> bool {
+        let adjacents = match corner {
+            NORTH_WEST => (NORTH, WEST),
+            SOUTH_WEST => (SOUTH, WEST),
+            NORTH_EAST => (NORTH, EAST),
+            SOUTH_EAST => (SOUTH, EAST),
+            _ => panic!("Not a corner!"),
+        };
+        let c = Self::get_adjacent_value(map, pos, corner);
+        let a0 = Self::get_adjacent_value(map, pos, adjacents.0);
+        let a1 = Self::get_adjacent_value(map, pos, adjacents.1);
+        (!c && (a0 == a1)) || (c && !a0 && !a1)
+    }
+
+    /// the region on its own, with a border of empty cells around it
+    fn to_padded_bool_map(&self) -> Grid<bool> {
+        let (xmin, ymin, xmax, ymax) = self.get_range();
+        let width = xmax - xmin;
+        let height = ymax - ymin;
+        let mut map = Grid::new(width + 3, height + 3, false);
+        for coord in &self.coordinates {
+            map[Position::new(coord.x - xmin + 1, coord.y - ymin + 1)] = true;
+        }
+        map
+    }
+
+    fn get_range(&self) -> (usize, usize, usize, usize) {
+        let (mut xmin, mut ymin) = (usize::MAX, usize::MAX);
+        let (mut xmax, mut ymax) = (usize::MIN, usize::MIN);
+        for coord in &self.coordinates {
+            xmin = xmin.min(coord.x);
+            xmax = xmax.max(coord.x);
+            ymin = ymin.min(coord.y);
+            ymax = ymax.max(coord.y);
+        }
+        (xmin, ymin, xmax, ymax)
+    }
+
+    fn get_adjacent_value(map: &Grid<bool>, pos: Position, dir: (isize, isize)) -> bool {
+        map[pos.offset(dir.0, dir.1).unwrap()]
+    }
+}
+
+fn get_crop_coordinates(map: &mut Map, pos: Position) -> Vec<Position> {
+    map.visited[pos] = true;
+    let mut coordinates: Vec<Position> = vec![pos];
+
+    let crop_type = map.data[pos];
+    let neighbours: Vec<Position> = map.data.neighbours4(pos).collect();
+    for next in neighbours {
+        if !map.visited[next] && map.data[next] == crop_type {
+            coordinates.extend(get_crop_coordinates(map, next));
+        }
+    }
+    coordinates
+}
+
+pub fn part_one(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut cost = 0;
+    for pos in map.data.positions() {
+        if map.visited[pos] {
+            continue;
+        }
+        let region = Region {
+            coordinates: get_crop_coordinates(&mut map, pos),
+        };
+        cost += region.coordinates.len() * region.calculate_perimeter();
+    }
+    cost
+}
+
+pub fn part_two(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut cost = 0;
+    for pos in map.data.positions() {
+        if map.visited[pos] {
+            continue;
+        }
+        let region = Region {
+            coordinates: get_crop_coordinates(&mut map, pos),
+        };
+        cost += region.coordinates.len() * region.calculate_sides();
+    }
+    cost
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_text(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part_one(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part_two(map).into()
+    }
+}
+
+impl Generate for Day12 {
+    /// a `size` by `size` garden of patchy regions
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let mut garden = Grid::new(size, size, 'A');
+        for pos in garden.positions() {
+            let left = pos.offset(-1, 0).map(|left| garden[left]);
+            let up = pos.offset(0, -1).map(|up| garden[up]);
+            garden[pos] = match (left, up, rng.below(10)) {
+                (Some(left), _, 0..=3) => left,
+                (_, Some(up), 4..=7) => up,
+                _ => char::from(b'A' + rng.below(8) as u8),
+            };
+        }
+        garden.render(|_, &c| c)
+    }
+
+    fn render(map: &Self::Input) -> String {
+        map.data.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day12>(EXAMPLE, 1, 1930);
+        assert_example::<Day12>(EXAMPLE, 2, 1206);
+    }
+}
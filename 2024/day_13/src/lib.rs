@@ -0,0 +1,184 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use aoc_math::solve_2x2_integer;
+
+const SURPLUS: i64 = 10000000000000;
+
+#[derive(Debug, Clone)]
+struct Button {
+    x: i64,
+    y: i64,
+}
+
+impl Button {
+    fn from_line(line: &mut Scanner, name: &str) -> Result<Button, ParseError> {
+        line.expect("Button ")?;
+        line.expect(name)?;
+        line.expect(": X+")?;
+        let x: i64 = line.number()?;
+        line.expect(", Y+")?;
+        let y: i64 = line.number()?;
+        line.end()?;
+        Ok(Button { x, y })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Prize {
+    x: i64,
+    y: i64,
+}
+
+impl Prize {
+    fn from_line(line: &mut Scanner) -> Result<Prize, ParseError> {
+        line.expect("Prize: X=")?;
+        let x: i64 = line.number()?;
+        line.expect(", Y=")?;
+        let y: i64 = line.number()?;
+        line.end()?;
+        Ok(Prize { x, y })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Claw {
+    button_a: Button,
+    button_b: Button,
+    prize: Prize,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Claw>, ParseError> {
+    let mut claws: Vec<Claw> = Vec::new();
+    for lines in aoc_core::sections(input) {
+        let [a, b, prize] = lines.as_slice() else {
+            return Err(match lines.get(3) {
+                Some(extra) => extra.error("a blank line"),
+                None => ParseError {
+                    line: lines[lines.len() - 1].line() + 1,
+                    column: 1,
+                    expected: "three lines per claw machine".to_string(),
+                    found: "a blank line".to_string(),
+                },
+            });
+        };
+        let button_a = Button::from_line(&mut a.clone(), "A")?;
+        let button_b = Button::from_line(&mut b.clone(), "B")?;
+        let prize = Prize::from_line(&mut prize.clone())?;
+        claws.push(Claw {
+            button_a,
+            button_b,
+            prize,
+        });
+    }
+
+    Ok(claws)
+}
+
+/// presses of A and B that land exactly on the prize
+pub fn find_solution(claw: &Claw) -> Option<[i64; 2]> {
+    solve_2x2_integer(
+        [
+            [claw.button_a.x, claw.button_b.x],
+            [claw.button_a.y, claw.button_b.y],
+        ],
+        [claw.prize.x, claw.prize.y],
+    )
+}
+
+pub fn part_one(claws: &[Claw]) -> i64 {
+    let mut tokens = 0;
+    for claw in claws {
+        if let Some([a, b]) = find_solution(claw) {
+            tokens += (3 * a) + b
+        }
+    }
+    tokens
+}
+
+pub fn part_two(claws: &[Claw]) -> i64 {
+    let mut tokens = 0;
+    for claw in claws {
+        let mut claw = claw.clone();
+        claw.prize.x += SURPLUS;
+        claw.prize.y += SURPLUS;
+        if let Some([a, b]) = find_solution(&claw) {
+            tokens += (3 * a) + b
+        }
+    }
+    tokens
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Claw>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(claws: &Self::Input) -> Answer {
+        part_one(claws).into()
+    }
+
+    fn part2(claws: &Self::Input) -> Answer {
+        part_two(claws).into()
+    }
+}
+
+impl Generate for Day13 {
+    /// `size` claw machines, about half of them winnable in part 1
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut machines = Vec::new();
+        for _ in 0..size {
+            let a = (rng.range(10, 99), rng.range(10, 99));
+            let b = (rng.range(10, 99), rng.range(10, 99));
+            let prize = match rng.chance(0.5) {
+                true => {
+                    let (presses_a, presses_b) = (rng.range(1, 100), rng.range(1, 100));
+                    (
+                        a.0 * presses_a + b.0 * presses_b,
+                        a.1 * presses_a + b.1 * presses_b,
+                    )
+                }
+                false => (rng.range(1000, 20000), rng.range(1000, 20000)),
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        machines.join("\n")
+    }
+
+    fn render(claws: &Self::Input) -> String {
+        let claws: Vec<String> = claws
+            .iter()
+            .map(
+                |Claw {
+                     button_a: a,
+                     button_b: b,
+                     prize,
+                 }| {
+                    format!(
+                        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                        a.x, a.y, b.x, b.y, prize.x, prize.y
+                    )
+                },
+            )
+            .collect();
+        claws.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day13>(EXAMPLE, 1, 480);
+    }
+}
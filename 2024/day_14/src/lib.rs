@@ -0,0 +1,236 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution, debug};
+use aoc_grid::view::{self, Cell, Color, Visualize};
+use aoc_grid::{Grid, Position};
+
+pub struct Area {
+    pub width: i16,
+    pub height: i16,
+}
+
+pub const BATHROOM: Area = Area {
+    width: 101,
+    height: 103,
+};
+
+const SECONDS_100: u8 = 100;
+const SECONDS_10K: u16 = 10000;
+
+#[derive(Clone)]
+pub struct Robot {
+    x: i16,
+    y: i16,
+    vx: i16,
+    vy: i16,
+}
+
+impl Robot {
+    fn from_line(line: &mut Scanner) -> Result<Robot, ParseError> {
+        line.expect("p=")?;
+        let x: i16 = line.number()?;
+        line.expect(",")?;
+        let y: i16 = line.number()?;
+        line.expect(" v=")?;
+        let vx: i16 = line.number()?;
+        line.expect(",")?;
+        let vy: i16 = line.number()?;
+        line.end()?;
+        Ok(Robot { x, y, vx, vy })
+    }
+
+    fn move_(&mut self, area: &Area) {
+        let mut nx = self.x + self.vx;
+        while nx < 0 {
+            nx += area.width;
+        }
+        while nx >= area.width {
+            nx -= area.width;
+        }
+        let mut ny = self.y + self.vy;
+        while ny < 0 {
+            ny += area.height;
+        }
+        while ny >= area.height {
+            ny -= area.height;
+        }
+        self.x = nx;
+        self.y = ny;
+    }
+
+    fn quadrant(&self, area: &Area) -> Option<u8> {
+        let (hx, hy) = (area.width / 2, area.height / 2);
+        if self.x == hx || self.y == hy {
+            return None;
+        }
+        let (qx, qy) = (self.x < hx, self.y < hy);
+        match (qx, qy) {
+            (true, true) => Some(1),
+            (false, true) => Some(2),
+            (true, false) => Some(3),
+            (false, false) => Some(4),
+        }
+    }
+}
+
+pub fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    aoc_core::lines(input)
+        .map(|mut line| Robot::from_line(&mut line))
+        .collect()
+}
+
+pub fn safety_factor(robots: &[Robot], area: &Area) -> i32 {
+    let mut robots = robots.to_vec();
+    for _ in 0..SECONDS_100 {
+        for robot in &mut robots {
+            robot.move_(area);
+        }
+    }
+    let mut quadrants = [0; 4];
+    for robot in &robots {
+        let quadrant = match robot.quadrant(area) {
+            Some(q) => q as usize,
+            None => continue,
+        };
+        quadrants[quadrant - 1] += 1;
+    }
+    quadrants.iter().product::<i32>()
+}
+
+pub fn part_one(robots: &[Robot]) -> i32 {
+    safety_factor(robots, &BATHROOM)
+}
+
+/// Robots per tile of the bathroom floor at some second.
+struct Floor<'a>(&'a [Vec<u16>]);
+
+impl Visualize for Floor<'_> {
+    /// blank where there are no robots, 9 for 9 or more
+    fn view(&self) -> Grid<Cell> {
+        let mut view = Grid::new(
+            BATHROOM.width as usize,
+            BATHROOM.height as usize,
+            Cell::new(' ', Color::BLACK),
+        );
+        for (y, row) in self.0.iter().enumerate() {
+            for (x, &robots) in row.iter().enumerate() {
+                if robots > 0 {
+                    let digit = char::from_digit(robots.min(9) as u32, 10).unwrap();
+                    view[Position::new(x, y)] = Cell::new(digit, Color::GREEN);
+                }
+            }
+        }
+        view
+    }
+}
+
+/// seconds until the robots display the christmas tree
+pub fn part_two(robots: &[Robot]) -> u16 {
+    let mut robots = robots.to_vec();
+    let mut max_frame = 0;
+    let mut max_consecutives = 0;
+    let mut max_area = Vec::new();
+    for s in 1..SECONDS_10K {
+        let mut area = vec![vec![0; BATHROOM.width as usize]; BATHROOM.height as usize];
+
+        for robot in &mut robots {
+            robot.move_(&BATHROOM);
+            area[robot.y as usize][robot.x as usize] += 1;
+        }
+
+        for row in &area {
+            let mut consecutives = 0;
+            for cell in row {
+                if *cell == 0 {
+                    consecutives = 0;
+                } else {
+                    consecutives += 1;
+                    if consecutives > max_consecutives {
+                        max_consecutives = consecutives;
+                        max_frame = s;
+                        max_area = area.clone();
+                        // each frame more ordered than the last, up to the tree
+                        view::show(&Floor(&area));
+                    }
+                }
+            }
+        }
+    }
+    debug!(
+        "The tree, after {} seconds:\n{}",
+        max_frame,
+        view::text(&Floor(&max_area).view())
+    );
+    max_frame
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_robots(input)
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        part_one(robots).into()
+    }
+
+    fn part2(robots: &Self::Input) -> Answer {
+        part_two(robots).into()
+    }
+}
+
+impl Generate for Day14 {
+    /// `size` robots wandering about, and a hundred more that line up into a
+    /// tree at some second
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let (width, height) = (BATHROOM.width as i64, BATHROOM.height as i64);
+        let seconds = rng.range(1, SECONDS_10K as i64 - 1);
+        let (top, middle) = (rng.range(0, height - 10), rng.range(9, width - 10));
+        let mut tree = Vec::new();
+        for row in 0..10 {
+            for x in middle - row..=middle + row {
+                tree.push((x, top + row));
+            }
+        }
+        let mut input = String::new();
+        let mut robot = |(x, y): (i64, i64), rng: &mut Rng, seconds: i64| {
+            let (vx, vy) = (rng.range(-100, 100), rng.range(-100, 100));
+            let x = (x - vx * seconds).rem_euclid(width);
+            let y = (y - vy * seconds).rem_euclid(height);
+            input.push_str(&format!("p={},{} v={},{}\n", x, y, vx, vy));
+        };
+        for &tile in &tree {
+            robot(tile, rng, seconds);
+        }
+        for _ in 0..size {
+            let tile = (rng.range(0, width - 1), rng.range(0, height - 1));
+            robot(tile, rng, 0);
+        }
+        input
+    }
+
+    fn render(robots: &Self::Input) -> String {
+        robots
+            .iter()
+            .map(|robot| format!("p={},{} v={},{}\n", robot.x, robot.y, robot.vx, robot.vy))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let robots = Day14::parse(EXAMPLE).unwrap();
+        let area = Area {
+            width: 11,
+            height: 7,
+        };
+        assert_eq!(safety_factor(&robots, &area), 12);
+    }
+}
@@ -0,0 +1,228 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution, debug};
+use aoc_grid::{Direction, Grid, Position};
+use std::collections::{HashMap, HashSet};
+
+const WALL: char = '#';
+const BOX: char = 'O';
+const ROBOT: char = '@';
+const EMPTY: char = '.';
+
+const BOX_L: char = '[';
+const BOX_R: char = ']';
+
+#[derive(Clone)]
+pub struct Map {
+    data: Grid<char>,
+    robot: Position,
+}
+
+impl Map {
+    fn from_lines(lines: &[Scanner]) -> Result<Map, ParseError> {
+        let mut data = Grid::from_lines(lines, "'#', 'O', '@' or '.'", |c| {
+            [WALL, BOX, ROBOT, EMPTY].contains(&c).then_some(c)
+        })?;
+        let robot = data
+            .find(|&c| c == ROBOT)
+            .ok_or_else(|| lines[0].error("a map with a robot '@'"))?;
+        data[robot] = EMPTY;
+        Ok(Map { data, robot })
+    }
+
+    fn extend(&self) -> Map {
+        let mut data = Grid::new(self.data.width() * 2, self.data.height(), EMPTY);
+        for (pos, &cell) in self.data.iter() {
+            let [left, right] = match cell {
+                EMPTY => [EMPTY, EMPTY],
+                WALL => [WALL, WALL],
+                BOX => [BOX_L, BOX_R],
+                _ => unreachable!(),
+            };
+            data[Position::new(pos.x * 2, pos.y)] = left;
+            data[Position::new(pos.x * 2 + 1, pos.y)] = right;
+        }
+        Map {
+            data,
+            robot: Position::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    fn move_robot(&mut self, dir: Direction) {
+        let mut boxes = HashSet::new();
+        self.get_touching_boxes(self.robot, dir, &mut boxes);
+
+        let next = Map::next(self.robot, dir);
+
+        if boxes.is_empty() {
+            if self.data[next] == WALL {
+                return;
+            }
+        } else {
+            if !self.boxes_can_move(&boxes, dir) {
+                return;
+            }
+            self.move_boxes(&boxes, dir);
+        }
+
+        self.robot = next;
+    }
+
+    fn get_touching_boxes(&self, pos: Position, dir: Direction, block: &mut HashSet<Position>) {
+        let adjacent = Map::next(pos, dir);
+
+        let adjacents = match self.data[adjacent] {
+            BOX => vec![adjacent],
+            BOX_R => vec![adjacent, Map::next(adjacent, Direction::West)],
+            BOX_L => vec![adjacent, Map::next(adjacent, Direction::East)],
+            _ => Vec::new(),
+        };
+        for adjacent in adjacents {
+            if block.insert(adjacent) {
+                self.get_touching_boxes(adjacent, dir, block);
+            }
+        }
+    }
+
+    fn boxes_can_move(&self, block: &HashSet<Position>, dir: Direction) -> bool {
+        block.iter().all(|&b| self.data[Map::next(b, dir)] != WALL)
+    }
+
+    fn move_boxes(&mut self, block: &HashSet<Position>, dir: Direction) {
+        let mut current = HashMap::new();
+        for &b in block {
+            current.insert(b, self.data[b]);
+        }
+        for &b in block {
+            self.data[Map::next(b, dir)] = current[&b];
+
+            let previous = Map::next(b, dir.opposite());
+            self.data[b] = *current.get(&previous).unwrap_or(&EMPTY);
+        }
+    }
+
+    /// the map is surrounded by walls, so there's always a next position
+    fn next(pos: Position, dir: Direction) -> Position {
+        pos.step(dir).unwrap()
+    }
+
+    fn sum_gps(&self) -> usize {
+        self.data
+            .iter()
+            .filter(|(_, c)| [BOX, BOX_L].contains(c))
+            .map(|(pos, _)| 100 * pos.y + pos.x)
+            .sum()
+    }
+
+    fn render(&self) -> String {
+        self.data
+            .render(|pos, &c| if pos == self.robot { ROBOT } else { c })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Map, Vec<Direction>), ParseError> {
+    let [map, movements] = aoc_core::two_sections(input, "the robot's moves")?;
+    let map = Map::from_lines(&map)?;
+    let mut directions = Vec::new();
+    for mut line in movements {
+        while !line.is_done() {
+            directions.push(line.char_as("'^', 'v', '<' or '>'", Direction::from_arrow)?);
+        }
+    }
+    Ok((map, directions))
+}
+
+pub fn part_one(map: &Map, movements: &[Direction]) -> usize {
+    let mut map = map.clone();
+    for movement in movements {
+        map.move_robot(*movement);
+    }
+
+    debug!("{}", map.render());
+    map.sum_gps()
+}
+
+pub fn part_two(map: &Map, movements: &[Direction]) -> usize {
+    let mut map = map.extend();
+    for movement in movements {
+        map.move_robot(*movement);
+    }
+
+    debug!("{}", map.render());
+    map.sum_gps()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Map, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (map, movements) = input;
+        part_one(map, movements).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (map, movements) = input;
+        part_two(map, movements).into()
+    }
+}
+
+impl Generate for Day15 {
+    /// a walled in `size` by `size` warehouse, and `size` lines of moves
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(3);
+        let mut map = Grid::new(size, size, EMPTY);
+        for pos in map.positions() {
+            let edge = pos.x == 0 || pos.y == 0 || pos.x == size - 1 || pos.y == size - 1;
+            map[pos] = match (edge, rng.below(20)) {
+                (true, _) | (false, 0) => WALL,
+                (false, 1..=4) => BOX,
+                _ => EMPTY,
+            };
+        }
+        map[Position::new(
+            rng.range(1, size as i64 - 2) as usize,
+            rng.range(1, size as i64 - 2) as usize,
+        )] = ROBOT;
+        let mut input = map.render(|_, &c| c);
+        input.push('\n');
+        for _ in 0..size {
+            let moves: String = (0..70).map(|_| *rng.pick(&['<', '>', '^', 'v'])).collect();
+            input.push_str(&moves);
+            input.push('\n');
+        }
+        input
+    }
+
+    fn render((map, movements): &Self::Input) -> String {
+        let mut text = map.data.render(|position, &c| match position == map.robot {
+            true => ROBOT,
+            false => c,
+        });
+        text.push('\n');
+        for line in movements.chunks(70) {
+            text.extend(line.iter().map(|direction| direction.to_arrow()));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day15>(EXAMPLE, 1, 2028);
+        assert_example::<Day15>(EXAMPLE_2, 1, 10092);
+        assert_example::<Day15>(EXAMPLE_2, 2, 9021);
+    }
+}
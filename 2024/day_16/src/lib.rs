@@ -0,0 +1,207 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug};
+use aoc_grid::view::{self, Cell, Color, Visualize};
+use aoc_grid::{Direction, Grid, Position};
+use aoc_search::{dijkstra, dijkstra_all};
+use std::collections::HashSet;
+
+const WALL: char = '#';
+const START: char = 'S';
+const END: char = 'E';
+
+const STEP_COST: u32 = 1;
+const TURN_COST: u32 = 1000;
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+struct Pose {
+    tile: Position,
+    facing: Direction,
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    tiles: Grid<char>,
+    start: Pose,
+}
+
+impl Maze {
+    pub fn from_text(text: &str) -> Result<Maze, ParseError> {
+        let tiles = Grid::parse(text, "'#', '.', 'S' or 'E'", |c| {
+            "#.SE".contains(c).then_some(c)
+        })?;
+        let start = Pose {
+            tile: tiles
+                .find(|&c| c == START)
+                .ok_or_else(|| aoc_core::unexpected_end(text, "a start tile 'S'"))?,
+            facing: Direction::East,
+        };
+        if tiles.find(|&c| c == END).is_none() {
+            return Err(aoc_core::unexpected_end(text, "an end tile 'E'"));
+        }
+        Ok(Maze { tiles, start })
+    }
+
+    /// moving forward into anything but a wall, or turning 90 degrees
+    fn moves(&self, pose: &Pose) -> Vec<(Pose, u32)> {
+        let mut moves = vec![
+            (
+                Pose {
+                    tile: pose.tile,
+                    facing: pose.facing.turn_right(),
+                },
+                TURN_COST,
+            ),
+            (
+                Pose {
+                    tile: pose.tile,
+                    facing: pose.facing.turn_left(),
+                },
+                TURN_COST,
+            ),
+        ];
+        if let Some(tile) = self.tiles.step(pose.tile, pose.facing)
+            && self.tiles[tile] != WALL
+        {
+            let forward = Pose {
+                tile,
+                facing: pose.facing,
+            };
+            moves.push((forward, STEP_COST));
+        }
+        moves
+    }
+
+    fn is_end(&self, pose: &Pose) -> bool {
+        self.tiles[pose.tile] == END
+    }
+}
+
+/// The maze with a way through it.
+struct Route<'a> {
+    maze: &'a Maze,
+    path: &'a [Pose],
+}
+
+impl Visualize for Route<'_> {
+    /// the way drawn as arrows in the direction the reindeer faces
+    fn view(&self) -> Grid<Cell> {
+        let mut view = self.maze.tiles.map(|&c| match c {
+            WALL => Cell::new(c, Color::GREY),
+            START | END => Cell::new(c, Color::GREEN),
+            _ => Cell::new(c, Color::DARK_GREY),
+        });
+        for pose in self.path {
+            view[pose.tile] = Cell::new(pose.facing.to_arrow(), Color::RED);
+        }
+        view
+    }
+}
+
+/// minimum cost, if the end can be reached
+pub fn part1(maze: &Maze) -> Option<u32> {
+    let (path, cost) = dijkstra(
+        maze.start.clone(),
+        |pose| maze.moves(pose),
+        |pose| maze.is_end(pose),
+    )?;
+    let route = Route { maze, path: &path };
+    debug!("{}", view::text(&route.view()));
+    view::show(&route);
+    Some(cost)
+}
+
+/// tiles that are part of at least one of the best paths
+pub fn part2(maze: &Maze) -> Option<usize> {
+    let best_paths = dijkstra_all(
+        maze.start.clone(),
+        |pose| maze.moves(pose),
+        |pose| maze.is_end(pose),
+    )?;
+    let tiles: HashSet<Position> = best_paths.states().iter().map(|pose| pose.tile).collect();
+    Some(tiles.len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::from_text(input)
+    }
+
+    fn part1(maze: &Self::Input) -> Answer {
+        part1(maze).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(maze: &Self::Input) -> Answer {
+        part2(maze).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day16 {
+    /// a maze of `size` by `size` cells, with some walls knocked through so
+    /// there's more than one way to the end
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let cells = size.max(2);
+        let side = 2 * cells + 1;
+        let mut maze = Grid::new(side, side, WALL);
+        // a random depth first walk carves a way to every cell
+        let mut visited = Grid::new(cells, cells, false);
+        let mut stack = vec![Position::new(0, cells - 1)];
+        visited[stack[0]] = true;
+        while let Some(&cell) = stack.last() {
+            maze[Position::new(2 * cell.x + 1, 2 * cell.y + 1)] = '.';
+            let unvisited: Vec<Position> =
+                visited.neighbours4(cell).filter(|&n| !visited[n]).collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let next = *rng.pick(&unvisited);
+            visited[next] = true;
+            maze[Position::new(cell.x + next.x + 1, cell.y + next.y + 1)] = '.';
+            stack.push(next);
+        }
+        // walls between two cells have one odd and one even coordinate
+        for pos in maze.positions() {
+            let inside = pos.x > 0 && pos.y > 0 && pos.x < side - 1 && pos.y < side - 1;
+            if inside && (pos.x + pos.y) % 2 == 1 && rng.chance(0.1) {
+                maze[pos] = '.';
+            }
+        }
+        maze[Position::new(1, side - 2)] = START;
+        maze[Position::new(side - 2, 1)] = END;
+        maze.render(|_, &c| c)
+    }
+
+    fn render(maze: &Self::Input) -> String {
+        maze.tiles.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day16>(EXAMPLE, 1, 7036);
+        assert_example::<Day16>(EXAMPLE, 2, 45);
+        assert_example::<Day16>(EXAMPLE_2, 1, 11048);
+        assert_example::<Day16>(EXAMPLE_2, 2, 64);
+    }
+
+    #[test]
+    fn test_end_must_be_reachable() {
+        let error = Day16::parse("S\n").err().unwrap();
+        assert_eq!(error.expected, "an end tile 'E'");
+
+        let maze = Day16::parse("S#E\n").unwrap();
+        assert_eq!(Day16::part1(&maze), Answer::None);
+        assert_eq!(Day16::part2(&maze), Answer::None);
+    }
+}
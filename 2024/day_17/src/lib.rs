@@ -0,0 +1,324 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+
+#[derive(Clone)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+fn parse_register(line: &Scanner, name: &str) -> Result<u64, ParseError> {
+    let mut line = line.clone();
+    line.expect("Register ")?;
+    line.expect(name)?;
+    line.expect(": ")?;
+    let value = line.number()?;
+    line.end()?;
+    Ok(value)
+}
+
+pub fn parse_input(input: &str) -> Result<(Registers, Vec<u8>), ParseError> {
+    let [registers, program] = aoc_core::two_sections(input, "the program")?;
+    let [a, b, c] = registers.as_slice() else {
+        return Err(match registers.get(3) {
+            Some(extra) => extra.error("a blank line"),
+            None => ParseError {
+                line: registers[registers.len() - 1].line() + 1,
+                column: 1,
+                expected: "registers A, B and C".to_string(),
+                found: "a blank line".to_string(),
+            },
+        });
+    };
+    let registers = Registers {
+        a: parse_register(a, "A")?,
+        b: parse_register(b, "B")?,
+        c: parse_register(c, "C")?,
+    };
+
+    if let Some(extra) = program.get(1) {
+        return Err(extra.error("the end of the input"));
+    }
+    let mut line = program[0].clone();
+    line.expect("Program: ")?;
+    let program: Vec<u8> = line.separated(",", |item| {
+        item.char_as("a 3-bit number", |c| c.to_digit(8).map(|d| d as u8))
+    })?;
+    // every opcode comes with its operand
+    if program.len() % 2 == 1 {
+        return Err(line.error("an operand after the last opcode"));
+    }
+    line.end()?;
+
+    Ok((registers, program))
+}
+
+fn combo(registers: &Registers, operand: u8) -> u64 {
+    match operand {
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        3 => 3,
+        4 => registers.a,
+        5 => registers.b,
+        6 => registers.c,
+        _ => 0,
+    }
+}
+
+/// `n` divided by 2 to the power of `value`, which is 0 once the power is
+/// past what a u64 holds
+fn divide(n: u64, value: u64) -> u64 {
+    u32::try_from(value)
+        .ok()
+        .and_then(|value| n.checked_shr(value))
+        .unwrap_or(0)
+}
+
+// 0
+fn adv(registers: &mut Registers, value: u64) {
+    registers.a = divide(registers.a, value);
+}
+
+// 1
+fn bxl(registers: &mut Registers, value: u8) {
+    registers.b ^= value as u64;
+}
+
+// 2
+fn bst(registers: &mut Registers, value: u64) {
+    registers.b = value % 8;
+}
+
+// 3
+fn jnz(registers: &Registers, value: u8) -> Option<usize> {
+    if registers.a == 0 {
+        return None;
+    }
+    Some(value as usize)
+}
+
+// 4
+fn bxc(registers: &mut Registers) {
+    registers.b ^= registers.c;
+}
+
+// 5
+fn out(val: u64) -> u8 {
+    (val % 8) as u8
+}
+
+// 6
+fn bdv(registers: &mut Registers, value: u64) {
+    registers.b = divide(registers.a, value);
+}
+
+// 7
+fn cdv(registers: &mut Registers, value: u64) {
+    registers.c = divide(registers.a, value);
+}
+
+pub fn run_program(registers: &mut Registers, program: &[u8]) -> Vec<u8> {
+    let mut pointer = 0;
+    let mut results: Vec<u8> = Vec::new();
+    while pointer < program.len() {
+        let value = program[pointer + 1];
+        let combo_value = combo(registers, value);
+        match program[pointer] {
+            0 => adv(registers, combo_value),
+            1 => bxl(registers, value),
+            2 => bst(registers, combo_value),
+            3 => {
+                if let Some(jump_to) = jnz(registers, value) {
+                    pointer = jump_to;
+                    continue;
+                }
+            }
+            4 => bxc(registers),
+            5 => results.push(out(combo_value)),
+            6 => bdv(registers, combo_value),
+            7 => cdv(registers, combo_value),
+            _ => unreachable!("opcodes have 3 bits"),
+        };
+        pointer += 2;
+    }
+    results
+}
+
+/// program output
+pub fn part1(registers: &Registers, program: &[u8]) -> String {
+    let mut registers = registers.clone();
+    let output = run_program(&mut registers, program);
+
+    output
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// lowest value of A for which the program outputs itself
+///
+/// Like every input, the program outputs one value per loop and shifts A
+/// right by 3 bits before jumping back, so A can be built from its highest
+/// 3 bits down, matching one more value of the output's tail at a time.
+pub fn part2(registers: &Registers, program: &[u8]) -> Option<u64> {
+    let mut candidates: Vec<u64> = vec![0];
+    for i in (0..program.len()).rev() {
+        let mut next = Vec::new();
+        // an A too big for a u64 can't be the answer
+        for a in candidates.into_iter().filter_map(|a| a.checked_mul(8)) {
+            for bits in 0..8 {
+                let mut registers = Registers {
+                    a: a + bits,
+                    ..registers.clone()
+                };
+                if run_program(&mut registers, program) == program[i..] {
+                    next.push(a + bits);
+                }
+            }
+        }
+        candidates = next;
+    }
+    candidates.into_iter().min()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Registers, Vec<u8>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (registers, program) = input;
+        part1(registers, program).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (registers, program) = input;
+        part2(registers, program).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day17 {
+    /// a program shaped like the puzzle's, printing a value for every 3 bits
+    /// of A, which starts with `size` of them (up to 20)
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let digits = size.clamp(1, 20) as u32;
+        let a = rng.range(8_i64.pow(digits - 1), 8_i64.pow(digits) - 1);
+        // B from the low bits of A, C from A shifted by B, then both mixed
+        let mut program = vec![[2, 4], [1, rng.range(0, 7)], [7, 5]];
+        let mut mix = [[1, rng.range(0, 7)], [4, rng.range(0, 7)]];
+        rng.shuffle(&mut mix);
+        program.extend(mix);
+        program.insert(rng.range(3, program.len() as i64) as usize, [0, 3]);
+        program.extend([[5, 5], [3, 0]]);
+        let program: Vec<String> = program.iter().flatten().map(|n| n.to_string()).collect();
+        format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            a,
+            program.join(",")
+        )
+    }
+
+    fn render((registers, program): &Self::Input) -> String {
+        let program: Vec<String> = program.iter().map(u8::to_string).collect();
+        format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+            registers.a,
+            registers.b,
+            registers.c,
+            program.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day17>(EXAMPLE, 1, "4,6,3,5,6,3,5,2,1,0");
+        assert_example::<Day17>(EXAMPLE_2, 2, 117440);
+    }
+
+    #[test]
+    fn test_case_1() {
+        let mut registers = Registers { a: 0, b: 0, c: 9 };
+        let program: Vec<u8> = vec![2, 6];
+        run_program(&mut registers, &program);
+        assert_eq!(registers.b, 1);
+    }
+    #[test]
+    fn test_case_2() {
+        let mut registers = Registers { a: 10, b: 0, c: 0 };
+        let program: Vec<u8> = vec![5, 0, 5, 1, 5, 4];
+        assert_eq!(run_program(&mut registers, &program), vec![0, 1, 2]);
+    }
+    #[test]
+    fn test_case_3() {
+        let mut registers = Registers {
+            a: 2024,
+            b: 0,
+            c: 0,
+        };
+        let program: Vec<u8> = vec![0, 1, 5, 4, 3, 0];
+        let expected = vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0];
+        assert_eq!(run_program(&mut registers, &program), expected);
+        assert_eq!(registers.a, 0);
+    }
+    #[test]
+    fn test_case_4() {
+        let mut registers = Registers { a: 0, b: 29, c: 0 };
+        let program: Vec<u8> = vec![1, 7];
+        run_program(&mut registers, &program);
+        assert_eq!(registers.b, 26);
+    }
+    #[test]
+    fn test_case_5() {
+        let mut registers = Registers {
+            a: 0,
+            b: 2024,
+            c: 43690,
+        };
+        let program: Vec<u8> = vec![4, 0];
+        run_program(&mut registers, &program);
+        assert_eq!(registers.b, 44354);
+    }
+    #[test]
+    fn test_case_6() {
+        let mut registers = Registers {
+            a: 117440,
+            b: 0,
+            c: 0,
+        };
+        let program: Vec<u8> = vec![0, 3, 5, 4, 3, 0];
+        assert!(program.eq(&run_program(&mut registers, &program)));
+    }
+
+    #[test]
+    fn test_whole_instructions_only() {
+        let input = |program: &str| {
+            format!(
+                "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                program
+            )
+        };
+        let error = parse_input(&input("0")).err().map(|e| (e.line, e.column));
+        assert_eq!(error, Some((5, 11)));
+        assert!(parse_input(&input("0,1,8,1")).is_err());
+
+        // dividing by 2 to the power of a register past 63 leaves nothing
+        let mut registers = Registers { a: 5, b: 64, c: 0 };
+        run_program(&mut registers, &[0, 5]);
+        assert_eq!(registers.a, 0);
+    }
+}
@@ -0,0 +1,291 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution, debug};
+use aoc_grid::view::{self, Cell, Color, Visualize};
+use aoc_grid::{Grid, Position};
+use aoc_search::bfs;
+use std::collections::HashSet;
+
+const GRID_SIZE: usize = 71;
+const TAKE: usize = 1024;
+/// the example's memory space and bytes fallen for part 1, both smaller
+const EXAMPLE_GRID_SIZE: usize = 7;
+const EXAMPLE_TAKE: usize = 12;
+
+const START: Position = Position { x: 0, y: 0 };
+
+type Path = Vec<Position>;
+
+fn parse_coordinate(line: &mut Scanner, size: usize) -> Result<usize, ParseError> {
+    let before = line.clone();
+    let value: usize = line.number()?;
+    match value < size {
+        true => Ok(value),
+        false => Err(before.error(format!("a coordinate below {}", size))),
+    }
+}
+
+fn parse_position(line: &mut Scanner, size: usize) -> Result<Position, ParseError> {
+    let x = parse_coordinate(line, size)?;
+    line.expect(",")?;
+    let y = parse_coordinate(line, size)?;
+    line.end()?;
+    Ok(Position::new(x, y))
+}
+
+fn memory_space(size: usize) -> Grid<char> {
+    Grid::new(size, size, '.')
+}
+
+fn corrupt_with(grid: &mut Grid<char>, bytes: &[Position]) {
+    for &byte in bytes {
+        grid[byte] = '#';
+    }
+}
+
+/// The memory space with the way out through it.
+struct Memory<'a> {
+    grid: &'a Grid<char>,
+    path: &'a Path,
+}
+
+impl Visualize for Memory<'_> {
+    fn view(&self) -> Grid<Cell> {
+        let mut view = self.grid.map(|&c| match c {
+            '#' => Cell::new(c, Color::RED),
+            _ => Cell::new(c, Color::DARK_GREY),
+        });
+        for &position in self.path {
+            view[position] = Cell::new('O', Color::GREEN);
+        }
+        view
+    }
+}
+
+fn solve(grid: &Grid<char>) -> Option<Path> {
+    let end = Position::new(grid.width() - 1, grid.height() - 1);
+    bfs(
+        START,
+        |&position| {
+            grid.neighbours4(position)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        },
+        |&position| position == end,
+    )
+}
+
+/// Side of the memory space and bytes fallen for part 1: the example's 7
+/// and 12 when every byte falls within its space, or else the puzzle's 71
+/// and a kilobyte.
+pub fn dimensions(bytes: &[Position]) -> (usize, usize) {
+    let largest = bytes.iter().map(|byte| byte.x.max(byte.y)).max();
+    match largest.unwrap_or(0) < EXAMPLE_GRID_SIZE {
+        true => (EXAMPLE_GRID_SIZE, EXAMPLE_TAKE),
+        false => (GRID_SIZE, TAKE),
+    }
+}
+
+pub fn parse_bytes(input: &str) -> Result<Vec<Position>, ParseError> {
+    aoc_core::lines(input)
+        .map(|mut line| parse_position(&mut line, GRID_SIZE))
+        .collect()
+}
+
+/// minimum steps to reach the exit once `take` bytes have fallen, if it
+/// can still be reached
+pub fn steps_to_exit(bytes: &[Position], size: usize, take: usize) -> Option<usize> {
+    let grid = &mut memory_space(size);
+    corrupt_with(grid, &bytes[..take.min(bytes.len())]);
+    let path = solve(grid)?;
+    let memory = Memory { grid, path: &path };
+    debug!("{}", view::text(&memory.view()));
+    view::show(&memory);
+    Some(path.len() - 1)
+}
+
+/// the first byte after the first `take` that cuts off the exit
+pub fn first_blocking_byte(bytes: &[Position], size: usize, take: usize) -> Option<String> {
+    let grid = &memory_space(size);
+    for t in take.min(bytes.len())..=bytes.len() {
+        let mut new_grid = grid.clone();
+        corrupt_with(&mut new_grid, &bytes[..t]);
+        match solve(&new_grid) {
+            Some(path) => view::show(&Memory {
+                grid: &new_grid,
+                path: &path,
+            }),
+            None => return Some(format!("{},{}", bytes[t - 1].x, bytes[t - 1].y)),
+        }
+    }
+    None
+}
+
+pub fn part1(bytes: &[Position]) -> Option<usize> {
+    let (size, take) = dimensions(bytes);
+    steps_to_exit(bytes, size, take)
+}
+
+pub fn part2(bytes: &[Position]) -> Option<String> {
+    let (size, take) = dimensions(bytes);
+    first_blocking_byte(bytes, size, take)
+}
+
+/// Steps to every spot from the start, by going over the whole space again
+/// and again until no spot gets any closer.
+fn brute_force_steps(grid: &Grid<char>) -> Grid<Option<usize>> {
+    let mut steps = Grid::new(grid.width(), grid.height(), None);
+    if grid[START] != '#' {
+        steps[START] = Some(0);
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for position in grid.positions().filter(|&p| grid[p] != '#') {
+            for next in grid.neighbours4(position) {
+                let Some(next_steps) = steps[next] else {
+                    continue;
+                };
+                if steps[position].is_none_or(|s| next_steps + 1 < s) {
+                    steps[position] = Some(next_steps + 1);
+                    changed = true;
+                }
+            }
+        }
+    }
+    steps
+}
+
+/// Whether the exit can be reached at all, by trying every way there.
+fn brute_force_reachable(grid: &Grid<char>) -> bool {
+    let end = Position::new(grid.width() - 1, grid.height() - 1);
+    let mut seen = Grid::new(grid.width(), grid.height(), false);
+    let mut stack = vec![START];
+    while let Some(position) = stack.pop() {
+        if grid[position] == '#' || seen[position] {
+            continue;
+        }
+        seen[position] = true;
+        stack.extend(grid.neighbours4(position));
+    }
+    seen[end]
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Position>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_bytes(input)
+    }
+
+    fn part1(bytes: &Self::Input) -> Answer {
+        part1(bytes).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(bytes: &Self::Input) -> Answer {
+        part2(bytes).map_or(Answer::None, Answer::from)
+    }
+}
+
+/// Bytes falling on every spot of a `size` by `size` memory space but the
+/// corners, leaving a way out until after the first `take` of them.
+pub fn generate_bytes(size: usize, take: usize, rng: &mut Rng) -> Vec<Position> {
+    let size = size.max(2);
+    let end = Position::new(size - 1, size - 1);
+    // a wandering path down and right that only gets corrupted later
+    let mut path = HashSet::from([START]);
+    let mut at = START;
+    while at != end {
+        at = match (at.x + 1 < size, at.y + 1 < size, rng.chance(0.5)) {
+            (true, true, true) | (true, false, _) => Position::new(at.x + 1, at.y),
+            _ => Position::new(at.x, at.y + 1),
+        };
+        path.insert(at);
+    }
+    let space = memory_space(size);
+    let (mut early, mut late): (Vec<Position>, Vec<Position>) = space
+        .positions()
+        .filter(|&pos| pos != START && pos != end)
+        .partition(|pos| !path.contains(pos));
+    rng.shuffle(&mut early);
+    let take = take.min(early.len());
+    late.extend(early.drain(take..));
+    rng.shuffle(&mut late);
+    early.extend(late);
+    early
+}
+
+impl Generate for Day18 {
+    /// the puzzle's 71 by 71 memory space, with `size` rows' worth of bytes
+    /// falling after the first kilobyte
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut bytes = generate_bytes(GRID_SIZE, TAKE, rng);
+        bytes.truncate(TAKE + size * GRID_SIZE);
+        bytes
+            .iter()
+            .map(|byte| format!("{},{}\n", byte.x, byte.y))
+            .collect()
+    }
+
+    fn brute_force(bytes: &Self::Input, part: u8) -> Option<Answer> {
+        let (size, take) = dimensions(bytes);
+        let fallen = |t: usize| {
+            let mut grid = memory_space(size);
+            corrupt_with(&mut grid, &bytes[..t]);
+            grid
+        };
+        let answer = match part {
+            1 => {
+                let end = Position::new(size - 1, size - 1);
+                brute_force_steps(&fallen(take.min(bytes.len())))[end].map(Answer::from)
+            }
+            _ => (take.min(bytes.len())..=bytes.len())
+                .find(|&t| !brute_force_reachable(&fallen(t)))
+                .map(|t| Answer::from(format!("{},{}", bytes[t - 1].x, bytes[t - 1].y))),
+        };
+        Some(answer.unwrap_or(Answer::None))
+    }
+
+    fn render(bytes: &Self::Input) -> String {
+        bytes
+            .iter()
+            .map(|byte| format!("{},{}\n", byte.x, byte.y))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day18>(EXAMPLE, 1, 22);
+        assert_example::<Day18>(EXAMPLE, 2, "6,1");
+        let bytes = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::brute_force(&bytes, 1), Some(Answer::from(22)));
+        assert_eq!(Day18::brute_force(&bytes, 2), Some(Answer::from("6,1")));
+    }
+
+    #[test]
+    fn test_coordinates_within_space() {
+        let error = parse_bytes("5,4\n71,0\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(parse_bytes("70,70\n").is_ok());
+        assert!(parse_bytes("70,71\n").is_err());
+    }
+
+    #[test]
+    fn test_space_fits_the_bytes() {
+        // a few bytes are enough for the puzzle's space once one falls
+        // outside the example's
+        let bytes = parse_bytes("0,1\n6,6\n").unwrap();
+        assert_eq!(dimensions(&bytes), (EXAMPLE_GRID_SIZE, EXAMPLE_TAKE));
+        let bytes = parse_bytes("0,1\n7,6\n").unwrap();
+        assert_eq!(dimensions(&bytes), (GRID_SIZE, TAKE));
+        assert_eq!(part1(&bytes), Some(140));
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution};
+use std::collections::HashMap;
+
+const COLORS: &str = "wubrg";
+
+fn parse_stripes(line: &mut Scanner) -> Result<String, ParseError> {
+    line.word_as("a pattern of 'w', 'u', 'b', 'r' and 'g' stripes", |word| {
+        word.chars()
+            .all(|c| COLORS.contains(c))
+            .then(|| word.to_string())
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let [towels, designs] = aoc_core::two_sections(input, "the designs")?;
+    if let Some(extra) = towels.get(1) {
+        return Err(extra.error("a blank line"));
+    }
+    let mut line = towels[0].clone();
+    let towels = line.separated(",", parse_stripes)?;
+    line.end()?;
+    let designs = designs
+        .into_iter()
+        .map(|mut line| {
+            let design = parse_stripes(&mut line)?;
+            line.end()?;
+            Ok(design)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((towels, designs))
+}
+
+pub fn towel_arrangements(
+    design: &str,
+    towels: &[String],
+    memo: &mut HashMap<String, usize>,
+) -> usize {
+    if let Some(v) = memo.get(design) {
+        return *v;
+    }
+
+    if design.is_empty() {
+        return 1;
+    }
+
+    let mut arrangements = 0;
+    for towel in towels {
+        let towel_len = towel.len();
+        if towel_len > design.len() {
+            continue;
+        }
+
+        if design[..towel_len] == *towel {
+            let rest = &design[towel_len..];
+            let next_arrangements = towel_arrangements(rest, towels, memo);
+            memo.insert(rest.to_string(), next_arrangements);
+            arrangements += next_arrangements;
+        }
+    }
+    arrangements
+}
+
+fn all_arrangements(towels: &[String], designs: &[String]) -> Vec<usize> {
+    let mut arrangements = Vec::new();
+    for design in designs {
+        let mut memo = HashMap::new();
+        arrangements.push(towel_arrangements(design, towels, &mut memo));
+    }
+    arrangements
+}
+
+/// designs that can be done with the given towels
+pub fn part1(towels: &[String], designs: &[String]) -> usize {
+    all_arrangements(towels, designs)
+        .iter()
+        .filter(|&&a| a > 0)
+        .count()
+}
+
+pub fn part2(towels: &[String], designs: &[String]) -> usize {
+    all_arrangements(towels, designs).iter().sum()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (towels, designs) = input;
+        part1(towels, designs).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (towels, designs) = input;
+        part2(towels, designs).into()
+    }
+}
+
+impl Generate for Day19 {
+    /// `size` towels (at least 5) and `size` designs, most of them made of
+    /// those towels
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let colors: Vec<char> = COLORS.chars().collect();
+        let stripes = |rng: &mut Rng, length: i64| -> String {
+            (0..length).map(|_| *rng.pick(&colors)).collect()
+        };
+        let mut towels: Vec<String> = Vec::new();
+        while towels.len() < size.max(5) {
+            let length = rng.range(1, 8);
+            let towel = stripes(rng, length);
+            if !towels.contains(&towel) {
+                towels.push(towel);
+            }
+        }
+        let mut input = format!("{}\n\n", towels.join(", "));
+        for _ in 0..size {
+            let mut design = String::new();
+            while design.len() < 20 || (design.len() < 60 && rng.chance(0.8)) {
+                match rng.chance(0.98) {
+                    true => design.push_str(rng.pick::<String>(&towels)),
+                    false => design.push_str(&stripes(rng, 1)),
+                }
+            }
+            input.push_str(&design);
+            input.push('\n');
+        }
+        input
+    }
+
+    fn render((towels, designs): &Self::Input) -> String {
+        format!("{}\n\n{}\n", towels.join(", "), designs.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day19>(EXAMPLE, 1, 6);
+        assert_example::<Day19>(EXAMPLE, 2, 16);
+    }
+}
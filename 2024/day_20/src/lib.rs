@@ -0,0 +1,206 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution, debug, info};
+use aoc_grid::view::{self, Cell, Color, Visualize};
+use aoc_grid::{Grid, Position};
+use aoc_search::bfs;
+use std::collections::HashMap;
+
+const THRESHOLD: usize = 100;
+
+pub type Path = Vec<Position>;
+
+#[derive(Clone)]
+pub struct Racetrack {
+    tiles: Grid<char>,
+    start: Position,
+    end: Position,
+}
+
+impl Racetrack {
+    pub fn from_text(text: &str) -> Result<Racetrack, ParseError> {
+        let tiles = Grid::parse(text, "'#', '.', 'S' or 'E'", |c| {
+            "#.SE".contains(c).then_some(c)
+        })?;
+        let find = |tile: char| {
+            tiles
+                .find(|&c| c == tile)
+                .ok_or_else(|| aoc_core::unexpected_end(text, format!("a tile '{}'", tile)))
+        };
+        let start = find('S')?;
+        let end = find('E')?;
+        Ok(Racetrack { tiles, start, end })
+    }
+}
+
+/// The racetrack with the way around it.
+struct Race<'a> {
+    track: &'a Racetrack,
+    path: &'a Path,
+}
+
+impl Visualize for Race<'_> {
+    fn view(&self) -> Grid<Cell> {
+        let mut view = self.track.tiles.map(|&c| match c {
+            '#' => Cell::new(c, Color::GREY),
+            _ => Cell::new(c, Color::DARK_GREY),
+        });
+        for &position in self.path {
+            view[position] = Cell::new('O', Color::RED);
+        }
+        view
+    }
+}
+
+pub fn find_path(track: &Racetrack) -> Path {
+    bfs(
+        track.start,
+        |&position| {
+            track
+                .tiles
+                .neighbours4(position)
+                .filter(|&next| track.tiles[next] != '#')
+                .collect::<Vec<_>>()
+        },
+        |&position| position == track.end,
+    )
+    .unwrap()
+}
+
+fn find_jumpable(track: &Racetrack, position: Position, distance: usize) -> Vec<Position> {
+    let x1 = position.x.saturating_sub(distance);
+    let y1 = position.y.saturating_sub(distance);
+    let x2 = std::cmp::min(position.x + distance + 1, track.tiles.width() - 1);
+    let y2 = std::cmp::min(position.y + distance + 1, track.tiles.height() - 1);
+
+    let mut jumpables = Vec::new();
+    for y in y1..y2 {
+        for x in x1..x2 {
+            let jump = Position::new(x, y);
+            if position.manhattan_distance(jump) <= distance
+                && ['.', 'E'].contains(&track.tiles[jump])
+            {
+                jumpables.push(jump);
+            }
+        }
+    }
+    jumpables
+}
+
+/// cheats of up to `allowed_jumps` that save at least `threshold` picoseconds
+pub fn solve(track: &Racetrack, path: &Path, allowed_jumps: usize, threshold: usize) -> usize {
+    let mut times = HashMap::new();
+    for (i, position) in path.iter().enumerate() {
+        times.insert(position, path.len() - i);
+    }
+
+    let mut cheats = 0;
+    for position in path {
+        let current_cost = times.get(&position).unwrap();
+
+        let jumpables = find_jumpable(track, *position, allowed_jumps);
+
+        for jumpable in jumpables {
+            let new_cost = times.get(&jumpable).unwrap();
+            let cheat_cost = position.manhattan_distance(jumpable);
+
+            if new_cost + cheat_cost < *current_cost
+                && current_cost - new_cost - cheat_cost >= threshold
+            {
+                cheats += 1;
+            }
+        }
+    }
+    cheats
+}
+
+/// cheats of length 2 that save at least `THRESHOLD` picoseconds
+pub fn part1(track: &Racetrack) -> usize {
+    let path = &find_path(track);
+    let race = Race { track, path };
+    debug!("{}", view::terminal(&race.view()));
+    view::show(&race);
+    info!("Picoseconds to run: {}", path.len());
+    solve(track, path, 2, THRESHOLD)
+}
+
+/// cheats of length 20 that save at least `THRESHOLD` picoseconds
+pub fn part2(track: &Racetrack) -> usize {
+    let path = &find_path(track);
+    solve(track, path, 20, THRESHOLD)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Racetrack;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Racetrack::from_text(input)
+    }
+
+    fn part1(track: &Self::Input) -> Answer {
+        part1(track).into()
+    }
+
+    fn part2(track: &Self::Input) -> Answer {
+        part2(track).into()
+    }
+}
+
+impl Generate for Day20 {
+    /// a single lane track winding through a maze of `size` by `size` cells
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let cells = size.max(2);
+        let side = 2 * cells + 1;
+        // a random depth first walk, keeping the longest way it went
+        let mut visited = Grid::new(cells, cells, false);
+        let mut stack = vec![Position::new(0, 0)];
+        let mut longest = stack.clone();
+        visited[stack[0]] = true;
+        while let Some(&cell) = stack.last() {
+            let unvisited: Vec<Position> =
+                visited.neighbours4(cell).filter(|&n| !visited[n]).collect();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let next = *rng.pick(&unvisited);
+            visited[next] = true;
+            stack.push(next);
+            if stack.len() > longest.len() {
+                longest = stack.clone();
+            }
+        }
+        let mut track = Grid::new(side, side, '#');
+        for (i, cell) in longest.iter().enumerate() {
+            track[Position::new(2 * cell.x + 1, 2 * cell.y + 1)] = '.';
+            if let Some(next) = longest.get(i + 1) {
+                track[Position::new(cell.x + next.x + 1, cell.y + next.y + 1)] = '.';
+            }
+        }
+        let (first, last) = (longest[0], longest[longest.len() - 1]);
+        track[Position::new(2 * first.x + 1, 2 * first.y + 1)] = 'S';
+        track[Position::new(2 * last.x + 1, 2 * last.y + 1)] = 'E';
+        track.render(|_, &c| c)
+    }
+
+    fn render(track: &Self::Input) -> String {
+        track.tiles.render(|_, &c| c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        let track = Day20::parse(EXAMPLE).unwrap();
+        let path = &find_path(&track);
+        assert_eq!(solve(&track, path, 2, 2), 44);
+        assert_eq!(solve(&track, path, 2, 64), 1);
+        assert_eq!(solve(&track, path, 20, 50), 285);
+        assert_eq!(solve(&track, path, 20, 76), 3);
+    }
+}
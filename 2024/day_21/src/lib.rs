@@ -0,0 +1,230 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_search::dijkstra_all;
+use std::collections::HashMap;
+
+const UP: char = '^';
+const DOWN: char = 'v';
+const RIGHT: char = '>';
+const LEFT: char = '<';
+const ACCEPT: char = 'A';
+
+type Action = char;
+
+struct Pad {
+    graph: HashMap<char, HashMap<Action, char>>,
+}
+
+impl Pad {
+    fn new_numpad() -> Pad {
+        let mut graph: HashMap<char, HashMap<Action, char>> = HashMap::new();
+
+        graph.insert('A', HashMap::from([(UP, '3'), (LEFT, '0')]));
+        graph.insert('0', HashMap::from([(UP, '2'), (RIGHT, 'A')]));
+        graph.insert('1', HashMap::from([(UP, '4'), (RIGHT, '2')]));
+        graph.insert(
+            '2',
+            HashMap::from([(UP, '5'), (RIGHT, '3'), (LEFT, '1'), (DOWN, '0')]),
+        );
+        graph.insert('3', HashMap::from([(UP, '6'), (LEFT, '2'), (DOWN, 'A')]));
+        graph.insert('4', HashMap::from([(UP, '7'), (RIGHT, '5'), (DOWN, '1')]));
+        graph.insert(
+            '5',
+            HashMap::from([(UP, '8'), (RIGHT, '6'), (LEFT, '4'), (DOWN, '2')]),
+        );
+        graph.insert('6', HashMap::from([(UP, '9'), (LEFT, '5'), (DOWN, '3')]));
+        graph.insert('7', HashMap::from([(RIGHT, '8'), (DOWN, '4')]));
+        graph.insert('8', HashMap::from([(RIGHT, '9'), (LEFT, '7'), (DOWN, '5')]));
+        graph.insert('9', HashMap::from([(LEFT, '8'), (DOWN, '6')]));
+        Pad { graph }
+    }
+
+    fn new_arrowpad() -> Pad {
+        let mut graph: HashMap<char, HashMap<Action, char>> = HashMap::new();
+
+        graph.insert('A', HashMap::from([(DOWN, '>'), (LEFT, '^')]));
+        graph.insert('>', HashMap::from([(LEFT, 'v'), (UP, 'A')]));
+        graph.insert('^', HashMap::from([(DOWN, 'v'), (RIGHT, 'A')]));
+        graph.insert('<', HashMap::from([(RIGHT, 'v')]));
+        graph.insert('v', HashMap::from([(UP, '^'), (RIGHT, '>'), (LEFT, '<')]));
+        Pad { graph }
+    }
+
+    fn find_sequences(&self, from: char, to: char) -> Vec<Vec<Action>> {
+        if from == to {
+            return vec![vec![ACCEPT]];
+        }
+
+        let successors = |node: &char| {
+            self.graph[node]
+                .values()
+                .map(|&next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let shortest = dijkstra_all(from, successors, |&node| node == to).unwrap();
+        shortest
+            .paths()
+            .iter()
+            .map(|path| {
+                let mut sequence: Vec<Action> = path
+                    .windows(2)
+                    .map(|step| self.action_between(step[0], step[1]))
+                    .collect();
+                sequence.push(ACCEPT);
+                sequence
+            })
+            .collect()
+    }
+
+    fn action_between(&self, from: char, to: char) -> Action {
+        let (&action, _) = self.graph[&from]
+            .iter()
+            .find(|&(_, &node)| node == to)
+            .unwrap();
+        action
+    }
+}
+
+fn get_numpad_sequences(numpad: &Pad, code: &[char]) -> Vec<Vec<Action>> {
+    let mut from = ACCEPT;
+    let mut full_sequences = vec![Vec::new()];
+
+    for target in code.iter() {
+        let sequences = numpad.find_sequences(from, *target);
+
+        let mut new_full_sequences = Vec::new();
+        for full_sequence in full_sequences {
+            for sequence in &sequences {
+                let mut new_full_sequence = full_sequence.clone();
+                new_full_sequence.extend(sequence);
+                new_full_sequences.push(new_full_sequence);
+            }
+        }
+
+        full_sequences = new_full_sequences;
+        from = *target;
+    }
+    full_sequences
+}
+
+fn get_sequence_length(
+    arrowpad: &Pad,
+    sequence: &Vec<Action>,
+    steps: u8,
+    memo: &mut HashMap<(Vec<Action>, u8), usize>,
+) -> usize {
+    if let Some(&s) = memo.get(&(sequence.clone(), steps)) {
+        return s;
+    }
+
+    if steps == 0 {
+        return sequence.len();
+    }
+
+    let mut start = ACCEPT;
+    let mut length = 0;
+    for &end in sequence {
+        let subsequences = arrowpad.find_sequences(start, end);
+
+        let mut shortest: usize = usize::MAX;
+        for subsequence in subsequences {
+            let next_len = get_sequence_length(arrowpad, &subsequence, steps - 1, memo);
+            if next_len < shortest {
+                shortest = next_len;
+            }
+        }
+        length += shortest;
+        start = end;
+    }
+    memo.insert((sequence.clone(), steps), length);
+    length
+}
+
+pub fn parse_codes(input: &str) -> Result<Vec<String>, ParseError> {
+    aoc_core::lines(input)
+        .map(|mut line| {
+            let code = line.word_as("digits followed by 'A'", |word| {
+                let digits = word.strip_suffix('A')?;
+                let valid = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+                valid.then(|| word.to_string())
+            })?;
+            line.end()?;
+            Ok(code)
+        })
+        .collect()
+}
+
+pub fn run(codes: &[String], robots: u8) -> usize {
+    let numpad = Pad::new_numpad();
+    let arrowpad = Pad::new_arrowpad();
+
+    let mut memo = HashMap::new();
+    let mut sum = 0;
+    for code in codes {
+        let numbers: Vec<char> = code.chars().collect();
+        let sequences = get_numpad_sequences(&numpad, &numbers);
+
+        let mut shortest = usize::MAX;
+        for sequence in sequences {
+            let len = get_sequence_length(&arrowpad, &sequence, robots, &mut memo);
+            if len < shortest {
+                shortest = len;
+            }
+        }
+
+        let code_number: usize = code.trim()[..code.len() - 1].parse().unwrap();
+        sum += code_number * shortest;
+    }
+    sum
+}
+
+pub fn part1(codes: &[String]) -> usize {
+    run(codes, 2)
+}
+
+pub fn part2(codes: &[String]) -> usize {
+    run(codes, 25)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_codes(input)
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        part1(codes).into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        part2(codes).into()
+    }
+}
+
+impl Generate for Day21 {
+    /// `size` door codes
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!("{:03}A\n", rng.range(0, 999)))
+            .collect()
+    }
+
+    fn render(codes: &Self::Input) -> String {
+        codes.iter().map(|code| format!("{}\n", code)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day21>(EXAMPLE, 1, 126384);
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+pub fn parse_input(input: &str) -> Result<Vec<(&str, &str)>, ParseError> {
+    let mut connections = Vec::new();
+    for mut line in aoc_core::lines(input) {
+        let from = line.word()?;
+        line.expect("-")?;
+        let to = line.word()?;
+        line.end()?;
+        connections.push((from, to));
+    }
+    Ok(connections)
+}
+
+pub fn build_graph(connections: &Vec<(&str, &str)>) -> HashMap<String, Vec<String>> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    for &(from, to) in connections {
+        graph
+            .entry(from.to_string())
+            .or_default()
+            .push(to.to_string());
+
+        graph
+            .entry(to.to_string())
+            .or_default()
+            .push(from.to_string());
+    }
+    graph
+}
+
+/// sets of 3 inter-connected computers with at least one name starting with 't'
+pub fn part1(graph: &HashMap<String, Vec<String>>) -> usize {
+    let mut triangles: HashSet<Vec<String>> = HashSet::new();
+    for (current, neighbors) in graph.iter() {
+        for neighbor in neighbors {
+            let next_neighbors = graph.get(neighbor).unwrap();
+            for next_neighbor in next_neighbors {
+                if neighbors.contains(next_neighbor)
+                    && (current.starts_with("t")
+                        || neighbor.starts_with("t")
+                        || next_neighbor.starts_with("t"))
+                {
+                    let mut triangle = vec![
+                        current.to_string(),
+                        neighbor.to_string(),
+                        next_neighbor.to_string(),
+                    ];
+                    triangle.sort();
+                    triangles.insert(triangle);
+                }
+            }
+        }
+    }
+    triangles.len()
+}
+
+pub fn largest_fully_connected(graph: &HashMap<String, Vec<String>>) -> Vec<String> {
+    let mut queue: VecDeque<Vec<&str>> = VecDeque::new();
+    let nodes: Vec<Vec<&str>> = graph.keys().map(|x| vec![x.as_str()]).collect();
+    queue.extend(nodes);
+
+    let mut longest = Vec::new();
+
+    let mut scanned: HashSet<Vec<&str>> = HashSet::new();
+    while let Some(network) = queue.pop_front() {
+        let last = network.last().unwrap();
+        for neighbor in graph.get(*last).unwrap() {
+            if !network
+                .iter()
+                .all(|x| graph.get(*x).unwrap().contains(neighbor))
+            {
+                continue;
+            }
+
+            let mut new_network = network.clone();
+            new_network.push(neighbor);
+            new_network.sort();
+            if scanned.contains(&new_network) {
+                continue;
+            }
+            if new_network.len() > longest.len() {
+                longest = new_network.iter().map(|x| x.to_string()).collect();
+            }
+            queue.push_back(new_network.clone());
+            scanned.insert(new_network);
+        }
+    }
+    longest
+}
+
+/// password to get into the LAN party
+pub fn part2(graph: &HashMap<String, Vec<String>>) -> String {
+    let mut network = largest_fully_connected(graph);
+    network.sort();
+    network.join(",")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(build_graph(&parse_input(input)?))
+    }
+
+    fn part1(graph: &Self::Input) -> Answer {
+        part1(graph).into()
+    }
+
+    fn part2(graph: &Self::Input) -> Answer {
+        part2(graph).into()
+    }
+}
+
+impl Generate for Day23 {
+    /// `size` computers (from 4 to 676, as many as there are names), each
+    /// linked to a few others, and a LAN party of up to 13 all linked
+    /// together
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let computers = size.clamp(4, 26 * 26);
+        let mut names: Vec<String> = (b'a'..=b'z')
+            .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(computers);
+
+        let mut links = BTreeSet::new();
+        let mut link = |a: usize, b: usize| {
+            if a != b {
+                links.insert((a.min(b), a.max(b)));
+            }
+        };
+        for a in 0..computers {
+            for _ in 0..3 {
+                link(a, rng.below(computers));
+            }
+        }
+        let mut party: Vec<usize> = (0..computers).collect();
+        rng.shuffle(&mut party);
+        party.truncate(13);
+        for (i, &a) in party.iter().enumerate() {
+            for &b in &party[i + 1..] {
+                link(a, b);
+            }
+        }
+
+        let mut lines: Vec<String> = links
+            .into_iter()
+            .map(|(a, b)| format!("{}-{}\n", names[a], names[b]))
+            .collect();
+        rng.shuffle(&mut lines);
+        lines.concat()
+    }
+
+    /// each connection once, sorted, as the graph holds both ends of it
+    fn render(graph: &Self::Input) -> String {
+        let mut lines = Vec::new();
+        for (from, tos) in graph {
+            // a computer wired to itself is on its own list twice
+            let mut loops = 0;
+            for to in tos {
+                match from.cmp(to) {
+                    Ordering::Less => lines.push(format!("{}-{}\n", from, to)),
+                    Ordering::Equal => loops += 1,
+                    Ordering::Greater => {}
+                }
+            }
+            lines.extend(std::iter::repeat_n(
+                format!("{}-{}\n", from, from),
+                loops / 2,
+            ));
+        }
+        lines.sort();
+        lines.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day23>(EXAMPLE, 1, 7);
+        assert_example::<Day23>(EXAMPLE, 2, "co,de,ka,ta");
+    }
+}
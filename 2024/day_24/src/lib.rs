@@ -0,0 +1,628 @@
+// Some of the ugliest code I've written in my life, but
+// it gets the job done and I don't want to revisit this
+// MADNESS.
+
+use aoc_core::log::{self, Level};
+use aoc_core::{Answer, Generate, ParseError, Rng, Scanner, Solution, debug, info};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+pub type Wires = HashMap<String, Option<bool>>;
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+enum GateOp {
+    AND,
+    OR,
+    XOR,
+}
+
+impl GateOp {
+    fn from_name(name: &str) -> Option<GateOp> {
+        match name {
+            "AND" => Some(GateOp::AND),
+            "OR" => Some(GateOp::OR),
+            "XOR" => Some(GateOp::XOR),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, a: bool, b: bool) -> bool {
+        match &self {
+            GateOp::AND => a && b,
+            GateOp::OR => a || b,
+            GateOp::XOR => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Gate {
+    inputs: (String, String),
+    output: String,
+    op: GateOp,
+}
+
+impl fmt::Display for Gate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} {} -> {}",
+            self.inputs.0, self.op, self.inputs.1, self.output
+        )
+    }
+}
+
+fn bool_from_char(c: char) -> Option<bool> {
+    match c {
+        '0' => Some(false),
+        '1' => Some(true),
+        _ => None,
+    }
+}
+
+/// A wire name, and where it was read for the errors about it.
+fn parse_wire<'a>(line: &mut Scanner<'a>) -> Result<(Scanner<'a>, String), ParseError> {
+    line.skip_spaces();
+    let at = line.clone();
+    Ok((at, line.word()?.to_string()))
+}
+
+/// The z wires are the output's bits, so they have to be `z00` up with no
+/// gaps, and fit in a u64.
+fn check_z_wires(wires: &[(Scanner, String)]) -> Result<(), ParseError> {
+    let names: HashSet<&String> = wires
+        .iter()
+        .map(|(_, wire)| wire)
+        .filter(|wire| wire.starts_with("z"))
+        .collect();
+    let bits = names.len().min(64);
+    let valid: HashSet<String> = (0..bits).map(|bit| format!("z{:02}", bit)).collect();
+    match wires
+        .iter()
+        .find(|(_, wire)| wire.starts_with("z") && !valid.contains(wire))
+    {
+        Some((at, _)) => Err(at.error("z wires numbered from z00 to at most z63, with no gaps")),
+        None => Ok(()),
+    }
+}
+
+/// Gates only ever read wires set at the start or by another gate, and
+/// never their own output, however far back.
+fn check_gates(
+    wires: &Wires,
+    gates: &[Gate],
+    gate_lines: &[Scanner],
+    inputs: &[(Scanner, String)],
+) -> Result<(), ParseError> {
+    let outputs: HashSet<&String> = gates.iter().map(|gate| &gate.output).collect();
+    for (at, wire) in inputs {
+        if wires[wire].is_none() && !outputs.contains(wire) {
+            return Err(at.error("a wire set at the start or by a gate"));
+        }
+    }
+
+    let mut known: HashSet<&String> = wires
+        .iter()
+        .filter(|(_, value)| value.is_some())
+        .map(|(wire, _)| wire)
+        .collect();
+    let mut pending: Vec<usize> = (0..gates.len()).collect();
+    loop {
+        let before = pending.len();
+        pending.retain(|&i| {
+            let gate = &gates[i];
+            let ready = known.contains(&gate.inputs.0) && known.contains(&gate.inputs.1);
+            if ready {
+                known.insert(&gate.output);
+            }
+            !ready
+        });
+        match pending.first() {
+            None => return Ok(()),
+            Some(&i) if pending.len() == before => {
+                return Err(gate_lines[i].error("a gate that isn't fed by its own output"));
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Wires, Vec<Gate>), ParseError> {
+    let [initial, connections] = aoc_core::two_sections(input, "the gates")?;
+
+    let mut wires: Wires = HashMap::new();
+    let mut gates = Vec::new();
+    // where each gate and each wire was read, for the checks at the end
+    let mut gate_lines = Vec::new();
+    let mut gate_inputs = Vec::new();
+    let mut seen = Vec::new();
+
+    for mut line in initial {
+        let (at, wire) = parse_wire(&mut line)?;
+        line.expect(": ")?;
+        let value = line.char_as("'0' or '1'", bool_from_char)?;
+        line.end()?;
+        wires.insert(wire.clone(), Some(value));
+        seen.push((at, wire));
+    }
+
+    for mut line in connections {
+        gate_lines.push(line.clone());
+        let input_a = parse_wire(&mut line)?;
+        let op = line.word_as("'AND', 'OR' or 'XOR'", GateOp::from_name)?;
+        let input_b = parse_wire(&mut line)?;
+        line.expect(" -> ")?;
+        let output = parse_wire(&mut line)?;
+        line.end()?;
+
+        gates.push(Gate {
+            inputs: (input_a.1.clone(), input_b.1.clone()),
+            op,
+            output: output.1.clone(),
+        });
+
+        for (_, wire) in [&input_a, &input_b, &output] {
+            if !wires.contains_key(wire) {
+                wires.insert(wire.to_string(), None);
+            }
+        }
+        gate_inputs.extend([input_a.clone(), input_b.clone()]);
+        seen.extend([input_a, input_b, output]);
+    }
+
+    check_z_wires(&seen)?;
+    check_gates(&wires, &gates, &gate_lines, &gate_inputs)?;
+    Ok((wires, gates))
+}
+
+pub fn wires_to_decimal(wires: &Wires, prefix: &str) -> u64 {
+    let mut decimal = 0;
+    let num_prefixed_wires = wires
+        .keys()
+        .filter(|x| x.starts_with(prefix))
+        .count()
+        .min(64);
+    for i in 0..num_prefixed_wires {
+        let wire_name = format!("{}{:02}", prefix, i);
+        // a wire that's missing or has no value counts as a 0
+        let wire_value = wires
+            .get(&wire_name)
+            .is_some_and(|&value| value == Some(true));
+        if wire_value {
+            decimal += 2_u64.pow(i as u32);
+        }
+    }
+    decimal
+}
+
+pub fn compute(wires: &mut Wires, gates: &[Gate]) -> Result<(), String> {
+    let mut queue: VecDeque<Gate> = VecDeque::new();
+    for gate in gates {
+        queue.push_back(gate.clone());
+    }
+
+    let mut fails = 0;
+    while let Some(g) = queue.pop_front() {
+        let val_a = wires.get(&g.inputs.0).copied().flatten();
+        let val_b = wires.get(&g.inputs.1).copied().flatten();
+        let (Some(val_a), Some(val_b)) = (val_a, val_b) else {
+            queue.push_back(g);
+            fails += 1;
+            if fails >= queue.len() {
+                return Err(String::from("Couldn't compute wire values"));
+            }
+            continue;
+        };
+        fails = 0;
+
+        let val_out = g.op.apply(val_a, val_b);
+        wires.insert(g.output, Some(val_out));
+    }
+    Ok(())
+}
+
+/// the number on the z wires, `None` when the gates can't all be worked out
+pub fn part1(wires: &Wires, gates: &[Gate]) -> Option<u64> {
+    let wires = &mut wires.clone();
+    compute(wires, gates).ok()?;
+    Some(wires_to_decimal(wires, "z"))
+}
+
+fn build_input_map(wires: &Vec<String>, gates: &[Gate]) -> HashMap<String, Vec<Gate>> {
+    let mut map = HashMap::new();
+    for wire in wires {
+        let mut gates_in = Vec::new();
+        for gate in gates {
+            if &gate.inputs.0 == wire || &gate.inputs.1 == wire {
+                gates_in.push(gate.clone());
+            }
+        }
+        map.insert(wire.to_string(), gates_in);
+    }
+    map
+}
+
+/// the gates that take bit `n` of x and y, and the ones right after them
+fn find_nth_bit_adder(n: usize, input_map: &HashMap<String, Vec<Gate>>) -> HashSet<Gate> {
+    let x_name = format!("x{:02}", n);
+    let y_name = format!("y{:02}", n);
+
+    let mut gates: HashSet<Gate> = HashSet::new();
+    for gate in input_map.get(&x_name).map_or(&[][..], Vec::as_slice) {
+        gates.insert(gate.clone());
+    }
+    for gate in input_map.get(&y_name).map_or(&[][..], Vec::as_slice) {
+        gates.insert(gate.clone());
+    }
+    let mut next_gates: HashSet<Gate> = HashSet::new();
+    for gate in &gates {
+        if !gate.output.starts_with("z") {
+            for next_gate in input_map.get(&gate.output).map_or(&[][..], Vec::as_slice) {
+                next_gates.insert(next_gate.clone());
+            }
+        }
+    }
+    gates.extend(next_gates);
+    gates
+}
+
+fn is_xyz(wire: &str) -> bool {
+    wire.starts_with("x") || wire.starts_with("y") || wire.starts_with("z")
+}
+
+/// the adder's last output, which takes the final carry instead of a sum
+fn last_output(gates: &[Gate]) -> String {
+    gates
+        .iter()
+        .map(|gate| &gate.output)
+        .filter(|wire| wire.starts_with("z"))
+        .max()
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn or_gates_no_xyz(gates: &[Gate], last: &str) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::OR {
+            continue;
+        }
+        if is_xyz(&gate.inputs.0) {
+            bad_wires.push(gate.inputs.0.clone());
+        }
+        if is_xyz(&gate.inputs.1) {
+            bad_wires.push(gate.inputs.1.clone());
+        }
+        if is_xyz(&gate.output) && gate.output != last {
+            bad_wires.push(gate.output.clone());
+        }
+    }
+    bad_wires
+}
+
+fn and_gates_no_xyz_output(gates: &[Gate]) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::AND {
+            continue;
+        }
+        if is_xyz(&gate.output) {
+            bad_wires.push(gate.output.clone());
+        }
+    }
+    bad_wires
+}
+
+fn and_xor_gates_both_xyz_or_none(gates: &[Gate]) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if ![GateOp::AND, GateOp::XOR].contains(&gate.op) {
+            continue;
+        }
+        if (is_xyz(&gate.inputs.0) && !is_xyz(&gate.inputs.1))
+            || (!is_xyz(&gate.inputs.0) && is_xyz(&gate.inputs.1))
+        {
+            bad_wires.push(gate.inputs.0.clone());
+            bad_wires.push(gate.inputs.1.clone());
+        }
+    }
+    bad_wires
+}
+
+fn and_output_is_or_input(gates: &[Gate], input_map: &HashMap<String, Vec<Gate>>) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::AND
+            // the output of the 0th bit doesn't fulfill this condition
+            || gate.inputs == (String::from("x00"), String::from("y00"))
+            || gate.inputs == (String::from("y00"), String::from("x00"))
+        {
+            continue;
+        }
+        let next_gates = input_map.get(&gate.output).map_or(&[][..], Vec::as_slice);
+        if next_gates.len() != 1 {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+        if next_gates[0].op != GateOp::OR {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+    }
+    bad_wires
+}
+
+fn or_output_goes_in_one_and_one_xor(
+    gates: &[Gate],
+    input_map: &HashMap<String, Vec<Gate>>,
+    last: &str,
+) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::OR || gate.output == last {
+            continue;
+        }
+        let next_gates = input_map.get(&gate.output).map_or(&[][..], Vec::as_slice);
+        if next_gates.len() != 2 {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+        if !((next_gates[0].op == GateOp::AND && next_gates[1].op == GateOp::XOR)
+            || (next_gates[0].op == GateOp::XOR && next_gates[1].op == GateOp::AND))
+        {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+    }
+    bad_wires
+}
+
+fn xor_output_non_z_goes_in_one_and_one_xor(
+    gates: &[Gate],
+    input_map: &HashMap<String, Vec<Gate>>,
+) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::XOR || gate.output.starts_with("z") {
+            continue;
+        }
+        let next_gates = input_map.get(&gate.output).map_or(&[][..], Vec::as_slice);
+        if next_gates.len() != 2 {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+        if !((next_gates[0].op == GateOp::AND && next_gates[1].op == GateOp::XOR)
+            || (next_gates[0].op == GateOp::XOR && next_gates[1].op == GateOp::AND))
+        {
+            bad_wires.push(gate.output.clone());
+            continue;
+        }
+    }
+    bad_wires
+}
+
+fn xor_with_non_xy_in_has_z_out(gates: &[Gate]) -> Vec<String> {
+    let mut bad_wires = Vec::new();
+    for gate in gates {
+        if gate.op != GateOp::XOR || is_xyz(&gate.inputs.0) || is_xyz(&gate.inputs.1) {
+            continue;
+        }
+
+        if !gate.output.starts_with("z") {
+            bad_wires.push(gate.output.clone());
+        }
+    }
+    bad_wires
+}
+
+// With a bit of help from Reddit, I read that the circuit represents
+// a Ripple Carry adder. My solution was to draw an adder for 1-bit
+// on a piece of paper and come up with some heuristics of what type
+// of wires can and can't go into a gate. The goal was to find all
+// the bad wires by finding wires that break the heuristics.
+// E.g. I figured out that the OR gate can't ever have an xNN, yNN
+// or zNN connection, unless it's the last bit.
+/// wires that need to be swapped, sorted and joined
+pub fn part2(wires: &Wires, gates: &[Gate]) -> String {
+    let input_map = build_input_map(&wires.keys().cloned().collect(), gates);
+    let last = last_output(gates);
+    if log::enabled(Level::Debug) {
+        let bits = wires.keys().filter(|wire| wire.starts_with("x")).count();
+        for n in 0..bits {
+            for gate in find_nth_bit_adder(n, &input_map) {
+                debug!("{} is part of bit {}", gate, n);
+            }
+            debug!();
+        }
+    }
+
+    let mut bad_wires: HashSet<String> = HashSet::new();
+    let bad = or_gates_no_xyz(gates, &last);
+    info!("CHECK: OR gates can't have xyz wires in or out: {:?}", bad);
+    bad_wires.extend(bad);
+
+    let bad = and_gates_no_xyz_output(gates);
+    info!("CHECK: AND gates can't have xyz outputs: {:?}", bad);
+    bad_wires.extend(bad);
+
+    let bad = and_xor_gates_both_xyz_or_none(gates);
+    info!(
+        "CHECK: AND/XOR gate inputs are both or neither xyz: {:?}",
+        bad
+    );
+    bad_wires.extend(bad);
+
+    let bad = and_output_is_or_input(gates, &input_map);
+    info!("CHECK: AND outputs are followed by a single OR: {:?}", bad);
+    bad_wires.extend(bad);
+
+    let bad = or_output_goes_in_one_and_one_xor(gates, &input_map, &last);
+    info!(
+        "CHECK: OR outputs go in exactly one AND & one XOR: {:?}",
+        bad
+    );
+    bad_wires.extend(bad);
+
+    let bad = xor_output_non_z_goes_in_one_and_one_xor(gates, &input_map);
+    info!(
+        "CHECK: Non-z XOR outputs go in exactly one AND & one XOR: {:?}",
+        bad
+    );
+    bad_wires.extend(bad);
+
+    let bad = xor_with_non_xy_in_has_z_out(gates);
+    info!("CHECK: XOR with non-xy inputs has z output: {:?}", bad);
+    bad_wires.extend(bad);
+
+    let mut sorted: Vec<String> = bad_wires.into_iter().collect();
+    sorted.sort();
+    sorted.join(",")
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = (Wires, Vec<Gate>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (wires, gates) = input;
+        part1(wires, gates).map_or(Answer::None, Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let (wires, gates) = input;
+        part2(wires, gates).into()
+    }
+}
+
+impl Generate for Day24 {
+    /// a ripple carry adder of `size` bits (from 2 to 63), with the outputs
+    /// of up to four pairs of gates swapped where it can't make a loop
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let bits = size.clamp(2, 63);
+        let mut taken: HashSet<String> = HashSet::new();
+        let mut wire = |rng: &mut Rng| loop {
+            // x, y and z are for the adder's own inputs and outputs
+            let name: String = (0..3)
+                .map(|_| char::from(b'a' + rng.below(23) as u8))
+                .collect();
+            if taken.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        // per bit: the sum and carry of x and y, then the sum and carry
+        // with the carry from below
+        let mut gates: Vec<(String, &str, String, String)> = Vec::new();
+        let mut carry = String::new();
+        for bit in 0..bits {
+            let (x, y, z) = (
+                format!("x{:02}", bit),
+                format!("y{:02}", bit),
+                format!("z{:02}", bit),
+            );
+            if bit == 0 {
+                carry = wire(rng);
+                gates.push((x.clone(), "XOR", y.clone(), z));
+                gates.push((x, "AND", y, carry.clone()));
+                continue;
+            }
+            let (sum, both) = (wire(rng), wire(rng));
+            let (with_carry, next_carry) = match bit + 1 == bits {
+                true => (wire(rng), format!("z{:02}", bits)),
+                false => (wire(rng), wire(rng)),
+            };
+            gates.push((x.clone(), "XOR", y.clone(), sum.clone()));
+            gates.push((x, "AND", y, both.clone()));
+            gates.push((sum.clone(), "XOR", carry.clone(), z));
+            gates.push((sum, "AND", carry, with_carry.clone()));
+            gates.push((both, "OR", with_carry, next_carry.clone()));
+            carry = next_carry;
+        }
+
+        // swapping outputs of gates that don't feed each other, like the
+        // puzzle's, in different bits away from both ends
+        let mut middle: Vec<usize> = (1..bits.saturating_sub(1)).collect();
+        rng.shuffle(&mut middle);
+        for &bit in middle.iter().take(4) {
+            let first = 2 + 5 * (bit - 1);
+            let (a, b) = *rng.pick(&[(0, 1), (1, 2), (2, 3), (2, 4)]);
+            let output = gates[first + a].3.clone();
+            gates[first + a].3 = std::mem::replace(&mut gates[first + b].3, output);
+        }
+
+        let mut input = String::new();
+        for prefix in ["x", "y"] {
+            for bit in 0..bits {
+                input.push_str(&format!("{}{:02}: {}\n", prefix, bit, rng.below(2)));
+            }
+        }
+        input.push('\n');
+        rng.shuffle(&mut gates);
+        for (a, op, b, output) in gates {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            input.push_str(&format!("{} {} {} -> {}\n", a, op, b, output));
+        }
+        input
+    }
+
+    /// the starting values sorted by wire, then the gates
+    fn render((wires, gates): &Self::Input) -> String {
+        let mut initial: Vec<(&String, bool)> = wires
+            .iter()
+            .filter_map(|(wire, value)| value.map(|value| (wire, value)))
+            .collect();
+        initial.sort();
+        let mut text: String = initial
+            .iter()
+            .map(|(wire, value)| format!("{}: {}\n", wire, *value as u8))
+            .collect();
+        text.push('\n');
+        for gate in gates {
+            text.push_str(&format!("{}\n", gate));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example_2.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day24>(EXAMPLE, 1, 4);
+        assert_example::<Day24>(EXAMPLE_2, 1, 2024);
+    }
+
+    #[test]
+    fn test_gates_can_be_worked_out() {
+        let error = |input: &str| parse_input(input).err().map(|e| (e.line, e.column));
+        assert_eq!(error("x00: 1\n\nx00 AND q -> z00\n"), Some((3, 9)));
+        assert_eq!(
+            error("x00: 1\n\nx00 AND b -> a\nx00 OR a -> b\na XOR b -> z00\n"),
+            Some((3, 1))
+        );
+        assert_eq!(
+            error("x00: 1\ny00: 0\n\nx00 AND y00 -> z01\n"),
+            Some((4, 16))
+        );
+        let mut too_wide: String = (0..65).map(|bit| format!("z{:02}: 0\n", bit)).collect();
+        too_wide.push_str("\nz00 OR z01 -> a\n");
+        assert_eq!(error(&too_wide), Some((65, 1)));
+
+        // no checks trip over wires nothing reads
+        let (wires, gates) = parse_input("x00: 1\ny00: 1\n\nx00 AND y00 -> z00\n").unwrap();
+        assert_eq!(part1(&wires, &gates), Some(1));
+        part2(&wires, &gates);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+use aoc_grid::Grid;
+
+pub type Key = Vec<u8>;
+pub type Lock = Vec<u8>;
+
+fn keylock_from_grid(grid: &Grid<bool>) -> Vec<u8> {
+    grid.columns()
+        .map(|column| column.filter(|&&pin| pin).count() as u8)
+        .collect()
+}
+
+pub fn key_fits_lock(key: &Key, lock: &Lock) -> bool {
+    for (k, l) in key.iter().zip(lock.iter()) {
+        if k + l > 7 {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Key>, Vec<Lock>), ParseError> {
+    let mut keys: Vec<Key> = Vec::new();
+    let mut locks: Vec<Lock> = Vec::new();
+
+    for lines in aoc_core::sections(input) {
+        let grid = Grid::from_lines(&lines, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        match grid.row(0).iter().all(|&pin| pin) {
+            false => keys.push(keylock_from_grid(&grid)),
+            true => locks.push(keylock_from_grid(&grid)),
+        }
+    }
+    Ok((keys, locks))
+}
+
+pub fn part1(keys: &[Key], locks: &[Lock]) -> u32 {
+    let mut sum = 0;
+    for key in keys {
+        for lock in locks {
+            if key_fits_lock(key, lock) {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Vec<Key>, Vec<Lock>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let (keys, locks) = input;
+        part1(keys, locks).into()
+    }
+}
+
+impl Generate for Day25 {
+    /// `size` schematics, about half locks and half keys
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut schematics = Vec::new();
+        for _ in 0..size {
+            let heights = [(); 5].map(|_| rng.range(0, 5) as usize);
+            let lock = rng.chance(0.5);
+            let mut schematic = Grid::new(5, 7, '.');
+            for pos in schematic.positions() {
+                let pin = match lock {
+                    true => pos.y <= heights[pos.x],
+                    false => pos.y >= 6 - heights[pos.x],
+                };
+                if pin {
+                    schematic[pos] = '#';
+                }
+            }
+            schematics.push(schematic.render(|_, &c| c));
+        }
+        schematics.join("\n")
+    }
+
+    /// each schematic drawn from its pin heights, at least 7 rows tall and
+    /// a key always a row taller than its longest pin
+    fn render((keys, locks): &Self::Input) -> String {
+        let draw = |heights: &Vec<u8>, lock: bool| {
+            let tallest = heights.iter().copied().max().unwrap_or(0) as usize;
+            let rows = 7.max(tallest + !lock as usize);
+            let mut schematic = Grid::new(heights.len(), rows, '.');
+            for pos in schematic.positions() {
+                let height = heights[pos.x] as usize;
+                let pin = match lock {
+                    true => pos.y < height,
+                    false => pos.y >= rows - height,
+                };
+                if pin {
+                    schematic[pos] = '#';
+                }
+            }
+            schematic.render(|_, &c| c)
+        };
+        let keys = keys.iter().map(|key| draw(key, false));
+        let locks = locks.iter().map(|lock| draw(lock, true));
+        keys.chain(locks).collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        assert_example::<Day25>(EXAMPLE, 1, 3);
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// (year, day, part)
+pub type Key = (u16, u8, u8);
+
+/// Known correct answers, stored as one `<year> <day> <part> <answer>` line
+/// each. Blank lines and lines starting with `#` are ignored.
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<Key, String>,
+}
+
+fn parse_line(line: &str) -> Option<(Key, String)> {
+    let mut fields = line.splitn(4, char::is_whitespace);
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let answer = fields.next()?.trim();
+    if answer.is_empty() {
+        return None;
+    }
+    Some(((year, day, part), answer.to_string()))
+}
+
+impl Answers {
+    /// A missing file is an empty registry, so it can be built up with
+    /// `aoc verify --record`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, answer) = parse_line(line).ok_or(format!(
+                "{}:{}: expected '<year> <day> <part> <answer>', got '{}'",
+                path.display(),
+                i + 1,
+                line
+            ))?;
+            answers.insert(key, answer);
+        }
+        Ok(Answers {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    pub fn get(&self, key: Key) -> Option<&str> {
+        self.answers.get(&key).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.answers.insert(key, answer);
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut content = String::from("# year day part answer\n");
+        for ((year, day, part), answer) in &self.answers {
+            content.push_str(&format!("{} {:02} {} {}\n", year, day, part, answer));
+        }
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+        write(&self.path, content)
+            .map_err(|e| format!("Could not write {}: {}", self.path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("2024 23 2 co,de,ka,ta"),
+            Some(((2024, 23, 2), "co,de,ka,ta".to_string()))
+        );
+        assert_eq!(
+            parse_line("2023 05 1 35"),
+            Some(((2023, 5, 1), "35".to_string()))
+        );
+        assert_eq!(parse_line("2023 05 1"), None);
+        assert_eq!(parse_line("2023 five 1 35"), None);
+    }
+}
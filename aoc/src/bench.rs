@@ -0,0 +1,309 @@
+use crate::input::{self, InputSource};
+use crate::puzzles::{self, Puzzle};
+use crate::run::panic_message;
+use aoc_core::{Answer, ParseError, Solution};
+use std::hint::black_box;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub const USAGE: &str = "Usage: aoc bench [<year> [<day>]] [--runs <n>] [--sort day|parse|part1|part2|total] [--inputs-dir <dir>]";
+
+const DEFAULT_RUNS: usize = 10;
+
+/// Every run of each step, in the order they were taken.
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    /// `None` for days without a second part
+    pub part2: Option<Vec<Duration>>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+pub fn time<S: Solution>(input: &str, runs: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings {
+        parse: Vec::with_capacity(runs),
+        part1: Vec::with_capacity(runs),
+        part2: Some(Vec::with_capacity(runs)),
+    };
+    for _ in 0..runs {
+        let (parsed, elapsed) = timed(|| S::parse(input));
+        let parsed = parsed?;
+        timings.parse.push(elapsed);
+
+        let (_, elapsed) = timed(|| S::part1(&parsed));
+        timings.part1.push(elapsed);
+
+        if let Some(part2) = &mut timings.part2 {
+            let (answer, elapsed) = timed(|| S::part2(&parsed));
+            match answer {
+                Answer::None => timings.part2 = None,
+                _ => part2.push(elapsed),
+            }
+        }
+    }
+    Ok(timings)
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(runs: &[Duration]) -> Self {
+        let mut sorted = runs.to_vec();
+        sorted.sort();
+        match sorted.len() {
+            0 => Stats::default(),
+            n => Stats {
+                min: sorted[0],
+                median: sorted[n / 2],
+                max: sorted[n - 1],
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SortBy {
+    Day,
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+pub struct BenchArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: usize,
+    sort: SortBy,
+    inputs_dir: Option<PathBuf>,
+}
+
+pub fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut runs = DEFAULT_RUNS;
+    let mut sort = SortBy::Day;
+    let mut inputs_dir = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                let value = iter.next().ok_or("--runs needs a value")?;
+                runs = match value.parse() {
+                    Ok(0) | Err(_) => return Err(format!("Invalid number of runs '{}'", value)),
+                    Ok(n) => n,
+                };
+            }
+            "--sort" => {
+                let value = iter.next().ok_or("--sort needs a column")?;
+                sort = match value.as_str() {
+                    "day" => SortBy::Day,
+                    "parse" => SortBy::Parse,
+                    "part1" => SortBy::Part1,
+                    "part2" => SortBy::Part2,
+                    "total" => SortBy::Total,
+                    _ => return Err(format!("Invalid sort column '{}'", value)),
+                };
+            }
+            "--inputs-dir" => {
+                let value = iter.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(PathBuf::from(value));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        return Err(USAGE.to_string());
+    }
+    let (year, day) = puzzles::parse_selection(&positional)?;
+    Ok(BenchArgs {
+        year,
+        day,
+        runs,
+        sort,
+        inputs_dir,
+    })
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    parse: Stats,
+    part1: Stats,
+    part2: Option<Stats>,
+}
+
+impl Row {
+    /// sum of the medians, what a single `aoc run` would typically take
+    fn total(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.map_or(Duration::ZERO, |s| s.median)
+    }
+
+    fn key(&self, sort: SortBy) -> Duration {
+        match sort {
+            SortBy::Day => Duration::ZERO,
+            SortBy::Parse => self.parse.median,
+            SortBy::Part1 => self.part1.median,
+            SortBy::Part2 => self.part2.map_or(Duration::ZERO, |s| s.median),
+            SortBy::Total => self.total(),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{}ns", nanos),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+fn format_stats(stats: Option<Stats>) -> String {
+    match stats {
+        Some(s) => format!(
+            "{}/{}/{}",
+            format_duration(s.min),
+            format_duration(s.median),
+            format_duration(s.max)
+        ),
+        None => "-".to_string(),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|r| {
+            [
+                format!("{} {:>2}", r.year, r.day),
+                format_stats(Some(r.parse)),
+                format_stats(Some(r.part1)),
+                format_stats(r.part2),
+                format_duration(r.total()),
+            ]
+        })
+        .collect();
+    let header = ["day", "parse", "part 1", "part 2", "total"];
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|c| c[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let line = |cells: [&str; 5]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, &w)| format!("{:>w$}", c, w = w))
+            .collect();
+        println!("{}", padded.join("  "));
+    };
+    println!("min/median/max");
+    line(header);
+    for c in &cells {
+        line([&c[0], &c[1], &c[2], &c[3], &c[4]]);
+    }
+}
+
+fn bench_puzzle(puzzle: &Puzzle, source: &InputSource, runs: usize) -> Result<Row, String> {
+    let input = input::read_input(source, puzzle.year, puzzle.day)?;
+    // a panicking day is skipped like one that can't parse its input
+    let timings = panic::catch_unwind(AssertUnwindSafe(|| (puzzle.bench)(&input, runs)))
+        .map_err(|payload| {
+            format!(
+                "panicked: {}",
+                panic_message(payload.as_ref()).unwrap_or("unknown cause")
+            )
+        })?
+        .map_err(|e| format!("{} day {}: {}", puzzle.year, puzzle.day, e))?;
+    Ok(Row {
+        year: puzzle.year,
+        day: puzzle.day,
+        parse: Stats::new(&timings.parse),
+        part1: Stats::new(&timings.part1),
+        part2: timings.part2.as_deref().map(Stats::new),
+    })
+}
+
+pub fn bench(args: BenchArgs) -> Result<(), String> {
+    let selected = puzzles::select(args.year, args.day);
+    if selected.is_empty() {
+        return Err("No solutions match the given year and day".to_string());
+    }
+    let source = InputSource::Lookup {
+        inputs_dir: args.inputs_dir,
+    };
+
+    let mut rows = Vec::new();
+    for puzzle in selected {
+        match bench_puzzle(puzzle, &source, args.runs) {
+            Ok(row) => rows.push(row),
+            Err(e) => eprintln!("Skipping {} day {}: {}", puzzle.year, puzzle.day, e),
+        }
+    }
+    // slowest first, except when sorting by day
+    rows.sort_by_key(|r| std::cmp::Reverse(r.key(args.sort)));
+
+    print_table(&rows);
+    let total: Duration = rows.iter().map(|r| r.total()).sum();
+    println!("\n{} days in {}", rows.len(), format_duration(total));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let runs = [5, 1, 4, 2, 3].map(Duration::from_millis);
+        let stats = Stats::new(&runs);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_panicking_day_is_skipped() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.txt", std::process::id()));
+        std::fs::write(&path, "3   4\n4   3\n").unwrap();
+        let source = InputSource::File(path.clone());
+        let day = puzzles::select(Some(2024), Some(1))[0];
+
+        let row = bench_puzzle(day, &source, 2).unwrap();
+        assert_eq!((row.year, row.day), (2024, 1));
+        let panicking = Puzzle {
+            bench: |_, _| panic!("no timings"),
+            ..*day
+        };
+        let error = bench_puzzle(&panicking, &source, 2).err().unwrap();
+        assert_eq!(error, "panicked: no timings");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(250)), "250ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2.00s");
+    }
+}
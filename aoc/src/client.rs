@@ -0,0 +1,187 @@
+use std::env;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Overrides the site's address, same as `--base-url`.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// The session cookie of a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Read for the session when `AOC_SESSION` isn't set, from the inputs directory.
+pub const SESSION_FILE: &str = ".session";
+/// When the last request was made, kept in the inputs directory.
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+/// Minimum time between two requests to the site, across runs.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/kikefdezl/advent-of-code aoc runner";
+
+/// `--base-url`, then `AOC_BASE_URL`, then the real site.
+pub fn base_url(flag: Option<&str>) -> String {
+    let url = match flag {
+        Some(url) => url.to_string(),
+        None => env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+    };
+    url.trim_end_matches('/').to_string()
+}
+
+/// `AOC_SESSION`, then the `.session` file in the inputs directory.
+pub fn session(inputs_dir: &Path) -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_VAR)
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().to_string());
+    }
+    let path = inputs_dir.join(SESSION_FILE);
+    match read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!(
+            "No session token, set {} or put it in {}",
+            SESSION_VAR,
+            path.display()
+        )),
+    }
+}
+
+/// Spaces requests out by at least an interval, remembering the last one in
+/// a file so separate runs are throttled too.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis())
+}
+
+/// how long to wait before the next request, `last` and `now` in milliseconds
+fn remaining(last: Option<u128>, now: u128, interval: Duration) -> Duration {
+    let Some(last) = last else {
+        return Duration::ZERO;
+    };
+    let next = last + interval.as_millis();
+    Duration::from_millis(next.saturating_sub(now) as u64)
+}
+
+impl Throttle {
+    pub fn new(state_dir: &Path, interval: Duration) -> Self {
+        Throttle {
+            path: state_dir.join(LAST_REQUEST_FILE),
+            interval,
+        }
+    }
+
+    /// Blocks until a request is allowed, then records it as made.
+    pub fn wait(&self) -> Result<(), String> {
+        let last = read_to_string(&self.path)
+            .ok()
+            .and_then(|s| s.trim().parse().ok());
+        let wait = remaining(last, now_millis(), self.interval);
+        if !wait.is_zero() {
+            eprintln!("Waiting {:.1}s before the next request", wait.as_secs_f64());
+            sleep(wait);
+        }
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+        write(&self.path, now_millis().to_string())
+            .map_err(|e| format!("Could not write {}: {}", self.path.display(), e))
+    }
+}
+
+/// A logged-in client for the Advent of Code site, or anything that
+/// answers like it.
+pub struct Client {
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+    agent: ureq::Agent,
+}
+
+/// A response the site answered with, whatever its status.
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Client {
+    pub fn new(base_url: String, session: String, throttle: Throttle) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            base_url,
+            session,
+            throttle,
+            agent,
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Sends `request` with the session cookie, posting `form` if there is one.
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<Response, String> {
+        self.throttle.wait()?;
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let result = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(format!("Request to {} failed: {}", url, e)),
+        };
+        let status = response.status();
+        let body = response
+            .into_string()
+            .map_err(|e| format!("Could not read the response from {}: {}", url, e))?;
+        Ok(Response { status, body })
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, String> {
+        self.send(self.agent.get(&self.url(path)), None)
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.send(self.agent.post(&self.url(path)), Some(form))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining() {
+        let interval = Duration::from_secs(5);
+        assert_eq!(remaining(None, 10_000, interval), Duration::ZERO);
+        assert_eq!(
+            remaining(Some(8_000), 10_000, interval),
+            Duration::from_secs(3)
+        );
+        assert_eq!(remaining(Some(1_000), 10_000, interval), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_base_url_trims_slash() {
+        assert_eq!(
+            base_url(Some("http://127.0.0.1:8080/")),
+            "http://127.0.0.1:8080"
+        );
+    }
+}
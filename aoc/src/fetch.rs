@@ -0,0 +1,208 @@
+use crate::client::{self, Client, Throttle};
+use crate::input;
+use crate::puzzles;
+use std::fs::{create_dir_all, write};
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str =
+    "Usage: aoc fetch <year> <day> [--force] [--inputs-dir <dir>] [--base-url <url>]";
+
+pub struct FetchArgs {
+    year: u16,
+    day: u8,
+    inputs_dir: Option<PathBuf>,
+    base_url: Option<String>,
+    /// download again even if the input is already cached
+    force: bool,
+}
+
+pub fn parse_fetch_args(args: &[String]) -> Result<FetchArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut inputs_dir = None;
+    let mut base_url = None;
+    let mut force = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--inputs-dir" => {
+                let value = iter.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(PathBuf::from(value));
+            }
+            "--base-url" => {
+                let value = iter.next().ok_or("--base-url needs a URL")?;
+                base_url = Some(value.clone());
+            }
+            "--force" => force = true,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 2 {
+        return Err(USAGE.to_string());
+    }
+    let (Some(year), Some(day)) = puzzles::parse_selection(&positional)? else {
+        return Err(USAGE.to_string());
+    };
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{}', expected 1 to 25", day));
+    }
+    Ok(FetchArgs {
+        year,
+        day,
+        inputs_dir,
+        base_url,
+        force,
+    })
+}
+
+/// Downloads a day's input into the shared inputs directory, unless it's
+/// already there. Returns where the input is. The client is only made for
+/// a download, as a cached input doesn't need a session.
+fn fetch_input(
+    connect: impl FnOnce() -> Result<Client, String>,
+    inputs_dir: &Path,
+    year: u16,
+    day: u8,
+    force: bool,
+) -> Result<PathBuf, String> {
+    let path = input::shared_path(inputs_dir, year, day);
+    if path.is_file() && !force {
+        eprintln!("Using cached input {}", path.display());
+        return Ok(path);
+    }
+
+    let client = connect()?;
+    let response = client.get(&format!("/{}/day/{}/input", year, day))?;
+    match response.status {
+        200 => {}
+        404 => {
+            return Err(format!(
+                "{} day {} has no input yet, or doesn't exist",
+                year, day
+            ));
+        }
+        400 | 500 => {
+            return Err(format!(
+                "The session token was rejected ({}), it may have expired",
+                response.status
+            ));
+        }
+        status => {
+            return Err(format!(
+                "Unexpected response {} from {}: {}",
+                status,
+                client.url(&format!("/{}/day/{}/input", year, day)),
+                response.body.trim()
+            ));
+        }
+    }
+
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+    write(&path, response.body)
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    eprintln!("Saved {} day {} to {}", year, day, path.display());
+    Ok(path)
+}
+
+pub fn fetch(args: FetchArgs) -> Result<(), String> {
+    let inputs_dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let connect = || {
+        Ok(Client::new(
+            client::base_url(args.base_url.as_deref()),
+            client::session(&inputs_dir)?,
+            Throttle::new(&inputs_dir, client::MIN_INTERVAL),
+        ))
+    };
+    fetch_input(connect, &inputs_dir, args.year, args.day, args.force)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all};
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::{Response, Server};
+
+    /// A stand-in for the site that answers `/<year>/day/<day>/input` for
+    /// 2023 day 5 only, and reports every request it gets as
+    /// `(url, cookie)`.
+    fn mock_server() -> (String, Receiver<(String, String)>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string())
+                    .unwrap_or_default();
+                let path = request.url().to_string();
+                let response = match path.as_str() {
+                    "/2023/day/5/input" => Response::from_string("seeds: 79 14 55 13\n"),
+                    _ => Response::from_string("Not found").with_status_code(404),
+                };
+                sender.send((path, cookie)).unwrap();
+                request.respond(response).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    fn temp_inputs_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        dir
+    }
+
+    fn test_client(url: &str, inputs_dir: &Path) -> impl FnOnce() -> Result<Client, String> {
+        let (url, inputs_dir) = (url.to_string(), inputs_dir.to_path_buf());
+        move || {
+            Ok(Client::new(
+                url,
+                "secret".to_string(),
+                Throttle::new(&inputs_dir, Duration::ZERO),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_uses_cache() {
+        let (url, requests) = mock_server();
+        let dir = temp_inputs_dir("cache");
+        let client = || test_client(&url, &dir);
+
+        let path = fetch_input(client(), &dir, 2023, 5, false).unwrap();
+        assert_eq!(path, input::shared_path(&dir, 2023, 5));
+        assert_eq!(read_to_string(&path).unwrap(), "seeds: 79 14 55 13\n");
+        let (url, cookie) = requests.recv().unwrap();
+        assert_eq!(url, "/2023/day/5/input");
+        assert_eq!(cookie, "session=secret");
+
+        // no session needed for a cached input
+        let no_session = || Err("no session".to_string());
+        fetch_input(no_session, &dir, 2023, 5, false).unwrap();
+        assert!(requests.try_recv().is_err());
+
+        fetch_input(client(), &dir, 2023, 5, true).unwrap();
+        assert!(requests.recv().is_ok());
+        remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_missing_day() {
+        let (url, _requests) = mock_server();
+        let dir = temp_inputs_dir("missing");
+        let error = fetch_input(test_client(&url, &dir), &dir, 2023, 6, false).unwrap_err();
+        assert_eq!(error, "2023 day 6 has no input yet, or doesn't exist");
+        assert!(!input::shared_path(&dir, 2023, 6).exists());
+        let _ = remove_dir_all(&dir);
+    }
+}
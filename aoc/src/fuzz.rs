@@ -0,0 +1,281 @@
+use crate::generate::parse_number;
+use crate::input;
+use crate::puzzles::{self, Puzzle};
+use crate::run::panic_message;
+use aoc_core::{ParseError, Rng, fuzz};
+use rayon::prelude::*;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: aoc fuzz [<year> [<day>]] [--runs <n>] [--seed <n>] [--save <dir>]";
+
+const DEFAULT_RUNS: u64 = 2000;
+
+pub struct FuzzArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: u64,
+    /// the first seed, each run uses the next one
+    seed: u64,
+    /// where to write the inputs that broke a parser
+    save: Option<PathBuf>,
+}
+
+pub fn parse_fuzz_args(args: &[String]) -> Result<FuzzArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut runs = DEFAULT_RUNS;
+    let mut seed = 0;
+    let mut save = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" | "-n" => runs = parse_number("--runs", iter.next())?,
+            "--seed" => seed = parse_number("--seed", iter.next())?,
+            "--save" => {
+                let value = iter.next().ok_or("--save needs a directory")?;
+                save = Some(PathBuf::from(value));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        return Err(USAGE.to_string());
+    }
+    let (year, day) = puzzles::parse_selection(&positional)?;
+    Ok(FuzzArgs {
+        year,
+        day,
+        runs,
+        seed,
+        save,
+    })
+}
+
+/// The inputs the mutations start from: the day's examples, and a few
+/// generated ones.
+fn corpus(puzzle: &Puzzle) -> Vec<String> {
+    let dir = input::repo_root()
+        .join(puzzle.year.to_string())
+        .join(format!("day_{:02}", puzzle.day));
+    let mut examples: Vec<PathBuf> = read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.starts_with("example") && name.ends_with(".txt")
+        })
+        .collect();
+    examples.sort();
+    let mut inputs: Vec<String> = examples
+        .iter()
+        .filter_map(|path| read_to_string(path).ok())
+        .collect();
+    for size in [1, 3, 8] {
+        inputs.push((puzzle.generate)(size, size as u64));
+    }
+    inputs
+}
+
+/// The input for one run, always the same for the same seed.
+fn fuzz_input(corpus: &[String], seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    match rng.chance(0.1) {
+        true => fuzz::arbitrary(200, &mut rng),
+        false => {
+            let original = rng.pick(corpus).clone();
+            fuzz::mutate(&original, &mut rng)
+        }
+    }
+}
+
+/// An error has to point at a character of the input, the end of one of
+/// its lines, or the line right after it.
+fn check_error(input: &str, error: &ParseError) -> Result<(), String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let fits = match error.line.checked_sub(1).map(|i| lines.get(i)) {
+        Some(Some(line)) => (1..=line.chars().count() + 1).contains(&error.column),
+        Some(None) => error.line == lines.len() + 1 && error.column == 1,
+        None => false,
+    };
+    match fits {
+        true => Ok(()),
+        false => Err(format!("error points outside the input: {}", error)),
+    }
+}
+
+fn render(puzzle: &Puzzle, input: &str) -> Result<Result<String, ParseError>, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| (puzzle.render)(input))).map_err(|payload| {
+        format!(
+            "panicked: {}",
+            panic_message(payload.as_ref()).unwrap_or("unknown cause")
+        )
+    })
+}
+
+/// Parses an input the parser may well reject, as long as it doesn't panic
+/// doing it. What it accepts has to read back the same once written out
+/// again.
+fn check(puzzle: &Puzzle, input: &str) -> Result<(), String> {
+    let rendered = match render(puzzle, input)? {
+        Ok(rendered) => rendered,
+        Err(e) => return check_error(input, &e),
+    };
+    match render(puzzle, &rendered)? {
+        Ok(again) if again == rendered => Ok(()),
+        Ok(again) => Err(format!(
+            "reads back differently once written out: {:?} became {:?}",
+            rendered, again
+        )),
+        Err(e) => Err(format!("rejects itself written out {:?}: {}", rendered, e)),
+    }
+}
+
+struct Failure {
+    seed: u64,
+    input: String,
+    error: String,
+}
+
+fn fuzz_puzzle(puzzle: &Puzzle, runs: u64, seed: u64) -> Vec<Failure> {
+    let corpus = corpus(puzzle);
+    (seed..seed + runs)
+        .into_par_iter()
+        .filter_map(|seed| {
+            let input = fuzz_input(&corpus, seed);
+            check(puzzle, &input)
+                .err()
+                .map(|error| Failure { seed, input, error })
+        })
+        .collect()
+}
+
+/// Feeds every day's parser its examples and generated inputs after
+/// random edits, and random text, to find what makes one panic or not
+/// read back what it parsed.
+pub fn fuzz(args: FuzzArgs) -> Result<(), String> {
+    let selected = puzzles::select(args.year, args.day);
+    if selected.is_empty() {
+        return Err("No solutions match".to_string());
+    }
+
+    // the panics are the point here, and they're reported below
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results: Vec<(&Puzzle, Vec<Failure>)> = selected
+        .par_iter()
+        .map(|&puzzle| (puzzle, fuzz_puzzle(puzzle, args.runs, args.seed)))
+        .collect();
+    panic::set_hook(hook);
+
+    let mut failed = 0;
+    for (puzzle, failures) in &results {
+        let status = match failures.len() {
+            0 => "ok".to_string(),
+            n => format!("{} FAIL", n),
+        };
+        println!("{:>4}  {:>3}  {}", puzzle.year, puzzle.day, status);
+        failed += failures.len();
+    }
+    if failed == 0 {
+        return Ok(());
+    }
+
+    println!();
+    for (puzzle, failures) in &results {
+        // the same bug tends to show up over and over
+        let mut shown: Vec<&str> = Vec::new();
+        for failure in failures {
+            if let Some(save) = &args.save {
+                create_dir_all(save)
+                    .map_err(|e| format!("Could not create {}: {}", save.display(), e))?;
+                let path = save.join(format!(
+                    "{}_day_{:02}_seed_{}.txt",
+                    puzzle.year, puzzle.day, failure.seed
+                ));
+                write(&path, &failure.input)
+                    .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+            }
+            if !shown.contains(&failure.error.as_str()) {
+                shown.push(&failure.error);
+                println!(
+                    "{} day {}, seed {}: {}",
+                    puzzle.year, puzzle.day, failure.seed, failure.error
+                );
+            }
+        }
+    }
+    Err(format!(
+        "{} of {} inputs broke a parser",
+        failed,
+        results.len() as u64 * args.runs
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2024_day_01::Day01;
+    use aoc_core::Solution;
+
+    #[test]
+    fn test_check_error() {
+        let input = "12 x\n\n";
+        let error = |line, column| ParseError {
+            line,
+            column,
+            expected: "a number".to_string(),
+            found: "'x'".to_string(),
+        };
+        assert!(check_error(input, &error(1, 4)).is_ok());
+        assert!(check_error(input, &error(1, 5)).is_ok());
+        assert!(check_error(input, &error(2, 1)).is_ok());
+        assert!(check_error(input, &error(3, 1)).is_ok());
+        assert!(check_error(input, &error(1, 6)).is_err());
+        assert!(check_error(input, &error(0, 1)).is_err());
+        assert!(check_error(input, &error(3, 2)).is_err());
+    }
+
+    #[test]
+    fn test_check_reads_back() {
+        let day = puzzles::select(Some(2024), Some(1))[0];
+        assert!(check(day, "3   4\n4   3\n").is_ok());
+        assert!(check(day, "3   x\n").is_ok());
+
+        // a render that doesn't write out what was parsed
+        let lossy = Puzzle {
+            render: |input| Ok(format!("{}1   1\n", input)),
+            ..*day
+        };
+        let error = check(&lossy, "3   4\n").unwrap_err();
+        assert!(error.starts_with("reads back differently"), "{}", error);
+        let broken = Puzzle {
+            render: |input| Day01::parse(input).map(|_| "x\n".to_string()),
+            ..*day
+        };
+        let error = check(&broken, "3   4\n").unwrap_err();
+        assert!(error.starts_with("rejects itself"), "{}", error);
+    }
+
+    #[test]
+    fn test_fuzz_input_is_reproducible() {
+        let corpus = ["3   4\n4   3\n".to_string()];
+        assert_eq!(fuzz_input(&corpus, 42), fuzz_input(&corpus, 42));
+    }
+
+    #[test]
+    fn test_parsers_never_panic() {
+        for puzzle in puzzles::select(None, None) {
+            if let Some(failure) = fuzz_puzzle(puzzle, 300, 0).first() {
+                panic!(
+                    "{} day {}, seed {}: {}\n{:?}",
+                    puzzle.year, puzzle.day, failure.seed, failure.error, failure.input
+                );
+            }
+        }
+    }
+}
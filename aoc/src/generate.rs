@@ -0,0 +1,84 @@
+use crate::puzzles;
+
+pub const USAGE: &str = "Usage: aoc generate <year> <day> [--size <n>] [--seed <n>]";
+
+pub const DEFAULT_SIZE: usize = 10;
+
+pub struct GenerateArgs {
+    year: u16,
+    day: u8,
+    size: usize,
+    seed: u64,
+}
+
+/// Parses the value of `--size`, `--seed` or `--runs`.
+pub fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("{} needs a value", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid {} '{}'", flag.trim_start_matches('-'), value))
+}
+
+pub fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut size = DEFAULT_SIZE;
+    let mut seed = 0;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--size" => {
+                size = match parse_number("--size", iter.next())? {
+                    0 => return Err("Invalid size '0'".to_string()),
+                    n => n,
+                };
+            }
+            "--seed" => seed = parse_number("--seed", iter.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 2 {
+        return Err(USAGE.to_string());
+    }
+    let (Some(year), Some(day)) = puzzles::parse_selection(&positional)? else {
+        return Err(USAGE.to_string());
+    };
+    Ok(GenerateArgs {
+        year,
+        day,
+        size,
+        seed,
+    })
+}
+
+/// Prints a made up input, ready to be piped into `aoc run --input -`.
+pub fn generate(args: GenerateArgs) -> Result<(), String> {
+    let puzzle = puzzles::find(args.year, args.day)
+        .ok_or(format!("No solution for {} day {}", args.year, args.day))?;
+    print!("{}", (puzzle.generate)(args.size, args.seed));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_generate_args() {
+        let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+        let parsed = parse_generate_args(&args("2024 24 --size 40 --seed 7")).unwrap();
+        assert_eq!(
+            (parsed.year, parsed.day, parsed.size, parsed.seed),
+            (2024, 24, 40, 7)
+        );
+        let defaults = parse_generate_args(&args("2023 5")).unwrap();
+        assert_eq!((defaults.size, defaults.seed), (DEFAULT_SIZE, 0));
+
+        assert!(parse_generate_args(&args("2023")).is_err());
+        assert_eq!(
+            parse_generate_args(&args("2023 5 --seed x")).err(),
+            Some("Invalid seed 'x'".to_string())
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::answers::Key;
+use std::fs::{OpenOptions, create_dir_all, read_to_string};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Verdict> {
+        match label {
+            "right" => Some(Verdict::Right),
+            "wrong" => Some(Verdict::Wrong),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+/// Every answer submitted so far and its verdict, stored as one
+/// `<year> <day> <part> <verdict> <answer>` line each and only ever
+/// appended to.
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<(Key, Verdict, String)>,
+}
+
+fn parse_line(line: &str) -> Option<(Key, Verdict, String)> {
+    let mut fields = line.splitn(5, char::is_whitespace);
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let verdict = Verdict::from_label(fields.next()?)?;
+    let answer = fields.next()?.trim();
+    if answer.is_empty() {
+        return None;
+    }
+    Some(((year, day, part), verdict, answer.to_string()))
+}
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+        };
+
+        let mut submissions = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let submission = parse_line(line).ok_or(format!(
+                "{}:{}: expected '<year> <day> <part> <verdict> <answer>', got '{}'",
+                path.display(),
+                i + 1,
+                line
+            ))?;
+            submissions.push(submission);
+        }
+        Ok(History {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    /// Why `answer` shouldn't be submitted for `key`, if the history already
+    /// tells how it would go.
+    pub fn known_outcome(&self, key: Key, answer: &str) -> Option<String> {
+        let submitted = self.submissions.iter().filter(|(k, _, _)| *k == key);
+        // (answer, previous answer), when both are numbers
+        let numbers = |previous: &str| -> Option<(i128, i128)> {
+            Some((answer.parse().ok()?, previous.parse().ok()?))
+        };
+        for (_, verdict, previous) in submitted {
+            let reason = match verdict {
+                Verdict::Right if previous == answer => "it was right".to_string(),
+                Verdict::Right => format!("it's already solved, with {}", previous),
+                _ if previous == answer => format!("it was {}", verdict.label().replace('-', " ")),
+                Verdict::TooHigh if numbers(previous).is_some_and(|(n, p)| n >= p) => {
+                    format!("{} was already too high", previous)
+                }
+                Verdict::TooLow if numbers(previous).is_some_and(|(n, p)| n <= p) => {
+                    format!("{} was already too low", previous)
+                }
+                _ => continue,
+            };
+            return Some(reason);
+        }
+        None
+    }
+
+    pub fn record(&mut self, key: Key, verdict: Verdict, answer: &str) -> Result<(), String> {
+        let (year, day, part) = key;
+        let line = format!(
+            "{} {:02} {} {} {}\n",
+            year,
+            day,
+            part,
+            verdict.label(),
+            answer
+        );
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| format!("Could not write {}: {}", self.path.display(), e))?;
+        self.submissions.push((key, verdict, answer.to_string()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("2024 23 2 wrong co,de,ka"),
+            Some(((2024, 23, 2), Verdict::Wrong, "co,de,ka".to_string()))
+        );
+        assert_eq!(
+            parse_line("2023 05 1 too-high 35"),
+            Some(((2023, 5, 1), Verdict::TooHigh, "35".to_string()))
+        );
+        assert_eq!(parse_line("2023 05 1 maybe 35"), None);
+    }
+
+    #[test]
+    fn test_known_outcome() {
+        let history = History {
+            path: PathBuf::new(),
+            submissions: vec![
+                ((2023, 5, 1), Verdict::TooHigh, "500".to_string()),
+                ((2023, 5, 1), Verdict::TooLow, "100".to_string()),
+                ((2023, 5, 1), Verdict::Wrong, "250".to_string()),
+                ((2023, 5, 2), Verdict::Right, "46".to_string()),
+            ],
+        };
+        let outcome = |part, answer| history.known_outcome((2023, 5, part), answer);
+        assert_eq!(outcome(1, "600").unwrap(), "500 was already too high");
+        assert_eq!(outcome(1, "100").unwrap(), "it was too low");
+        assert_eq!(outcome(1, "250").unwrap(), "it was wrong");
+        assert_eq!(outcome(1, "300"), None);
+        assert_eq!(outcome(1, "abc"), None);
+        assert_eq!(outcome(2, "46").unwrap(), "it was right");
+        assert_eq!(outcome(2, "47").unwrap(), "it's already solved, with 46");
+    }
+}
@@ -0,0 +1,115 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Overrides the shared inputs directory, same as `--inputs-dir`.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Where to read a day's puzzle input from.
+pub enum InputSource {
+    /// an explicit file given with `--input`
+    File(PathBuf),
+    /// `--input -`
+    Stdin,
+    /// look the day up in the shared inputs directory, then next to the
+    /// day's crate
+    Lookup { inputs_dir: Option<PathBuf> },
+}
+
+impl InputSource {
+    pub fn from_arg(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+pub fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// `--inputs-dir`, then `AOC_INPUTS_DIR`, then `inputs/` at the repo root.
+pub fn inputs_dir(flag: Option<&Path>) -> PathBuf {
+    match flag {
+        Some(dir) => dir.to_path_buf(),
+        None => env::var_os(INPUTS_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| repo_root().join("inputs")),
+    }
+}
+
+/// `<inputs_dir>/<year>/day_<dd>.txt`
+pub fn shared_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("day_{:02}.txt", day))
+}
+
+/// `<year>/day_<dd>/input.txt`, where the inputs lived before the workspace
+fn crate_path(year: u16, day: u8) -> PathBuf {
+    repo_root()
+        .join(year.to_string())
+        .join(format!("day_{:02}", day))
+        .join("input.txt")
+}
+
+fn candidates(inputs_dir: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    vec![shared_path(inputs_dir, year, day), crate_path(year, day)]
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    read_to_string(path).map_err(|e| format!("Could not read input file {}: {}", path.display(), e))
+}
+
+pub fn read_input(source: &InputSource, year: u16, day: u8) -> Result<String, String> {
+    match source {
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Could not read input from stdin: {}", e))?;
+            Ok(input)
+        }
+        InputSource::Lookup { inputs_dir: dir } => {
+            let paths = candidates(&inputs_dir(dir.as_deref()), year, day);
+            match paths.iter().find(|p| p.is_file()) {
+                Some(path) => read_file(path),
+                None => Err(missing_input(year, day, &paths)),
+            }
+        }
+    }
+}
+
+fn missing_input(year: u16, day: u8, tried: &[PathBuf]) -> String {
+    let mut message = format!("No input found for {} day {}, looked in:", year, day);
+    for path in tried {
+        message.push_str(&format!("\n  {}", path.display()));
+    }
+    message.push_str("\nPass a file with --input <path>, or --input - to read stdin");
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_path() {
+        let path = shared_path(Path::new("inputs"), 2023, 5);
+        assert_eq!(path, Path::new("inputs/2023/day_05.txt"));
+    }
+
+    #[test]
+    fn test_missing_input_lists_tried_paths() {
+        let dir = env::temp_dir().join("aoc-missing-inputs");
+        let source = InputSource::Lookup {
+            inputs_dir: Some(dir.clone()),
+        };
+        let error = read_input(&source, 1999, 1).unwrap_err();
+        assert!(error.starts_with("No input found for 1999 day 1"));
+        assert!(error.contains(&shared_path(&dir, 1999, 1).display().to_string()));
+    }
+}
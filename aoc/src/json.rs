@@ -0,0 +1,81 @@
+use std::fmt;
+
+/// `s` as a quoted JSON string.
+pub fn string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// A flat JSON object, written on a single line with its fields in the
+/// order they were added.
+#[derive(Default)]
+pub struct Object {
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    pub fn number(mut self, key: &'static str, value: impl fmt::Display) -> Self {
+        self.fields.push((key, value.to_string()));
+        self
+    }
+
+    /// `null` when there's no value
+    pub fn string(mut self, key: &'static str, value: Option<&str>) -> Self {
+        self.fields
+            .push((key, value.map_or("null".to_string(), string)));
+        self
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", string(key), value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(string("5,7,3,0"), "\"5,7,3,0\"");
+        assert_eq!(string("a \"b\"\\\n\u{1}"), "\"a \\\"b\\\"\\\\\\n\\u0001\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let object = Object::new()
+            .number("day", 5)
+            .string("answer", Some("35"))
+            .string("error", None);
+        assert_eq!(
+            object.to_string(),
+            "{\"day\":5,\"answer\":\"35\",\"error\":null}"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+mod answers;
+mod bench;
+mod client;
+mod fetch;
+mod fuzz;
+mod generate;
+mod history;
+mod input;
+mod json;
+mod new;
+mod puzzles;
+mod run;
+mod stress;
+mod submit;
+mod verify;
+
+use aoc_core::log;
+use std::env;
+use std::process::exit;
+
+const VERBOSITY_USAGE: &str =
+    "Add -v or -vv to any command to show the solutions' traces on stderr";
+
+fn main() {
+    let mut verbosity = 0;
+    let args: Vec<String> = env::args()
+        .skip(1)
+        .filter(|arg| match arg.as_str() {
+            "-v" | "--verbose" => {
+                verbosity += 1;
+                false
+            }
+            "-vv" => {
+                verbosity += 2;
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    log::set_level(log::level_for(verbosity));
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run::parse_run_args(&args[1..]).and_then(run::run),
+        Some("bench") => bench::parse_bench_args(&args[1..]).and_then(bench::bench),
+        Some("verify") => verify::parse_verify_args(&args[1..]).and_then(verify::verify),
+        Some("fetch") => fetch::parse_fetch_args(&args[1..]).and_then(fetch::fetch),
+        Some("submit") => submit::parse_submit_args(&args[1..]).and_then(submit::submit),
+        Some("new") => new::parse_new_args(&args[1..]).and_then(new::new),
+        Some("generate") => generate::parse_generate_args(&args[1..]).and_then(generate::generate),
+        Some("stress") => stress::parse_stress_args(&args[1..]).and_then(stress::stress),
+        Some("fuzz") => fuzz::parse_fuzz_args(&args[1..]).and_then(fuzz::fuzz),
+        _ => Err([
+            run::USAGE,
+            verify::USAGE,
+            bench::USAGE,
+            fetch::USAGE,
+            submit::USAGE,
+            new::USAGE,
+            generate::USAGE,
+            stress::USAGE,
+            fuzz::USAGE,
+            VERBOSITY_USAGE,
+        ]
+        .join("\n")),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
@@ -0,0 +1,368 @@
+use crate::input;
+use crate::puzzles;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "Usage: aoc new <year> <day>";
+
+pub struct NewArgs {
+    year: u16,
+    day: u8,
+}
+
+pub fn parse_new_args(args: &[String]) -> Result<NewArgs, String> {
+    if args.len() != 2 {
+        return Err(USAGE.to_string());
+    }
+    let (Some(year), Some(day)) = puzzles::parse_selection(&[args[0].as_str(), args[1].as_str()])?
+    else {
+        return Err(USAGE.to_string());
+    };
+    if year < 2015 {
+        return Err(format!("Invalid year '{}', the first one was 2015", year));
+    }
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day '{}', expected 1 to 25", day));
+    }
+    Ok(NewArgs { year, day })
+}
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "CRATE_NAME"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc_core.workspace = true
+"#;
+
+const LIB_TEMPLATE: &str = r#"use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::new();
+    for line in aoc_core::lines(input) {
+        lines.push(line.rest().to_string());
+    }
+    Ok(lines)
+}
+
+pub struct DAY_STRUCT;
+
+impl Solution for DAY_STRUCT {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::None
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+impl Generate for DAY_STRUCT {
+    /// `size` lines of made up input
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+    }
+
+    fn render(lines: &Self::Input) -> String {
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_example;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    #[ignore = "paste the puzzle's example into example.txt and fill in its answers"]
+    fn test_example() {
+        assert_example::<DAY_STRUCT>(EXAMPLE, 1, 0);
+        assert_example::<DAY_STRUCT>(EXAMPLE, 2, 0);
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"fn main() {
+    aoc_core::run_day::<CRATE_NAME::DAY_STRUCT>();
+}
+"#;
+
+fn crate_name(year: u16, day: u8) -> String {
+    format!("aoc_{}_day_{:02}", year, day)
+}
+
+/// `aoc_<year>_day_<dd> = { path = ... }` in the runner's manifest
+fn dependency_key(line: &str) -> Option<(u16, u8)> {
+    let name = line.strip_prefix("aoc_")?.split_once(' ')?.0;
+    let (year, day) = name.split_once("_day_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `puzzle!(<year>, <day>, ...)` in the runner's list of puzzles
+fn puzzle_key(line: &str) -> Option<(u16, u8)> {
+    let mut args = line.trim().strip_prefix("puzzle!(")?.split(", ");
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// `"<year>/day_*",` in the workspace members, sorted before any of its days
+fn member_key(line: &str) -> Option<(u16, u8)> {
+    let year = line.trim().strip_prefix('"')?.strip_suffix("/day_*\",")?;
+    Some((year.parse().ok()?, 0))
+}
+
+/// Adds `paths` to the workspace's `exclude` list, creating it after the
+/// members if there isn't one.
+fn with_excluded(workspace: &str, paths: &[String]) -> String {
+    let mut lines: Vec<String> = workspace.lines().map(str::to_string).collect();
+    let (position, mut excluded) = match lines.iter().position(|l| l.starts_with("exclude = [")) {
+        Some(i) => {
+            let list = lines.remove(i);
+            let list = list.trim_start_matches("exclude = [").trim_end_matches(']');
+            let excluded: Vec<String> = list
+                .split(',')
+                .map(|path| path.trim().trim_matches('"').to_string())
+                .filter(|path| !path.is_empty())
+                .collect();
+            (i, excluded)
+        }
+        None => {
+            let members_end = lines
+                .iter()
+                .position(|l| l == "]")
+                .map_or(lines.len(), |i| i + 1);
+            (members_end, Vec::new())
+        }
+    };
+    excluded.extend(paths.iter().cloned());
+    excluded.sort();
+    excluded.dedup();
+    let quoted: Vec<String> = excluded
+        .iter()
+        .map(|path| format!("\"{}\"", path))
+        .collect();
+    lines.insert(position, format!("exclude = [{}]", quoted.join(", ")));
+    lines.join("\n") + "\n"
+}
+
+/// The days of a year that are in some other language, and can't be
+/// workspace members.
+fn foreign_days(root: &Path, year: u16) -> Vec<String> {
+    let Ok(entries) = read_dir(root.join(year.to_string())) else {
+        return Vec::new();
+    };
+    let mut days: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir() && !entry.path().join("Cargo.toml").exists())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with("day_"))
+        .map(|name| format!("{}/{}", year, name))
+        .collect();
+    days.sort();
+    days
+}
+
+/// Inserts `line` among the lines `key` recognises, keeping them sorted, and
+/// fails if its key is already there.
+fn insert_sorted(
+    content: &str,
+    line: &str,
+    key: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Result<String, String> {
+    let new_key = key(line).expect("the inserted line has a key");
+    let mut lines: Vec<&str> = content.lines().collect();
+    let mut position = None;
+    for (i, existing) in lines.iter().enumerate() {
+        match key(existing) {
+            Some(k) if k == new_key => return Err(format!("'{}' is already there", line.trim())),
+            Some(k) if k < new_key => position = Some(i + 1),
+            Some(_) if position.is_none() => position = Some(i),
+            _ => {}
+        }
+    }
+    let position = position.ok_or(format!("Found nowhere to put '{}'", line.trim()))?;
+    lines.insert(position, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    write(path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+/// Creates the crate for a day under `root` and registers it with the
+/// workspace and the runner. Returns the crate's directory.
+fn scaffold(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    let dir = root.join(year.to_string()).join(format!("day_{:02}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let name = crate_name(year, day);
+
+    // every registration is worked out before anything is written, so a
+    // failure doesn't leave a half-registered day behind
+    let workspace_path = root.join("Cargo.toml");
+    let mut workspace = read_file(&workspace_path)?;
+    let member = format!("    \"{}/day_*\",", year);
+    if !workspace.contains(member.trim()) {
+        workspace = insert_sorted(&workspace, &member, member_key)?;
+        let foreign = foreign_days(root, year);
+        if !foreign.is_empty() {
+            workspace = with_excluded(&workspace, &foreign);
+        }
+    }
+    let runner_path = root.join("aoc").join("Cargo.toml");
+    let runner = insert_sorted(
+        &read_file(&runner_path)?,
+        &format!("{} = {{ path = \"../{}/day_{:02}\" }}", name, year, day),
+        dependency_key,
+    )?;
+    let puzzles_path = root.join("aoc").join("src").join("puzzles.rs");
+    let puzzles = insert_sorted(
+        &read_file(&puzzles_path)?,
+        &format!("    puzzle!({}, {}, {}::Day{:02}),", year, day, name, day),
+        puzzle_key,
+    )?;
+
+    let src = dir.join("src");
+    create_dir_all(&src).map_err(|e| format!("Could not create {}: {}", src.display(), e))?;
+    write_file(
+        &dir.join("Cargo.toml"),
+        &CARGO_TEMPLATE.replace("CRATE_NAME", &name),
+    )?;
+    write_file(
+        &src.join("lib.rs"),
+        &LIB_TEMPLATE.replace("DAY_STRUCT", &format!("Day{:02}", day)),
+    )?;
+    write_file(
+        &src.join("main.rs"),
+        &MAIN_TEMPLATE
+            .replace("CRATE_NAME", &name)
+            .replace("DAY_STRUCT", &format!("Day{:02}", day)),
+    )?;
+    write_file(&dir.join("example.txt"), "")?;
+
+    write_file(&workspace_path, &workspace)?;
+    write_file(&runner_path, &runner)?;
+    write_file(&puzzles_path, &puzzles)?;
+    Ok(dir)
+}
+
+pub fn new(args: NewArgs) -> Result<(), String> {
+    let dir = scaffold(input::repo_root(), args.year, args.day)?;
+    println!("Created {}", dir.display());
+    println!(
+        "Paste the example into example.txt, then: cargo run --release -- run {} {}",
+        args.year, args.day
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    #[test]
+    fn test_insert_sorted() {
+        let content = "[dependencies]\naoc_core.workspace = true\naoc_2023_day_01 = { path = \"../2023/day_01\" }\naoc_2024_day_02 = { path = \"../2024/day_02\" }\n";
+
+        let inserted = insert_sorted(
+            content,
+            "aoc_2024_day_01 = { path = \"../2024/day_01\" }",
+            dependency_key,
+        )
+        .unwrap();
+        assert_eq!(
+            inserted.lines().skip(2).collect::<Vec<_>>(),
+            [
+                "aoc_2023_day_01 = { path = \"../2023/day_01\" }",
+                "aoc_2024_day_01 = { path = \"../2024/day_01\" }",
+                "aoc_2024_day_02 = { path = \"../2024/day_02\" }",
+            ]
+        );
+
+        let first = insert_sorted(content, "aoc_2015_day_03 = { }", dependency_key).unwrap();
+        assert_eq!(first.lines().nth(2), Some("aoc_2015_day_03 = { }"));
+
+        assert!(insert_sorted(content, "aoc_2023_day_01 = { }", dependency_key).is_err());
+    }
+
+    #[test]
+    fn test_with_excluded() {
+        let workspace =
+            "members = [\n    \"2015/day_*\",\n]\n# not Rust\nexclude = [\"2015/day_01\"]\n";
+        assert_eq!(
+            with_excluded(workspace, &["2025/day_01".to_string()]),
+            "members = [\n    \"2015/day_*\",\n]\n# not Rust\nexclude = [\"2015/day_01\", \"2025/day_01\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = remove_dir_all(&root);
+        create_dir_all(root.join("aoc").join("src")).unwrap();
+        // a day solved in another language, which has to stay out of the workspace
+        create_dir_all(root.join("2025").join("day_01")).unwrap();
+        write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2024/day_*\",\n]\n",
+        )
+        .unwrap();
+        write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\naoc_2024_day_01 = { path = \"../2024/day_01\" }\n",
+        )
+        .unwrap();
+        write(
+            root.join("aoc").join("src").join("puzzles.rs"),
+            "pub const PUZZLES: &[Puzzle] = &[\n    puzzle!(2024, 1, aoc_2024_day_01::Day01),\n];\n",
+        )
+        .unwrap();
+
+        let dir = scaffold(&root, 2025, 7).unwrap();
+        assert_eq!(dir, root.join("2025").join("day_07"));
+        assert!(read_to_string(dir.join("Cargo.toml")).unwrap().contains(
+            "name = \"aoc_2025_day_07\"\nversion.workspace = true\nedition.workspace = true\n"
+        ));
+        let lib = read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day07 {"));
+        assert!(lib.contains("impl Generate for Day07 {"));
+        assert!(lib.contains("include_str!(\"../example.txt\")"));
+        assert_eq!(
+            read_to_string(dir.join("src").join("main.rs")).unwrap(),
+            "fn main() {\n    aoc_core::run_day::<aoc_2025_day_07::Day07>();\n}\n"
+        );
+        assert_eq!(read_to_string(dir.join("example.txt")).unwrap(), "");
+
+        assert!(read_to_string(root.join("Cargo.toml")).unwrap().contains(
+            "    \"2024/day_*\",\n    \"2025/day_*\",\n]\nexclude = [\"2025/day_01\"]\n"
+        ));
+        assert!(
+            read_to_string(root.join("aoc").join("Cargo.toml"))
+                .unwrap()
+                .ends_with("aoc_2025_day_07 = { path = \"../2025/day_07\" }\n")
+        );
+        assert!(
+            read_to_string(root.join("aoc").join("src").join("puzzles.rs"))
+                .unwrap()
+                .contains("    puzzle!(2025, 7, aoc_2025_day_07::Day07),\n];")
+        );
+
+        let error = scaffold(&root, 2025, 7).unwrap_err();
+        assert!(error.ends_with("already exists"));
+        remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use crate::bench::{self, Timings};
+use aoc_core::{Answer, Generate, ParseError, Rng, Solution};
+
+/// answers for the parts that were asked for, tagged with the part number
+pub type Answers = Vec<(u8, Answer)>;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    /// parses the input and solves the requested part, or both
+    pub solve: fn(&str, Option<u8>) -> Result<Answers, ParseError>,
+    /// times parsing and each part separately over a number of runs
+    pub bench: fn(&str, usize) -> Result<Timings, ParseError>,
+    /// makes up an input of some size from a seed
+    pub generate: fn(usize, u64) -> String,
+    /// parses the input and writes it out again
+    pub render: fn(&str) -> Result<String, ParseError>,
+    /// the answer to a part the slow way, for the days that have one
+    pub brute_force: fn(&str, u8) -> Option<Answer>,
+}
+
+fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Answers, ParseError> {
+    let input = S::parse(input)?;
+    let mut answers = Vec::new();
+    if part != Some(2) {
+        answers.push((1, S::part1(&input)));
+    }
+    if part != Some(1) {
+        answers.push((2, S::part2(&input)));
+    }
+    Ok(answers)
+}
+
+fn generate<S: Generate>(size: usize, seed: u64) -> String {
+    S::generate(size, &mut Rng::new(seed))
+}
+
+fn render<S: Generate>(input: &str) -> Result<String, ParseError> {
+    S::parse(input).map(|input| S::render(&input))
+}
+
+fn brute_force<S: Generate>(input: &str, part: u8) -> Option<Answer> {
+    S::brute_force(&S::parse(input).ok()?, part)
+}
+
+macro_rules! puzzle {
+    ($year:literal, $day:literal, $solution:path) => {
+        Puzzle {
+            year: $year,
+            day: $day,
+            solve: solve::<$solution>,
+            bench: bench::time::<$solution>,
+            generate: generate::<$solution>,
+            render: render::<$solution>,
+            brute_force: brute_force::<$solution>,
+        }
+    };
+}
+
+#[rustfmt::skip]
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle!(2015, 2, aoc_2015_day_02::Day02),
+    puzzle!(2015, 3, aoc_2015_day_03::Day03),
+    puzzle!(2023, 1, aoc_2023_day_01::Day01),
+    puzzle!(2023, 2, aoc_2023_day_02::Day02),
+    puzzle!(2023, 3, aoc_2023_day_03::Day03),
+    puzzle!(2023, 4, aoc_2023_day_04::Day04),
+    puzzle!(2023, 5, aoc_2023_day_05::Day05),
+    puzzle!(2023, 6, aoc_2023_day_06::Day06),
+    puzzle!(2023, 7, aoc_2023_day_07::Day07),
+    puzzle!(2023, 8, aoc_2023_day_08::Day08),
+    puzzle!(2023, 9, aoc_2023_day_09::Day09),
+    puzzle!(2023, 10, aoc_2023_day_10::Day10),
+    puzzle!(2023, 11, aoc_2023_day_11::Day11),
+    puzzle!(2023, 12, aoc_2023_day_12::Day12),
+    puzzle!(2023, 13, aoc_2023_day_13::Day13),
+    puzzle!(2023, 14, aoc_2023_day_14::Day14),
+    puzzle!(2023, 15, aoc_2023_day_15::Day15),
+    puzzle!(2023, 16, aoc_2023_day_16::Day16),
+    puzzle!(2023, 17, aoc_2023_day_17::Day17),
+    puzzle!(2023, 18, aoc_2023_day_18::Day18),
+    puzzle!(2023, 19, aoc_2023_day_19::Day19),
+    puzzle!(2023, 20, aoc_2023_day_20::Day20),
+    puzzle!(2023, 21, aoc_2023_day_21::Day21),
+    puzzle!(2023, 22, aoc_2023_day_22::Day22),
+    puzzle!(2023, 23, aoc_2023_day_23::Day23),
+    puzzle!(2023, 24, aoc_2023_day_24::Day24),
+    puzzle!(2023, 25, aoc_2023_day_25::Day25),
+    puzzle!(2024, 1, aoc_2024_day_01::Day01),
+    puzzle!(2024, 2, aoc_2024_day_02::Day02),
+    puzzle!(2024, 3, aoc_2024_day_03::Day03),
+    puzzle!(2024, 4, aoc_2024_day_04::Day04),
+    puzzle!(2024, 5, aoc_2024_day_05::Day05),
+    puzzle!(2024, 6, aoc_2024_day_06::Day06),
+    puzzle!(2024, 7, aoc_2024_day_07::Day07),
+    puzzle!(2024, 8, aoc_2024_day_08::Day08),
+    puzzle!(2024, 9, aoc_2024_day_09::Day09),
+    puzzle!(2024, 10, aoc_2024_day_10::Day10),
+    puzzle!(2024, 11, aoc_2024_day_11::Day11),
+    puzzle!(2024, 12, aoc_2024_day_12::Day12),
+    puzzle!(2024, 13, aoc_2024_day_13::Day13),
+    puzzle!(2024, 14, aoc_2024_day_14::Day14),
+    puzzle!(2024, 15, aoc_2024_day_15::Day15),
+    puzzle!(2024, 16, aoc_2024_day_16::Day16),
+    puzzle!(2024, 17, aoc_2024_day_17::Day17),
+    puzzle!(2024, 18, aoc_2024_day_18::Day18),
+    puzzle!(2024, 19, aoc_2024_day_19::Day19),
+    puzzle!(2024, 20, aoc_2024_day_20::Day20),
+    puzzle!(2024, 21, aoc_2024_day_21::Day21),
+    puzzle!(2024, 22, aoc_2024_day_22::Day22),
+    puzzle!(2024, 23, aoc_2024_day_23::Day23),
+    puzzle!(2024, 24, aoc_2024_day_24::Day24),
+    puzzle!(2024, 25, aoc_2024_day_25::Day25),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.year == year && p.day == day)
+}
+
+/// Every puzzle, or the ones for a year, or a single day.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .filter(|p| year.is_none_or(|y| p.year == y))
+        .filter(|p| day.is_none_or(|d| p.day == d))
+        .collect()
+}
+
+/// Parses the optional `[<year> [<day>]]` positional arguments.
+pub fn parse_selection(positional: &[&str]) -> Result<(Option<u16>, Option<u8>), String> {
+    let year = match positional.first() {
+        Some(year) => Some(
+            year.parse()
+                .map_err(|_| format!("Invalid year '{}'", year))?,
+        ),
+        None => None,
+    };
+    let day = match positional.get(1) {
+        Some(day) => Some(day.parse().map_err(|_| format!("Invalid day '{}'", day))?),
+        None => None,
+    };
+    Ok((year, day))
+}
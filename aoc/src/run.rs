@@ -0,0 +1,472 @@
+use crate::bench::format_duration;
+use crate::input::{self, InputSource};
+use crate::json;
+use crate::puzzles::{self, Puzzle};
+use aoc_core::Answer;
+use aoc_grid::view::{self, Output};
+use rayon::prelude::*;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub const USAGE: &str = "Usage: aoc run <year> <day>|--all [--part 1|2] [--input <path>|-] [--inputs-dir <dir>] [--format text|json] [--visualize terminal|ppm:<dir>|png:<dir>]";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    /// one JSON object per line for each part, for scripts to consume
+    Json,
+}
+
+pub struct RunArgs {
+    year: u16,
+    /// every day of the year when there's none
+    day: Option<u8>,
+    part: Option<u8>,
+    input: InputSource,
+    format: Format,
+    /// where the day's pictures of its state go, if anywhere
+    visualize: Option<Output>,
+}
+
+fn parse_output(value: &str) -> Result<Output, String> {
+    match value.split_once(':') {
+        None if value == "terminal" => Ok(Output::Terminal),
+        Some(("ppm", dir)) if !dir.is_empty() => Ok(Output::Ppm(PathBuf::from(dir))),
+        Some(("png", dir)) if !dir.is_empty() => Ok(Output::Png(PathBuf::from(dir))),
+        _ => Err(format!(
+            "Invalid visualization '{}', expected terminal, ppm:<dir> or png:<dir>",
+            value
+        )),
+    }
+}
+
+pub fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut part = None;
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut format = Format::Text;
+    let mut visualize = None;
+    let mut all = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let value = iter.next().ok_or("--part needs a value")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part '{}', expected 1 or 2", value)),
+                };
+            }
+            "--input" | "-i" => {
+                let value = iter.next().ok_or("--input needs a path, or - for stdin")?;
+                input = Some(InputSource::from_arg(value));
+            }
+            "--inputs-dir" => {
+                let value = iter.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(PathBuf::from(value));
+            }
+            "--format" => {
+                let value = iter.next().ok_or("--format needs text or json")?;
+                format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Invalid format '{}', expected text or json", value)),
+                };
+            }
+            "--visualize" => {
+                let value = iter
+                    .next()
+                    .ok_or("--visualize needs terminal, ppm:<dir> or png:<dir>")?;
+                visualize = Some(parse_output(value)?);
+            }
+            "--all" => all = true,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != if all { 1 } else { 2 } {
+        return Err(USAGE.to_string());
+    }
+    let (Some(year), day) = puzzles::parse_selection(&positional)? else {
+        return Err(USAGE.to_string());
+    };
+    if all && input.is_some() {
+        return Err("--input is a single day's input, it can't be used with --all".to_string());
+    }
+    if all && visualize.is_some() {
+        return Err("--visualize draws a single day, it can't be used with --all".to_string());
+    }
+    let input = input.unwrap_or(InputSource::Lookup { inputs_dir });
+    Ok(RunArgs {
+        year,
+        day,
+        part,
+        input,
+        format,
+        visualize,
+    })
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => Some(message),
+        None => payload.downcast_ref::<String>().map(String::as_str),
+    }
+}
+
+/// How solving one part went.
+pub enum Outcome {
+    /// `Answer::None` when the day has no such part
+    Solved(Answer),
+    NoInput(String),
+    InvalidInput(String),
+    Panicked(String),
+}
+
+pub struct PartRun {
+    pub part: u8,
+    pub outcome: Outcome,
+    /// parsing included
+    pub elapsed: Duration,
+}
+
+impl PartRun {
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.outcome,
+            Outcome::InvalidInput(_) | Outcome::Panicked(_)
+        )
+    }
+
+    fn status(&self) -> &'static str {
+        match self.outcome {
+            Outcome::Solved(Answer::None) => "no-answer",
+            Outcome::Solved(_) => "ok",
+            Outcome::NoInput(_) => "no-input",
+            Outcome::InvalidInput(_) => "error",
+            Outcome::Panicked(_) => "panicked",
+        }
+    }
+
+    fn answer(&self) -> Option<String> {
+        match &self.outcome {
+            Outcome::Solved(Answer::None) => None,
+            Outcome::Solved(answer) => Some(answer.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match &self.outcome {
+            Outcome::Solved(_) => None,
+            Outcome::NoInput(e) | Outcome::InvalidInput(e) | Outcome::Panicked(e) => Some(e),
+        }
+    }
+
+    fn to_json(&self, puzzle: &Puzzle) -> json::Object {
+        json::Object::new()
+            .number("year", puzzle.year)
+            .number("day", puzzle.day)
+            .number("part", self.part)
+            .string("status", Some(self.status()))
+            .string("answer", self.answer().as_deref())
+            .number(
+                "elapsed_ms",
+                format!("{:.3}", self.elapsed.as_secs_f64() * 1000.0),
+            )
+            .string("error", self.error())
+    }
+}
+
+/// Solves one part on its own, parsing included, so it can be timed and
+/// fail separately. Anything the solution prints goes to stderr.
+pub fn run_part(puzzle: &Puzzle, input: &Result<String, String>, part: u8) -> PartRun {
+    let start = Instant::now();
+    let outcome = match input {
+        Err(e) => Outcome::NoInput(e.clone()),
+        Ok(input) => {
+            match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve)(input, Some(part)))) {
+                Ok(Ok(answers)) => {
+                    Outcome::Solved(answers.into_iter().next().map_or(Answer::None, |(_, a)| a))
+                }
+                Ok(Err(e)) => Outcome::InvalidInput(format!("Invalid input: {}", e)),
+                Err(payload) => Outcome::Panicked(
+                    panic_message(payload.as_ref())
+                        .unwrap_or("unknown cause")
+                        .to_string(),
+                ),
+            }
+        }
+    };
+    PartRun {
+        part,
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run_json(puzzle: &Puzzle, args: &RunArgs) -> Result<(), String> {
+    let input = input::read_input(&args.input, puzzle.year, puzzle.day);
+    let mut failed = 0;
+    for part in parts(args.part) {
+        let run = run_part(puzzle, &input, part);
+        println!("{}", run.to_json(puzzle));
+        // a missing input is a failure when it's the only day asked for
+        failed += (run.failed() || matches!(run.outcome, Outcome::NoInput(_))) as usize;
+    }
+    match failed {
+        0 => Ok(()),
+        n => Err(format!(
+            "{} of {} day {}'s parts failed",
+            n, puzzle.year, puzzle.day
+        )),
+    }
+}
+
+fn run_day(puzzle: &Puzzle, args: &RunArgs) -> Result<(), String> {
+    if args.format == Format::Json {
+        return run_json(puzzle, args);
+    }
+
+    let input = input::read_input(&args.input, puzzle.year, puzzle.day)?;
+
+    let answers = (puzzle.solve)(&input, args.part).map_err(|e| {
+        format!(
+            "Invalid input for {} day {}: {}",
+            puzzle.year, puzzle.day, e
+        )
+    })?;
+    for (part, answer) in answers {
+        if answer == Answer::None && args.part == Some(part) {
+            return Err(format!(
+                "{} day {} has no part {}",
+                puzzle.year, puzzle.day, part
+            ));
+        }
+        println!("Part {}: {}", part, answer);
+    }
+    Ok(())
+}
+
+/// What a day's table cell shows for a part.
+fn cell(run: &PartRun) -> String {
+    match &run.outcome {
+        Outcome::Solved(answer) => answer.to_string(),
+        Outcome::NoInput(_) => "no input".to_string(),
+        Outcome::InvalidInput(_) => "FAIL".to_string(),
+        Outcome::Panicked(_) => "PANIC".to_string(),
+    }
+}
+
+fn day_status(runs: &[PartRun]) -> &'static str {
+    if runs.iter().any(PartRun::failed) {
+        "FAIL"
+    } else if runs
+        .iter()
+        .any(|r| matches!(r.outcome, Outcome::NoInput(_)))
+    {
+        "skipped"
+    } else {
+        "ok"
+    }
+}
+
+fn print_table(days: &[(&Puzzle, Vec<PartRun>)], parts: &[u8]) {
+    let width = days
+        .iter()
+        .flat_map(|(_, runs)| runs.iter().map(|r| cell(r).len()))
+        .max()
+        .unwrap_or(0)
+        .max("part 1".len());
+    let mut header = format!("{:>3}  {:<7}", "day", "status");
+    for part in parts {
+        header += &format!("  {:<w$}", format!("part {}", part), w = width);
+    }
+    println!("{}  {:>8}", header, "time");
+
+    for (puzzle, runs) in days {
+        let mut line = format!("{:>3}  {:<7}", puzzle.day, day_status(runs));
+        for run in runs {
+            line += &format!("  {:<w$}", cell(run), w = width);
+        }
+        let elapsed: Duration = runs.iter().map(|r| r.elapsed).sum();
+        println!("{}  {:>8}", line, format_duration(elapsed));
+    }
+}
+
+/// Runs every day of the year on the thread pool, each part on its own so
+/// a panic only takes that part down.
+fn run_year(args: &RunArgs) -> Result<(), String> {
+    let selected = puzzles::select(Some(args.year), None);
+    if selected.is_empty() {
+        return Err(format!("No solutions for {}", args.year));
+    }
+    let parts = parts(args.part);
+
+    let start = Instant::now();
+    let days: Vec<(&Puzzle, Vec<PartRun>)> = selected
+        .par_iter()
+        .map(|&puzzle| {
+            let input = input::read_input(&args.input, puzzle.year, puzzle.day);
+            let runs = parts
+                .iter()
+                .map(|&part| run_part(puzzle, &input, part))
+                .collect();
+            (puzzle, runs)
+        })
+        .collect();
+    let wall = start.elapsed();
+
+    let runs = || {
+        days.iter()
+            .flat_map(|(p, runs)| runs.iter().map(move |r| (*p, r)))
+    };
+    let failed: Vec<(&Puzzle, &PartRun)> = runs().filter(|(_, r)| r.failed()).collect();
+    let skipped = days
+        .iter()
+        .filter(|(_, runs)| day_status(runs) == "skipped")
+        .count();
+    let solving: Duration = runs().map(|(_, r)| r.elapsed).sum();
+    let summary = format!(
+        "{} days in {} wall clock, {} spent solving: {} failed, {} without input",
+        days.len(),
+        format_duration(wall),
+        format_duration(solving),
+        failed.len(),
+        skipped
+    );
+
+    match args.format {
+        Format::Json => {
+            for (puzzle, run) in runs() {
+                println!("{}", run.to_json(puzzle));
+            }
+            eprintln!("{}", summary);
+        }
+        Format::Text => {
+            print_table(&days, &parts);
+            if !failed.is_empty() {
+                println!();
+            }
+            for (puzzle, run) in &failed {
+                println!(
+                    "Day {} part {}: {}",
+                    puzzle.day,
+                    run.part,
+                    run.error().unwrap_or_default()
+                );
+            }
+            println!("\n{}", summary);
+        }
+    }
+
+    match failed.len() {
+        0 => Ok(()),
+        n => Err(format!("{} of {}'s parts failed", n, args.year)),
+    }
+}
+
+pub fn run(args: RunArgs) -> Result<(), String> {
+    match args.day {
+        Some(day) => {
+            let puzzle = puzzles::find(args.year, day)
+                .ok_or(format!("No solution for {} day {}", args.year, day))?;
+            view::set_output(args.visualize.clone());
+            run_day(puzzle, &args)
+        }
+        None => run_year(&args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_parse_run_args() {
+        let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+        let all = parse_run_args(&args("2023 --all --part 2")).unwrap();
+        assert_eq!((all.year, all.day, all.part), (2023, None, Some(2)));
+        let one = parse_run_args(&args("2023 5")).unwrap();
+        assert_eq!((one.year, one.day), (2023, Some(5)));
+
+        assert!(parse_run_args(&args("2023")).is_err());
+        assert!(parse_run_args(&args("2023 5 --all")).is_err());
+        assert!(parse_run_args(&args("2023 --all --input x.txt")).is_err());
+
+        let drawn = parse_run_args(&args("2023 16 --visualize png:frames")).unwrap();
+        assert_eq!(drawn.visualize, Some(Output::Png(PathBuf::from("frames"))));
+        assert!(parse_run_args(&args("2023 16 --visualize ppm")).is_err());
+        assert!(parse_run_args(&args("2023 --all --visualize terminal")).is_err());
+    }
+
+    #[test]
+    fn test_run_part_json() {
+        let puzzle = puzzles::find(2024, 1).unwrap();
+
+        let run = run_part(puzzle, &Ok(EXAMPLE.to_string()), 1);
+        assert!(!run.failed());
+        let object = run.to_json(puzzle).to_string();
+        assert!(object.starts_with(
+            "{\"year\":2024,\"day\":1,\"part\":1,\"status\":\"ok\",\"answer\":\"11\",\"elapsed_ms\":"
+        ));
+        assert!(object.ends_with(",\"error\":null}"));
+
+        let run = run_part(puzzle, &Ok("3 x\n".to_string()), 2);
+        assert!(run.failed());
+        let object = run.to_json(puzzle).to_string();
+        assert!(object.contains("\"status\":\"error\",\"answer\":null,"));
+        assert!(object.ends_with(
+            "\"error\":\"Invalid input: line 1, column 3: expected a number, found 'x'\"}"
+        ));
+    }
+
+    #[test]
+    fn test_panics_stay_in_their_part() {
+        let puzzle = Puzzle {
+            year: 2024,
+            day: 1,
+            solve: |input, part| match part {
+                Some(2) => panic!("no part 2 for {} bytes", input.len()),
+                _ => Ok(vec![(1, Answer::from(7))]),
+            },
+            bench: puzzles::find(2024, 1).unwrap().bench,
+            generate: puzzles::find(2024, 1).unwrap().generate,
+            render: puzzles::find(2024, 1).unwrap().render,
+            brute_force: puzzles::find(2024, 1).unwrap().brute_force,
+        };
+        let input = Ok(EXAMPLE.to_string());
+        let first = run_part(&puzzle, &input, 1);
+        let second = run_part(&puzzle, &input, 2);
+        assert_eq!(cell(&first), "7");
+        assert_eq!(cell(&second), "PANIC");
+        assert_eq!(second.error(), Some("no part 2 for 36 bytes"));
+        assert_eq!(day_status(&[first, second]), "FAIL");
+    }
+
+    #[test]
+    fn test_missing_input_is_not_a_failure() {
+        let puzzle = puzzles::find(2024, 1).unwrap();
+        let runs = vec![
+            run_part(puzzle, &Err("No input".to_string()), 1),
+            run_part(puzzle, &Err("No input".to_string()), 2),
+        ];
+        assert!(!runs.iter().any(PartRun::failed));
+        assert_eq!(day_status(&runs), "skipped");
+        assert_eq!(cell(&runs[0]), "no input");
+    }
+}
@@ -0,0 +1,270 @@
+use crate::bench::format_duration;
+use crate::generate::{DEFAULT_SIZE, parse_number};
+use crate::puzzles::{self, Puzzle};
+use crate::run::{Outcome, panic_message, run_part};
+use aoc_core::Answer;
+use rayon::prelude::*;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+pub const USAGE: &str = "Usage: aoc stress [<year> [<day>]] [--size <n>] [--runs <n>] [--seed <n>]";
+
+const DEFAULT_RUNS: u64 = 20;
+
+pub struct StressArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    size: usize,
+    runs: u64,
+    /// the first seed, each run uses the next one
+    seed: u64,
+}
+
+pub fn parse_stress_args(args: &[String]) -> Result<StressArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut size = DEFAULT_SIZE;
+    let mut runs = DEFAULT_RUNS;
+    let mut seed = 0;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--size" => {
+                size = match parse_number("--size", iter.next())? {
+                    0 => return Err("Invalid size '0'".to_string()),
+                    n => n,
+                };
+            }
+            "--runs" | "-n" => {
+                runs = match parse_number("--runs", iter.next())? {
+                    0 => return Err("Invalid runs '0'".to_string()),
+                    n => n,
+                };
+            }
+            "--seed" => seed = parse_number("--seed", iter.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        return Err(USAGE.to_string());
+    }
+    let (year, day) = puzzles::parse_selection(&positional)?;
+    Ok(StressArgs {
+        year,
+        day,
+        size,
+        runs,
+        seed,
+    })
+}
+
+struct Failure {
+    seed: u64,
+    /// none when it's the generator that panicked
+    part: Option<u8>,
+    error: String,
+}
+
+struct Row {
+    puzzle: &'static Puzzle,
+    failures: Vec<Failure>,
+    /// the slowest run of a single part
+    slowest: Duration,
+}
+
+/// Checks an answer against the day's brute force, when it has one.
+fn check_answer(puzzle: &Puzzle, input: &str, part: u8, answer: &Answer) -> Result<(), String> {
+    let expected = panic::catch_unwind(AssertUnwindSafe(|| (puzzle.brute_force)(input, part)))
+        .map_err(|payload| {
+            format!(
+                "brute force panicked: {}",
+                panic_message(payload.as_ref()).unwrap_or("unknown cause")
+            )
+        })?;
+    match expected {
+        Some(expected) if expected != *answer => Err(format!(
+            "answered {}, but brute force says {}",
+            answer, expected
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Solves both parts of one generated input, and checks the answers against
+/// the brute force of the days that have one.
+fn stress_seed(puzzle: &Puzzle, size: usize, seed: u64) -> (Vec<Failure>, Duration) {
+    let input = match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.generate)(size, seed))) {
+        Ok(input) => input,
+        Err(payload) => {
+            let error = panic_message(payload.as_ref()).unwrap_or("unknown cause");
+            let failure = Failure {
+                seed,
+                part: None,
+                error: format!("generator panicked: {}", error),
+            };
+            return (vec![failure], Duration::ZERO);
+        }
+    };
+    let mut failures = Vec::new();
+    let mut slowest = Duration::ZERO;
+    for part in [1, 2] {
+        let run = run_part(puzzle, &Ok(input.clone()), part);
+        slowest = slowest.max(run.elapsed);
+        let checked = match &run.outcome {
+            Outcome::Solved(answer) => check_answer(puzzle, &input, part, answer),
+            _ => Err(run.error().unwrap_or_default().to_string()),
+        };
+        if let Err(error) = checked {
+            failures.push(Failure {
+                seed,
+                part: Some(part),
+                error,
+            });
+        }
+    }
+    (failures, slowest)
+}
+
+fn stress_puzzle(puzzle: &'static Puzzle, args: &StressArgs) -> Row {
+    let runs: Vec<(Vec<Failure>, Duration)> = (args.seed..args.seed + args.runs)
+        .into_par_iter()
+        .map(|seed| stress_seed(puzzle, args.size, seed))
+        .collect();
+    let slowest = runs
+        .iter()
+        .map(|(_, elapsed)| *elapsed)
+        .max()
+        .unwrap_or_default();
+    Row {
+        puzzle,
+        failures: runs
+            .into_iter()
+            .flat_map(|(failures, _)| failures)
+            .collect(),
+        slowest,
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:>4}  {:>3}  {:<7}  {:>8}",
+        "year", "day", "status", "slowest"
+    );
+    for row in rows {
+        let status = match row.failures.len() {
+            0 => "ok".to_string(),
+            n => format!("{} FAIL", n),
+        };
+        println!(
+            "{:>4}  {:>3}  {:<7}  {:>8}",
+            row.puzzle.year,
+            row.puzzle.day,
+            status,
+            format_duration(row.slowest)
+        );
+    }
+}
+
+/// Solves inputs made up from consecutive seeds, to catch the assumptions a
+/// single personal input never challenges.
+pub fn stress(args: StressArgs) -> Result<(), String> {
+    let selected = puzzles::select(args.year, args.day);
+    if selected.is_empty() {
+        return Err("No solutions match".to_string());
+    }
+
+    let rows: Vec<Row> = selected
+        .par_iter()
+        .map(|&puzzle| stress_puzzle(puzzle, &args))
+        .collect();
+    print_table(&rows);
+
+    let failed: Vec<(&Puzzle, &Failure)> = rows
+        .iter()
+        .flat_map(|row| row.failures.iter().map(move |f| (row.puzzle, f)))
+        .collect();
+    if failed.is_empty() {
+        return Ok(());
+    }
+    println!();
+    for (puzzle, failure) in &failed {
+        let part = failure
+            .part
+            .map_or(String::new(), |p| format!(" part {}", p));
+        println!(
+            "{} day {}{}, seed {}: {}",
+            puzzle.year, puzzle.day, part, failure.seed, failure.error
+        );
+    }
+    let (puzzle, failure) = failed[0];
+    println!(
+        "\nReproduce with: aoc generate {} {} --size {} --seed {} | aoc run {} {} --input -",
+        puzzle.year, puzzle.day, args.size, failure.seed, puzzle.year, puzzle.day
+    );
+    Err(format!(
+        "{} of {} runs failed",
+        failed.len(),
+        rows.len() as u64 * args.runs
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stress_args() {
+        let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+        let parsed = parse_stress_args(&args("2024 --runs 5 --size 3")).unwrap();
+        assert_eq!(
+            (
+                parsed.year,
+                parsed.day,
+                parsed.size,
+                parsed.runs,
+                parsed.seed
+            ),
+            (Some(2024), None, 3, 5, 0)
+        );
+        assert!(parse_stress_args(&args("2024 --runs 0")).is_err());
+        assert!(parse_stress_args(&args("2024 1 2")).is_err());
+    }
+
+    #[test]
+    fn test_answers_are_checked_against_brute_force() {
+        let day = puzzles::find(2024, 1).unwrap();
+        let puzzle = Puzzle {
+            brute_force: |_, part| Some(Answer::from(part)),
+            ..*day
+        };
+        let (failures, _) = stress_seed(&puzzle, 3, 0);
+        let parts: Vec<Option<u8>> = failures.iter().map(|f| f.part).collect();
+        assert_eq!(parts, [Some(1), Some(2)]);
+        assert!(failures[0].error.ends_with("but brute force says 1"));
+        assert!(stress_seed(day, 3, 0).0.is_empty());
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solved() {
+        let args = StressArgs {
+            year: None,
+            day: None,
+            size: 3,
+            runs: 2,
+            seed: 0,
+        };
+        let rows: Vec<Row> = puzzles::select(None, None)
+            .par_iter()
+            .map(|&puzzle| stress_puzzle(puzzle, &args))
+            .collect();
+        for row in rows {
+            if let Some(failure) = row.failures.first() {
+                panic!(
+                    "{} day {} part {:?}, seed {}: {}",
+                    row.puzzle.year, row.puzzle.day, failure.part, failure.seed, failure.error
+                );
+            }
+        }
+    }
+}
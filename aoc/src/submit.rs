@@ -0,0 +1,306 @@
+use crate::answers::{Answers, Key};
+use crate::client::{self, Client, Throttle};
+use crate::history::{History, Verdict};
+use crate::input::{self, InputSource};
+use crate::puzzles;
+use aoc_core::Answer;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: aoc submit <year> <day> <part> [--input <path>|-] [--inputs-dir <dir>] [--base-url <url>] [--history <file>]";
+
+pub struct SubmitArgs {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<InputSource>,
+    inputs_dir: Option<PathBuf>,
+    base_url: Option<String>,
+    history: Option<PathBuf>,
+}
+
+pub fn parse_submit_args(args: &[String]) -> Result<SubmitArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut input = None;
+    let mut inputs_dir = None;
+    let mut base_url = None;
+    let mut history = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let value = iter.next().ok_or("--input needs a path, or - for stdin")?;
+                input = Some(InputSource::from_arg(value));
+            }
+            "--inputs-dir" => {
+                let value = iter.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(PathBuf::from(value));
+            }
+            "--base-url" => {
+                let value = iter.next().ok_or("--base-url needs a URL")?;
+                base_url = Some(value.clone());
+            }
+            "--history" => {
+                let value = iter.next().ok_or("--history needs a file")?;
+                history = Some(PathBuf::from(value));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 3 {
+        return Err(USAGE.to_string());
+    }
+    let (Some(year), Some(day)) = puzzles::parse_selection(&positional[..2])? else {
+        return Err(USAGE.to_string());
+    };
+    let part = match positional[2] {
+        "1" => 1,
+        "2" => 2,
+        other => return Err(format!("Invalid part '{}', expected 1 or 2", other)),
+    };
+    Ok(SubmitArgs {
+        year,
+        day,
+        part,
+        input,
+        inputs_dir,
+        base_url,
+        history,
+    })
+}
+
+/// How the site answered a submission.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Verdict(Verdict),
+    /// answered too recently, with how long is left to wait
+    Wait(Option<String>),
+    /// the part was already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+fn parse_response(body: &str) -> Option<Outcome> {
+    if body.contains("That's the right answer") {
+        return Some(Outcome::Verdict(Verdict::Right));
+    }
+    if body.contains("That's not the right answer") {
+        let verdict = if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        return Some(Outcome::Verdict(verdict));
+    }
+    if body.contains("You gave an answer too recently") {
+        let left = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(left, _)| left.to_string());
+        return Some(Outcome::Wait(left));
+    }
+    if body.contains("You don't seem to be solving the right level") {
+        return Some(Outcome::WrongLevel);
+    }
+    None
+}
+
+/// Posts `answer` unless the history already tells how it would go, and
+/// records the verdict.
+fn submit_answer(
+    client: &Client,
+    history: &mut History,
+    key: Key,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let (year, day, part) = key;
+    if let Some(reason) = history.known_outcome(key, answer) {
+        return Err(format!(
+            "Not submitting {} for {} day {} part {}, {}",
+            answer, year, day, part, reason
+        ));
+    }
+
+    let level = part.to_string();
+    let path = format!("/{}/day/{}/answer", year, day);
+    let response = client.post_form(&path, &[("level", &level), ("answer", answer)])?;
+    if response.status != 200 {
+        return Err(format!(
+            "Unexpected response {} from {}",
+            response.status,
+            client.url(&path)
+        ));
+    }
+    match parse_response(&response.body) {
+        Some(Outcome::Verdict(verdict)) => {
+            history.record(key, verdict, answer)?;
+            Ok(verdict)
+        }
+        Some(Outcome::Wait(left)) => Err(format!(
+            "Answered too recently, try again in {}",
+            left.as_deref().unwrap_or("a while")
+        )),
+        Some(Outcome::WrongLevel) => Err(format!(
+            "{} day {} part {} is already solved, or not unlocked yet",
+            year, day, part
+        )),
+        None => Err(format!(
+            "Could not make sense of the response from {}",
+            client.url(&path)
+        )),
+    }
+}
+
+pub fn submit(args: SubmitArgs) -> Result<(), String> {
+    let key = (args.year, args.day, args.part);
+    let puzzle = puzzles::find(args.year, args.day)
+        .ok_or(format!("No solution for {} day {}", args.year, args.day))?;
+    let inputs_dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let source = args.input.unwrap_or(InputSource::Lookup {
+        inputs_dir: Some(inputs_dir.clone()),
+    });
+    let input = input::read_input(&source, args.year, args.day)?;
+
+    let answers = (puzzle.solve)(&input, Some(args.part))
+        .map_err(|e| format!("Invalid input for {} day {}: {}", args.year, args.day, e))?;
+    let answer = match answers.into_iter().next() {
+        Some((_, Answer::None)) | None => {
+            return Err(format!(
+                "{} day {} has no part {}",
+                args.year, args.day, args.part
+            ));
+        }
+        Some((_, answer)) => answer.to_string(),
+    };
+    println!("Part {}: {}", args.part, answer);
+
+    let history_path = args.history.unwrap_or(inputs_dir.join("submissions.txt"));
+    let mut history = History::load(&history_path)?;
+    let client = Client::new(
+        client::base_url(args.base_url.as_deref()),
+        client::session(&inputs_dir)?,
+        Throttle::new(&inputs_dir, client::MIN_INTERVAL),
+    );
+
+    match submit_answer(&client, &mut history, key, &answer)? {
+        Verdict::Right => {
+            println!("That's the right answer!");
+            let mut known = Answers::load(&inputs_dir.join("answers.txt"))?;
+            if known.get(key).is_none() {
+                known.insert(key, answer);
+                known.save()?;
+            }
+            Ok(())
+        }
+        verdict => Err(format!(
+            "That's not the right answer ({})",
+            verdict.label().replace('-', " ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read_to_string, remove_dir_all};
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::{Response, Server};
+
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer to restoring snow operations.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>";
+
+    /// A stand-in for the site that only likes 42, asks to wait on 7, and
+    /// reports the body of every post it gets.
+    fn mock_server() -> (String, Receiver<String>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let page = match body.as_str() {
+                    "level=1&answer=42" => RIGHT,
+                    "level=1&answer=7" => WAIT,
+                    _ => TOO_HIGH,
+                };
+                sender.send(body).unwrap();
+                request.respond(Response::from_string(page)).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(RIGHT),
+            Some(Outcome::Verdict(Verdict::Right))
+        );
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Some(Outcome::Verdict(Verdict::TooHigh))
+        );
+        assert_eq!(
+            parse_response(WAIT),
+            Some(Outcome::Wait(Some("34s".to_string())))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            ),
+            Some(Outcome::WrongLevel)
+        );
+        assert_eq!(parse_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_submit_records_and_blocks_known_wrong() {
+        let (url, posts) = mock_server();
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+        let client = Client::new(
+            url,
+            "secret".to_string(),
+            Throttle::new(&dir, Duration::ZERO),
+        );
+        let history_path = dir.join("submissions.txt");
+        let mut history = History::load(&history_path).unwrap();
+        let key = (2023, 5, 1);
+
+        assert_eq!(
+            submit_answer(&client, &mut history, key, "50"),
+            Ok(Verdict::TooHigh)
+        );
+        assert_eq!(posts.recv().unwrap(), "level=1&answer=50");
+
+        let error = submit_answer(&client, &mut history, key, "60").unwrap_err();
+        assert_eq!(
+            error,
+            "Not submitting 60 for 2023 day 5 part 1, 50 was already too high"
+        );
+        assert!(posts.try_recv().is_err());
+
+        let error = submit_answer(&client, &mut history, key, "7").unwrap_err();
+        assert_eq!(error, "Answered too recently, try again in 34s");
+        posts.recv().unwrap();
+
+        assert_eq!(
+            submit_answer(&client, &mut history, key, "42"),
+            Ok(Verdict::Right)
+        );
+        posts.recv().unwrap();
+
+        // waiting isn't a verdict, so it wasn't recorded
+        assert_eq!(
+            read_to_string(&history_path).unwrap(),
+            "2023 05 1 too-high 50\n2023 05 1 right 42\n"
+        );
+        remove_dir_all(&dir).unwrap();
+    }
+}
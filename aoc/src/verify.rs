@@ -0,0 +1,240 @@
+use crate::answers::Answers;
+use crate::input::{self, InputSource};
+use crate::puzzles::{self, Puzzle};
+use aoc_core::Answer;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+pub const USAGE: &str =
+    "Usage: aoc verify [<year> [<day>]] [--inputs-dir <dir>] [--answers <file>] [--record]";
+
+pub struct VerifyArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    inputs_dir: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    /// store answers for parts that don't have one yet
+    record: bool,
+}
+
+pub fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut inputs_dir = None;
+    let mut answers = None;
+    let mut record = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--inputs-dir" => {
+                let value = iter.next().ok_or("--inputs-dir needs a directory")?;
+                inputs_dir = Some(PathBuf::from(value));
+            }
+            "--answers" => {
+                let value = iter.next().ok_or("--answers needs a file")?;
+                answers = Some(PathBuf::from(value));
+            }
+            "--record" => record = true,
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        return Err(USAGE.to_string());
+    }
+    let (year, day) = puzzles::parse_selection(&positional)?;
+    Ok(VerifyArgs {
+        year,
+        day,
+        inputs_dir,
+        answers,
+        record,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Mismatch,
+    /// the solution panicked or couldn't parse the input
+    Fail,
+    /// there's no input for the day
+    Skipped,
+    /// no stored answer to compare against
+    Unknown,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Mismatch => "MISMATCH",
+            Status::Fail => "FAIL",
+            Status::Skipped => "skipped",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: String,
+    expected: String,
+}
+
+fn status(answer: &Answer, expected: Option<&str>) -> Status {
+    match expected {
+        None => Status::Unknown,
+        Some(expected) if answer.to_string() == expected => Status::Pass,
+        Some(_) => Status::Mismatch,
+    }
+}
+
+fn verify_puzzle(puzzle: &Puzzle, source: &InputSource, answers: &Answers) -> Vec<Row> {
+    let expected = |part| answers.get((puzzle.year, puzzle.day, part));
+    let row = |part, status, answer: String| Row {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        status,
+        answer,
+        expected: expected(part).unwrap_or("-").to_string(),
+    };
+
+    // without answers to go by, each part gets the same row
+    let both_parts = |status: Status, answer: &str| {
+        vec![
+            row(1, status, answer.to_string()),
+            row(2, status, answer.to_string()),
+        ]
+    };
+
+    let input = match input::read_input(source, puzzle.year, puzzle.day) {
+        Ok(input) => input,
+        Err(_) => return both_parts(Status::Skipped, "no input"),
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve)(&input, None))) {
+        Ok(Err(e)) => {
+            eprintln!("{} day {}: {}", puzzle.year, puzzle.day, e);
+            both_parts(Status::Fail, "invalid input")
+        }
+        Ok(Ok(solved)) => solved
+            .into_iter()
+            .filter(|(part, answer)| *answer != Answer::None || expected(*part).is_some())
+            .map(|(part, answer)| row(part, status(&answer, expected(part)), answer.to_string()))
+            .collect(),
+        Err(_) => both_parts(Status::Fail, "panicked"),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let width = rows
+        .iter()
+        .map(|r| r.answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("answer".len());
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<8}  {:<w$}  expected",
+        "year",
+        "day",
+        "part",
+        "status",
+        "answer",
+        w = width
+    );
+    for r in rows {
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<8}  {:<w$}  {}",
+            r.year,
+            r.day,
+            r.part,
+            r.status.label(),
+            r.answer,
+            r.expected,
+            w = width
+        );
+    }
+}
+
+pub fn verify(args: VerifyArgs) -> Result<(), String> {
+    let inputs_dir = input::inputs_dir(args.inputs_dir.as_deref());
+    let answers_path = args.answers.unwrap_or(inputs_dir.join("answers.txt"));
+    let mut answers = Answers::load(&answers_path)?;
+    let source = InputSource::Lookup {
+        inputs_dir: Some(inputs_dir),
+    };
+
+    let selected = puzzles::select(args.year, args.day);
+    if selected.is_empty() {
+        return Err("No solutions match the given year and day".to_string());
+    }
+
+    let rows: Vec<Row> = selected
+        .iter()
+        .flat_map(|p| verify_puzzle(p, &source, &answers))
+        .collect();
+    print_table(&rows);
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} passed, {} mismatched, {} failed, {} unknown, {} skipped",
+        count(Status::Pass),
+        count(Status::Mismatch),
+        count(Status::Fail),
+        count(Status::Unknown),
+        count(Status::Skipped)
+    );
+
+    if args.record && count(Status::Unknown) > 0 {
+        for r in rows.iter().filter(|r| r.status == Status::Unknown) {
+            answers.insert((r.year, r.day, r.part), r.answer.clone());
+        }
+        answers.save()?;
+        println!(
+            "Recorded {} new answers in {}",
+            count(Status::Unknown),
+            answers_path.display()
+        );
+    }
+
+    if count(Status::Mismatch) + count(Status::Fail) > 0 {
+        return Err("Some solutions did not match their stored answers".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{remove_file, write};
+
+    #[test]
+    fn test_failures_cover_both_parts() {
+        let path = std::env::temp_dir().join(format!("aoc-verify-{}.txt", std::process::id()));
+        let source = InputSource::File(path.clone());
+        let answers = Answers::load(&path.with_extension("missing")).unwrap();
+        let day = puzzles::select(Some(2024), Some(1))[0];
+        let outcome = |puzzle: &Puzzle| -> Vec<(u8, Status, String)> {
+            verify_puzzle(puzzle, &source, &answers)
+                .into_iter()
+                .map(|r| (r.part, r.status, r.answer))
+                .collect()
+        };
+        let both = |status, answer: &str| [1, 2].map(|part| (part, status, answer.to_string()));
+
+        assert_eq!(outcome(day), both(Status::Skipped, "no input"));
+        write(&path, "3   x\n").unwrap();
+        assert_eq!(outcome(day), both(Status::Fail, "invalid input"));
+        let panicking = Puzzle {
+            solve: |_, _| panic!("bad input"),
+            ..*day
+        };
+        write(&path, "3   4\n").unwrap();
+        assert_eq!(outcome(&panicking), both(Status::Fail, "panicked"));
+        remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+//! Made up and mangled inputs, for checking that parsers reject what they
+//! can't read instead of panicking on it.
+
+use crate::Rng;
+
+/// Pieces that tend to trip up hand-rolled parsers: separators, signs,
+/// numbers too big for their type and a character wider than a byte.
+const TRICKY: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    "\n\n",
+    "\t",
+    ",",
+    ":",
+    "-",
+    "+",
+    "=",
+    "(",
+    ")",
+    "#",
+    ".",
+    "0",
+    "-1",
+    "99999999999999999999",
+    "é",
+];
+
+/// Up to `max_len` characters of mostly printable ASCII, with the odd
+/// tricky piece mixed in.
+pub fn arbitrary(max_len: usize, rng: &mut Rng) -> String {
+    let len = rng.below(max_len + 1);
+    let mut input = String::new();
+    while input.chars().count() < len {
+        match rng.chance(0.9) {
+            true => input.push(char::from(b' ' + rng.below(95) as u8)),
+            false => input.push_str(rng.pick::<&str>(TRICKY)),
+        }
+    }
+    input
+}
+
+/// `input` after a few random edits: characters dropped, repeated or
+/// swapped for tricky pieces, lines dropped or repeated, or the whole thing
+/// cut short.
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..rng.range(1, 3) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(7) {
+            0 if at < chars.len() => {
+                chars.remove(at);
+            }
+            1 if !chars.is_empty() => {
+                let c = *rng.pick(&chars);
+                chars.insert(at, c);
+            }
+            2 => {
+                let end = (at + rng.below(4)).min(chars.len());
+                chars.splice(at..end, rng.pick(TRICKY).chars());
+            }
+            3 => {
+                let end = (at + rng.below(16)).min(chars.len());
+                let repeated: Vec<char> = chars[at..end].to_vec();
+                chars.splice(at..at, repeated);
+            }
+            4 => chars.truncate(at),
+            _ => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.lines().collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                let line = rng.below(lines.len());
+                match rng.chance(0.5) {
+                    true => {
+                        lines.remove(line);
+                    }
+                    false => lines.insert(line, lines[line]),
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate_changes_input() {
+        let mut rng = Rng::new(3);
+        let input = "467..114..\n...*......\n..35..633.\n";
+        let changed = (0..100)
+            .filter(|_| mutate(input, &mut rng) != input)
+            .count();
+        assert!(
+            changed > 80,
+            "only {} of 100 mutations changed anything",
+            changed
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_stays_short() {
+        let mut rng = Rng::new(5);
+        for _ in 0..100 {
+            // a tricky piece can push it over by its own length
+            assert!(arbitrary(10, &mut rng).chars().count() <= 30);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+pub mod fuzz;
+pub mod log;
+mod parse;
+mod rng;
+
+pub use parse::{ParseError, Scanner, first_line, lines, sections, two_sections, unexpected_end};
+pub use rng::Rng;
+
+use std::{env, fmt, fs, process};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// days without a second part (every day 25), or a part that
+    /// couldn't be solved for the given input
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A single day of Advent of Code.
+///
+/// The input is parsed once and shared between both parts, so any part
+/// that needs to mutate it has to clone it first.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::None
+    }
+}
+
+/// Makes up inputs for a day, to push its solution past the one input it
+/// was written against.
+pub trait Generate: Solution {
+    /// A valid input that grows with `size`, always the same for the same
+    /// random numbers. What `size` counts (lines, grid side, ...) is up to
+    /// each day.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+
+    /// A parsed input written out again, the way the puzzle writes it, so
+    /// whatever a parser accepts can be checked to read back the same.
+    fn render(input: &Self::Input) -> String;
+
+    /// The answer to `part` worked out the slow and obvious way, to check
+    /// the real one against on generated inputs. Days without one leave it
+    /// at `None`.
+    fn brute_force(_input: &Self::Input, _part: u8) -> Option<Answer> {
+        None
+    }
+}
+
+/// Solves one part of a puzzle's example input and checks it against the
+/// published answer. Meant for the days' own tests.
+#[track_caller]
+pub fn assert_example<S: Solution>(input: &str, part: u8, expected: impl Into<Answer>) {
+    let input = match S::parse(input) {
+        Ok(input) => input,
+        Err(e) => panic!("invalid example input: {}", e),
+    };
+    let answer = match part {
+        1 => S::part1(&input),
+        2 => S::part2(&input),
+        _ => panic!("there is no part {}", part),
+    };
+    assert_eq!(answer, expected.into(), "part {}", part);
+}
+
+/// Both answers for the input in `text`, a line each, the way `aoc run`
+/// prints them.
+pub fn solve_text<S: Solution>(text: &str) -> Result<String, ParseError> {
+    let input = S::parse(text)?;
+    Ok(format!(
+        "Part 1: {}\nPart 2: {}\n",
+        S::part1(&input),
+        S::part2(&input)
+    ))
+}
+
+/// The whole of a day's own `main.rs`: solves the file given as the first
+/// argument, or `input.txt` in the current directory, and prints both
+/// answers. `aoc run` is the way to run days with all the options.
+pub fn run_day<S: Solution>() {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "input.txt".to_string());
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Can't read {}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    match solve_text::<S>(&text) {
+        Ok(answers) => print!("{}", answers),
+        Err(e) => {
+            eprintln!("Invalid input in {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from("co,de,ka,ta").to_string(), "co,de,ka,ta");
+        assert_eq!(Answer::None.to_string(), "-");
+    }
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            first_line(input)?.numbers()
+        }
+
+        fn part1(numbers: &Self::Input) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+    }
+
+    #[test]
+    fn test_solve_text() {
+        assert_eq!(
+            solve_text::<Sum>("1 2 3\n").unwrap(),
+            "Part 1: 6\nPart 2: -\n"
+        );
+        assert!(solve_text::<Sum>("").is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Traces the solutions can leave along the way, silent unless the runner
+//! was asked for them with `-v` or `-vv`. Everything goes to stderr.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much to show, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// answers only, the default
+    Quiet,
+    /// intermediate results, a few lines per part (`-v`)
+    Info,
+    /// full state dumps like grids and circuits (`-vv`)
+    Debug,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` are shown, for traces too costly to build
+/// when they aren't.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// The level for a number of `-v` flags.
+pub fn level_for(verbosity: usize) -> Level {
+    match verbosity {
+        0 => Level::Quiet,
+        1 => Level::Info,
+        _ => Level::Debug,
+    }
+}
+
+/// Prints a line to stderr when running with `-v` or more. The arguments
+/// aren't evaluated otherwise.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints a line to stderr when running with `-vv`. The arguments aren't
+/// evaluated otherwise.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(level_for(0), Level::Quiet);
+        assert_eq!(level_for(1), Level::Info);
+        assert_eq!(level_for(3), Level::Debug);
+
+        set_level(Level::Info);
+        assert!(enabled(Level::Info));
+        assert!(!enabled(Level::Debug));
+        assert!(!enabled(Level::Quiet));
+        set_level(Level::Quiet);
+        assert!(!enabled(Level::Info));
+    }
+}
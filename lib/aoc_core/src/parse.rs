@@ -0,0 +1,328 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why an input couldn't be parsed, pointing at where it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in characters
+    pub column: usize,
+    /// what the parser was looking for, e.g. `"a number"` or `"'->'"`
+    pub expected: String,
+    /// what was there instead
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Every line of the input, numbered from 1. Blank lines at the end are
+/// dropped, blank lines in between are kept.
+pub fn lines(input: &str) -> impl Iterator<Item = Scanner<'_>> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Scanner::new(i + 1, line))
+}
+
+/// The lines of the input split into the blocks separated by blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Scanner<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if line.rest().trim().is_empty() {
+            if !sections.last().unwrap().is_empty() {
+                sections.push(Vec::new());
+            }
+        } else {
+            sections.last_mut().unwrap().push(line);
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+/// The two blocks of an input made of exactly two, `second` saying what the
+/// second one should hold.
+pub fn two_sections<'a>(input: &'a str, second: &str) -> Result<[Vec<Scanner<'a>>; 2], ParseError> {
+    let mut sections = sections(input);
+    if let Some(extra) = sections.get(2) {
+        return Err(extra[0].error("the end of the input"));
+    }
+    if sections.len() < 2 {
+        return Err(unexpected_end(
+            input,
+            format!("a blank line followed by {}", second),
+        ));
+    }
+    let second = sections.pop().unwrap();
+    let first = sections.pop().unwrap();
+    Ok([first, second])
+}
+
+/// The first line of a single-line input.
+pub fn first_line(input: &str) -> Result<Scanner<'_>, ParseError> {
+    lines(input)
+        .next()
+        .ok_or_else(|| unexpected_end(input, "a line"))
+}
+
+/// An error for an input that ended before the parser was done with it.
+pub fn unexpected_end(input: &str, expected: impl Into<String>) -> ParseError {
+    ParseError {
+        line: input.trim_end().lines().count() + 1,
+        column: 1,
+        expected: expected.into(),
+        found: "end of input".to_string(),
+    }
+}
+
+/// Reads a single line from left to right, keeping track of the column so
+/// errors can point at it.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    line: usize,
+    text: &'a str,
+    /// byte offset of what hasn't been read yet
+    offset: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: usize, text: &'a str) -> Scanner<'a> {
+        Scanner {
+            line,
+            text: text.trim_end_matches('\r'),
+            offset: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.offset].chars().count() + 1
+    }
+
+    /// Everything that hasn't been read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// An error at the current column, showing the next token as what was
+    /// found instead.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let token: String = self
+            .rest()
+            .chars()
+            .take_while(|c| !c.is_whitespace())
+            .take(16)
+            .collect();
+        let found = match (token.is_empty(), self.rest().chars().next()) {
+            (_, None) => "end of line".to_string(),
+            (true, Some(c)) => format!("{:?}", c),
+            (false, _) => format!("'{}'", token),
+        };
+        ParseError {
+            line: self.line,
+            column: self.column(),
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    fn advance(&mut self, bytes: usize) -> &'a str {
+        let taken = &self.text[self.offset..self.offset + bytes];
+        self.offset += bytes;
+        taken
+    }
+
+    pub fn skip_spaces(&mut self) {
+        let spaces = self.rest().len() - self.rest().trim_start().len();
+        self.advance(spaces);
+    }
+
+    /// Consumes `literal` if the line continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.advance(literal.len());
+        }
+        found
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.eat(literal) {
+            true => Ok(()),
+            false => Err(self.error(format!("'{}'", literal))),
+        }
+    }
+
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        let c = self
+            .rest()
+            .chars()
+            .next()
+            .ok_or(self.error("a character"))?;
+        self.advance(c.len_utf8());
+        Ok(c)
+    }
+
+    /// The next character converted with `convert`, which returns `None` for
+    /// anything that isn't `expected`.
+    pub fn char_as<T>(
+        &mut self,
+        expected: &str,
+        convert: impl FnOnce(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        let at = self.clone();
+        let value = self.char().ok().and_then(convert);
+        value.ok_or_else(|| at.error(expected))
+    }
+
+    /// The next word converted with `convert`, which returns `None` for
+    /// anything that isn't `expected`.
+    pub fn word_as<T>(
+        &mut self,
+        expected: &str,
+        convert: impl FnOnce(&'a str) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        self.skip_spaces();
+        let at = self.clone();
+        let value = self.word().ok().and_then(convert);
+        value.ok_or_else(|| at.error(expected))
+    }
+
+    /// An integer, with an optional leading sign. Spaces before it are skipped.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let sign = rest.starts_with(['-', '+']) as usize;
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        match rest[..sign + digits].parse() {
+            Ok(number) if digits > 0 => {
+                self.advance(sign + digits);
+                Ok(number)
+            }
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    /// A non-empty run of letters, digits and underscores. Spaces before it
+    /// are skipped.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_spaces();
+        let len = self
+            .rest()
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(self.rest().len());
+        match len {
+            0 => Err(self.error("a word")),
+            _ => Ok(self.advance(len)),
+        }
+    }
+
+    /// Everything up to `delimiter`, or up to the end of the line when it's
+    /// not there. The delimiter itself is left unread.
+    pub fn until(&mut self, delimiter: &str) -> &'a str {
+        let len = self.rest().find(delimiter).unwrap_or(self.rest().len());
+        self.advance(len)
+    }
+
+    /// Items separated by `separator` until the end of the line, or until
+    /// something that isn't a separator follows an item.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Scanner<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Numbers separated by whitespace, as many as there are before anything
+    /// else or the end of the line.
+    pub fn numbers<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        let mut numbers = Vec::new();
+        self.skip_spaces();
+        while self
+            .rest()
+            .trim_start_matches(['-', '+'])
+            .starts_with(|c: char| c.is_ascii_digit())
+        {
+            numbers.push(self.number()?);
+            self.skip_spaces();
+        }
+        Ok(numbers)
+    }
+
+    /// Fails unless the whole line has been read.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_spaces();
+        match self.is_done() {
+            true => Ok(()),
+            false => Err(self.error("end of line")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut line = Scanner::new(3, "Game 12: 3 blue, -4 red");
+        line.expect("Game").unwrap();
+        assert_eq!(line.number::<u32>(), Ok(12));
+        line.expect(":").unwrap();
+        let cubes = line.separated(",", |s| Ok((s.number::<i32>()?, s.word()?)));
+        assert_eq!(cubes, Ok(vec![(3, "blue"), (-4, "red")]));
+        assert!(line.end().is_ok());
+        assert_eq!(line.column(), 24);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut line = Scanner::new(2, "x=1,y=oops");
+        line.expect("x=").unwrap();
+        line.number::<u8>().unwrap();
+        line.expect(",y=").unwrap();
+        let error = line.number::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: expected a number, found 'oops'"
+        );
+        assert_eq!(line.end().unwrap_err().found, "'oops'");
+        assert_eq!(Scanner::new(1, "").char().unwrap_err().found, "end of line");
+    }
+
+    #[test]
+    fn test_sections() {
+        let sections = sections("a\nb\n\n\nc\n");
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1][0].line(), 5);
+        assert_eq!(unexpected_end("a\nb\n", "c").line, 3);
+    }
+}
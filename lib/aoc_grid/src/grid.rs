@@ -0,0 +1,305 @@
+use crate::{Direction, Position};
+use aoc_core::{ParseError, Scanner};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// all 8 neighbours, clockwise starting from north
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from text, one row per line, converting every character
+    /// with `cell`, which returns `None` for characters that aren't
+    /// `expected`. Blank lines around the grid are ignored, but there has to
+    /// be at least one cell.
+    pub fn parse(
+        text: &str,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<Scanner> = aoc_core::lines(text)
+            .skip_while(|line| line.is_done())
+            .collect();
+        if lines.is_empty() {
+            return Err(aoc_core::unexpected_end(text, expected));
+        }
+        Grid::from_lines(&lines, expected, cell)
+    }
+
+    /// Like [`Grid::parse`], for a grid that is only part of the input.
+    pub fn from_lines(
+        lines: &[Scanner],
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let width = lines
+            .first()
+            .map_or(0, |line| line.rest().trim_end().chars().count());
+        if let Some(first) = lines.first()
+            && width == 0
+        {
+            return Err(first.error(expected));
+        }
+        for line in lines {
+            let mut line = line.clone();
+            for _ in 0..width {
+                cells.push(line.char_as(expected, &mut cell)?);
+            }
+            line.end()
+                .map_err(|_| line.error(format!("the end of a row of {} cells", width)))?;
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    /// The position at signed coordinates, if they fall inside the grid.
+    pub fn position(&self, x: isize, y: isize) -> Option<Position> {
+        let position = Position::new(x.try_into().ok()?, y.try_into().ok()?);
+        self.contains(position).then_some(position)
+    }
+
+    fn index_of(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[self.index_of(position)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => {
+                let index = self.index_of(position);
+                Some(&mut self.cells[index])
+            }
+            false => None,
+        }
+    }
+
+    /// Treats the grid as tiling the whole plane.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[Position::new(x, y)]
+    }
+
+    /// The neighbouring position in `direction`, if it is inside the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|&next| self.contains(next))
+    }
+
+    /// The neighbouring position in `direction`, wrapping around the edges.
+    pub fn step_wrapping(&self, position: Position, direction: Direction) -> Position {
+        let (dx, dy) = direction.delta();
+        Position::new(
+            (position.x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (position.y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// Orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| position.offset(dx, dy).filter(|&next| self.contains(next)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Draws the grid as text, one line per row, with the character `cell`
+    /// gives for every position.
+    pub fn render(&self, mut cell: impl FnMut(Position, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for (position, value) in self.iter() {
+            text.push(cell(position, value));
+            if position.x == self.width - 1 {
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the grid",
+            position
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the grid",
+            position
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|_, &c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "#..\n.#.\n..S\n";
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = Grid::parse(TEXT, "a tile", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.find(|&c| c == 'S'), Some(Position::new(2, 2)));
+        assert_eq!(grid.to_string(), TEXT);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse("..\n.#\n", "'.'", |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Grid::parse("..\n...\n", "a tile", Some).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = Grid::parse("...\n..\n", "a tile", Some).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.found.as_str()),
+            (2, 3, "end of line")
+        );
+        assert!(Grid::parse("", "a tile", Some).is_err());
+        let error = Grid::parse("\n  \n..\n", "a tile", Some).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(TEXT, "a tile", Some).unwrap();
+        let corner: Vec<Position> = grid.neighbours4(Position::new(0, 0)).collect();
+        assert_eq!(corner, vec![Position::new(1, 0), Position::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Position::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::parse(TEXT, "a tile", Some).unwrap();
+        assert_eq!(grid.get_wrapping(-1, -1), &'S');
+        assert_eq!(grid.get_wrapping(3, 4), &'.');
+        let wrapped = grid.step_wrapping(Position::new(0, 1), Direction::West);
+        assert_eq!(wrapped, Position::new(2, 1));
+        assert_eq!(grid.step(Position::new(0, 1), Direction::West), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse(TEXT, "a tile", Some).unwrap();
+        assert_eq!(grid.row(1), &['.', '#', '.']);
+        let column: String = grid.column(2).collect();
+        assert_eq!(column, "..S");
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.columns().count(), 3);
+    }
+}
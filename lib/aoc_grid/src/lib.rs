@@ -0,0 +1,10 @@
+//! 2D grids shared by the puzzles that walk around a map.
+
+mod direction;
+mod grid;
+mod position;
+pub mod view;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use position::Position;
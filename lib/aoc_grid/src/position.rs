@@ -0,0 +1,32 @@
+use crate::Direction;
+
+/// A cell in a grid, `(0, 0)` being the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Position {
+        Position { x, y }
+    }
+
+    /// `None` if the result would have a negative coordinate.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Position> {
+        Some(Position {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// The neighbouring position, without checking it against any grid.
+    pub fn step(self, direction: Direction) -> Option<Position> {
+        let (dx, dy) = direction.delta();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan_distance(self, other: Position) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
@@ -0,0 +1,287 @@
+//! Pictures of a puzzle's state, drawn the same way whatever the day: as
+//! coloured text on stderr, or as numbered PPM or PNG frames that play back
+//! as an animation. Nothing is drawn unless the runner was asked to, with
+//! `aoc run --visualize`.
+
+use crate::Grid;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    pub const DARK_GREY: Color = Color::new(50, 50, 50);
+    pub const GREY: Color = Color::new(110, 110, 110);
+    pub const WHITE: Color = Color::new(230, 230, 230);
+    pub const RED: Color = Color::new(230, 60, 50);
+    pub const GREEN: Color = Color::new(60, 200, 80);
+    pub const BLUE: Color = Color::new(70, 120, 230);
+    pub const YELLOW: Color = Color::new(250, 210, 40);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+}
+
+/// One spot of a picture: a character for text, in a colour that's all
+/// images keep of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Cell {
+        Cell { glyph, color }
+    }
+}
+
+/// A puzzle state that can be drawn.
+pub trait Visualize {
+    fn view(&self) -> Grid<Cell>;
+}
+
+/// Where pictures go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// redrawn in place on stderr, in colour
+    Terminal,
+    /// `frame_00001.ppm`, `frame_00002.ppm` and so on, in a directory
+    Ppm(PathBuf),
+    /// the same as PNG images
+    Png(PathBuf),
+}
+
+struct Sink {
+    output: Output,
+    frames: usize,
+}
+
+static SINK: Mutex<Option<Sink>> = Mutex::new(None);
+
+/// Sends pictures to `output` from now on, numbering frames from 1 again,
+/// or stops drawing them.
+pub fn set_output(output: Option<Output>) {
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    *sink = output.map(|output| Sink { output, frames: 0 });
+}
+
+/// Draws `state` wherever pictures go, as the next frame. Its view isn't
+/// built when they go nowhere.
+pub fn show(state: &impl Visualize) {
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(sink) = sink.as_mut() else {
+        return;
+    };
+    let view = state.view();
+    sink.frames += 1;
+    let (dir, image, extension) = match &sink.output {
+        Output::Terminal => {
+            eprint!("\x1b[H\x1b[2J{}", terminal(&view));
+            return;
+        }
+        Output::Ppm(dir) => (dir, ppm(&view), "ppm"),
+        Output::Png(dir) => (dir, png(&view), "png"),
+    };
+    let path = dir.join(format!("frame_{:05}.{}", sink.frames, extension));
+    if let Err(e) = fs::create_dir_all(dir).and_then(|_| fs::write(&path, image)) {
+        eprintln!("Could not write {}: {}", path.display(), e);
+    }
+}
+
+/// The characters alone, one line per row.
+pub fn text(view: &Grid<Cell>) -> String {
+    view.render(|_, cell| cell.glyph)
+}
+
+/// The characters in their colours, for terminals that take 24-bit ANSI
+/// colour codes.
+pub fn terminal(view: &Grid<Cell>) -> String {
+    let mut text = String::new();
+    for row in view.rows() {
+        let mut color = None;
+        for cell in row {
+            if color != Some(cell.color) {
+                let Color { r, g, b } = cell.color;
+                text.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                color = Some(cell.color);
+            }
+            text.push(cell.glyph);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+/// Pixels on each side of a cell in images.
+pub const SCALE: usize = 4;
+
+/// Rows of RGB pixels, each cell a square of its colour.
+fn pixel_rows(view: &Grid<Cell>) -> Vec<Vec<u8>> {
+    let mut rows = Vec::with_capacity(view.height() * SCALE);
+    for row in view.rows() {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| [cell.color.r, cell.color.g, cell.color.b].repeat(SCALE))
+            .collect();
+        for _ in 0..SCALE {
+            rows.push(pixels.clone());
+        }
+    }
+    rows
+}
+
+/// A binary PPM image, which most viewers and `ffmpeg` read.
+pub fn ppm(view: &Grid<Cell>) -> Vec<u8> {
+    let header = format!(
+        "P6\n{} {}\n255\n",
+        view.width() * SCALE,
+        view.height() * SCALE
+    );
+    let mut image = header.into_bytes();
+    image.extend(pixel_rows(view).concat());
+    image
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn png_chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    let start = image.len();
+    image.extend(kind);
+    image.extend(data);
+    let crc = crc32(&image[start..]);
+    image.extend(crc.to_be_bytes());
+}
+
+/// A PNG image. It's left uncompressed, which keeps it simple and the
+/// frames are small anyway.
+pub fn png(view: &Grid<Cell>) -> Vec<u8> {
+    let (width, height) = (view.width() * SCALE, view.height() * SCALE);
+    // every row starts with the filter it was stored with, none here
+    let raw: Vec<u8> = pixel_rows(view)
+        .into_iter()
+        .flat_map(|row| std::iter::once(0).chain(row))
+        .collect();
+
+    // a zlib stream of stored deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = match raw.is_empty() {
+        true => vec![&[]],
+        false => raw.chunks(u16::MAX as usize).collect(),
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        zlib.push((i + 1 == blocks.len()) as u8);
+        let len = block.len() as u16;
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut image, b"IHDR", &header);
+    png_chunk(&mut image, b"IDAT", &zlib);
+    png_chunk(&mut image, b"IEND", &[]);
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    fn view() -> Grid<Cell> {
+        let mut view = Grid::new(2, 1, Cell::new('.', Color::GREY));
+        view[Position::new(1, 0)] = Cell::new('#', Color::RED);
+        view
+    }
+
+    #[test]
+    fn test_text_and_terminal() {
+        assert_eq!(text(&view()), ".#\n");
+        assert_eq!(
+            terminal(&view()),
+            "\x1b[38;2;110;110;110m.\x1b[38;2;230;60;50m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(&view());
+        let header = b"P6\n8 4\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 8 * 4 * 3);
+        assert_eq!(&image[header.len()..header.len() + 3], &[110, 110, 110]);
+        assert_eq!(&image[image.len() - 3..], &[230, 60, 50]);
+    }
+
+    #[test]
+    fn test_png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+
+        let image = png(&view());
+        assert_eq!(&image[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&image[12..16], b"IHDR");
+        assert_eq!(&image[16..24], &[0, 0, 0, 8, 0, 0, 0, 4]);
+        assert_eq!(&image[image.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn test_show_numbers_frames() {
+        struct State;
+        impl Visualize for State {
+            fn view(&self) -> Grid<Cell> {
+                view()
+            }
+        }
+
+        let dir = std::env::temp_dir().join(format!("aoc-view-{}", std::process::id()));
+        set_output(Some(Output::Png(dir.clone())));
+        show(&State);
+        show(&State);
+        set_output(None);
+        show(&State);
+
+        let mut frames: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        frames.sort();
+        assert_eq!(frames, ["frame_00001.png", "frame_00002.png"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
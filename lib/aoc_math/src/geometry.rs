@@ -0,0 +1,35 @@
+/// Shoelace formula: twice the area of the polygon with these vertices, in
+/// order, so it stays an integer. The polygon is closed automatically, and
+/// repeating the first vertex at the end makes no difference.
+pub fn shoelace(vertices: &[(i64, i64)]) -> i64 {
+    let next = vertices.iter().cycle().skip(1);
+    let sum: i64 = vertices
+        .iter()
+        .zip(next)
+        .map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1)
+        .sum();
+    sum.abs()
+}
+
+/// Pick's theorem: how many lattice points lie strictly inside a polygon with
+/// its vertices on the lattice, given twice its area (see [`shoelace`]) and
+/// the number of lattice points on its boundary.
+pub fn interior_points(twice_area: i64, boundary: i64) -> i64 {
+    (twice_area - boundary + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shoelace_and_pick() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(shoelace(&square), 32);
+        let mut closed = square.to_vec();
+        closed.push(square[0]);
+        assert_eq!(shoelace(&closed), 32);
+        assert_eq!(interior_points(shoelace(&square), 16), 9);
+        assert_eq!(shoelace(&[]), 0);
+    }
+}
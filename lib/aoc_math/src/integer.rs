@@ -0,0 +1,138 @@
+use std::ops::{Div, Mul, Rem};
+
+/// The primitive integers, signed or not.
+pub trait Integer:
+    Copy + Ord + Div<Output = Self> + Mul<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize);
+integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// Least common multiple, never negative. 0 if either number is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Least common multiple of all the numbers, 1 if there are none.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a, modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese remainder theorem: the `x` satisfying every `x ≡ residue (mod
+/// modulus)`, as `(x, lcm of the moduli)` with `x` in `0..lcm`. The moduli
+/// don't need to be coprime; `None` if the congruences contradict each other.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut x = 0;
+    let mut modulus = 1;
+    for &(residue, m) in congruences {
+        let (g, p, _) = extended_gcd(modulus, m);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = (difference / g % step * p).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+    Some((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0u32, 5), 5);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm_all([2usize, 3, 1]), 6);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (7, 0), (0, -7)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,14 @@
+//! Number theory and linear algebra shared by the puzzles that end up being
+//! maths problems in disguise.
+
+mod geometry;
+mod integer;
+mod linear;
+mod polynomial;
+mod rational;
+
+pub use geometry::{interior_points, shoelace};
+pub use integer::{Integer, crt, extended_gcd, gcd, lcm, lcm_all, mod_inverse};
+pub use linear::{Field, least_squares, solve, solve_2x2_integer};
+pub use polynomial::Quadratic;
+pub use rational::Rational;
@@ -0,0 +1,172 @@
+use crate::Rational;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Numbers Gaussian elimination can work with: exactly with [`Rational`], or
+/// approximately with `f64`.
+pub trait Field:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn zero() -> Self;
+    fn abs(self) -> Self;
+    /// whether a pivot is too small to divide by
+    fn is_zero(self) -> bool;
+}
+
+impl Field for f64 {
+    fn zero() -> f64 {
+        0.0
+    }
+
+    fn abs(self) -> f64 {
+        f64::abs(self)
+    }
+
+    fn is_zero(self) -> bool {
+        self.abs() < 1e-10
+    }
+}
+
+impl Field for Rational {
+    fn zero() -> Rational {
+        Rational::ZERO
+    }
+
+    fn abs(self) -> Rational {
+        Rational::abs(self)
+    }
+
+    fn is_zero(self) -> bool {
+        self == Rational::ZERO
+    }
+}
+
+/// Solves the square system `matrix * x = rhs` by Gaussian elimination with
+/// partial pivoting. `None` if the matrix is singular.
+pub fn solve<T: Field>(matrix: &[Vec<T>], rhs: &[T]) -> Option<Vec<T>> {
+    let n = rhs.len();
+    assert_eq!(matrix.len(), n, "the matrix must have a row per equation");
+
+    // augmented matrix, `rhs` being the last column
+    let mut rows: Vec<Vec<T>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &b)| {
+            assert_eq!(row.len(), n, "the matrix must be square");
+            row.iter().copied().chain([b]).collect()
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| rows[a][col].abs().partial_cmp(&rows[b][col].abs()).unwrap())
+            .unwrap();
+        rows.swap(col, pivot);
+        if rows[col][col].is_zero() {
+            return None;
+        }
+        let (above, below) = rows.split_at_mut(col + 1);
+        let pivot_row = &above[col];
+        for row in below {
+            let factor = row[col] / pivot_row[col];
+            for (value, &pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value = *value - factor * pivot_value;
+            }
+        }
+    }
+
+    // back substitution
+    let mut solution = vec![T::zero(); n];
+    for i in (0..n).rev() {
+        let mut sum = rows[i][n];
+        for j in i + 1..n {
+            sum = sum - rows[i][j] * solution[j];
+        }
+        solution[i] = sum / rows[i][i];
+    }
+    Some(solution)
+}
+
+/// Least squares fit of an overdetermined system `matrix * x ≈ rhs`, solving
+/// the normal equations `(Aᵀ A) x = Aᵀ b`.
+pub fn least_squares<T: Field>(matrix: &[Vec<T>], rhs: &[T]) -> Option<Vec<T>> {
+    let n = matrix.first().map_or(0, Vec::len);
+    let dot = |f: &dyn Fn(&[T], T) -> T| {
+        matrix
+            .iter()
+            .zip(rhs)
+            .fold(T::zero(), |sum, (row, &b)| sum + f(row, b))
+    };
+    let ata: Vec<Vec<T>> = (0..n)
+        .map(|i| (0..n).map(|j| dot(&|row, _| row[i] * row[j])).collect())
+        .collect();
+    let atb: Vec<T> = (0..n).map(|i| dot(&|row, b| row[i] * b)).collect();
+    solve(&ata, &atb)
+}
+
+/// The integer solution of `a * [x, y] = b`, if there is exactly one.
+pub fn solve_2x2_integer(a: [[i64; 2]; 2], b: [i64; 2]) -> Option<[i64; 2]> {
+    // Cramer's rule
+    let determinant = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+    if determinant == 0 {
+        return None;
+    }
+    let x = b[0] * a[1][1] - a[0][1] * b[1];
+    let y = a[0][0] * b[1] - b[0] * a[1][0];
+    if x % determinant != 0 || y % determinant != 0 {
+        return None;
+    }
+    Some([x / determinant, y / determinant])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::integer(v)).collect()
+    }
+
+    #[test]
+    fn test_solve() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+        let matrix = [
+            rationals(&[2, 1, -1]),
+            rationals(&[-3, -1, 2]),
+            rationals(&[-2, 1, 2]),
+        ];
+        let solution = solve(&matrix, &rationals(&[8, -11, -3])).unwrap();
+        assert_eq!(solution, rationals(&[2, 3, -1]));
+
+        let approximate = solve(&[vec![2.0, 1.0], vec![1.0, 3.0]], &[3.0, 5.0]).unwrap();
+        assert!((approximate[0] - 0.8).abs() < 1e-9 && (approximate[1] - 1.4).abs() < 1e-9);
+
+        let singular = [rationals(&[1, 2]), rationals(&[2, 4])];
+        assert_eq!(solve(&singular, &rationals(&[1, 2])), None);
+    }
+
+    #[test]
+    fn test_least_squares() {
+        // three points exactly on y = 2x + 1
+        let matrix = [rationals(&[0, 1]), rationals(&[1, 1]), rationals(&[2, 1])];
+        let fit = least_squares(&matrix, &rationals(&[1, 3, 5])).unwrap();
+        assert_eq!(fit, rationals(&[2, 1]));
+    }
+
+    #[test]
+    fn test_solve_2x2_integer() {
+        assert_eq!(
+            solve_2x2_integer([[94, 22], [34, 67]], [8400, 5400]),
+            Some([80, 40])
+        );
+        assert_eq!(
+            solve_2x2_integer([[26, 67], [66, 21]], [12748, 12176]),
+            None
+        );
+        assert_eq!(solve_2x2_integer([[1, 2], [2, 4]], [3, 6]), None);
+    }
+}
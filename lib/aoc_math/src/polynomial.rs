@@ -0,0 +1,51 @@
+use crate::{Rational, solve};
+
+/// `a x² + b x + c`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quadratic {
+    pub a: Rational,
+    pub b: Rational,
+    pub c: Rational,
+}
+
+impl Quadratic {
+    /// The quadratic going through three points, `None` if two of them share
+    /// an `x`.
+    pub fn through(points: [(i128, i128); 3]) -> Option<Quadratic> {
+        let matrix: Vec<Vec<Rational>> = points
+            .iter()
+            .map(|&(x, _)| {
+                vec![
+                    Rational::integer(x * x),
+                    Rational::integer(x),
+                    Rational::ONE,
+                ]
+            })
+            .collect();
+        let values = points.map(|(_, y)| Rational::integer(y));
+        let [a, b, c] = solve(&matrix, &values)?.try_into().unwrap();
+        Some(Quadratic { a, b, c })
+    }
+
+    pub fn at(&self, x: i128) -> Rational {
+        let x = Rational::integer(x);
+        (self.a * x + self.b) * x + self.c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_through() {
+        // 3x² - 2x + 1
+        let quadratic = Quadratic::through([(0, 1), (1, 2), (2, 9)]).unwrap();
+        assert_eq!(quadratic.a, Rational::integer(3));
+        assert_eq!(quadratic.at(10), Rational::integer(281));
+
+        let half = Quadratic::through([(0, 0), (1, 0), (2, 1)]).unwrap();
+        assert_eq!(half.a, Rational::new(1, 2));
+        assert_eq!(Quadratic::through([(1, 0), (1, 1), (2, 1)]), None);
+    }
+}
@@ -0,0 +1,134 @@
+//! Simulations that end up repeating themselves, for the puzzles that ask
+//! about a step far too many to take one by one.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Every state of a simulation from the start until it repeats: a prefix
+/// taken once, then a loop taken forever after.
+#[derive(Debug)]
+pub struct Cycle<S> {
+    /// the state after each step, from 0 up to just before the first repeat
+    states: Vec<S>,
+    prefix: usize,
+}
+
+impl<S> Cycle<S> {
+    /// Steps before the loop starts.
+    pub fn prefix(&self) -> usize {
+        self.prefix
+    }
+
+    /// Steps to go once round the loop.
+    pub fn period(&self) -> usize {
+        self.states.len() - self.prefix
+    }
+
+    /// The state after `steps` steps, however many that is.
+    pub fn state_at(&self, steps: usize) -> &S {
+        match steps < self.states.len() {
+            true => &self.states[steps],
+            false => &self.states[self.prefix + (steps - self.prefix) % self.period()],
+        }
+    }
+}
+
+/// Steps from `start` with `next` until a state comes round again. States
+/// are compared in full, never by a summary like a hash or a score, so the
+/// prefix and period are exact.
+pub fn find_cycle<S: Clone + Eq + Hash>(start: S, mut next: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    loop {
+        let state = next(states.last().unwrap());
+        if let Some(&prefix) = seen.get(&state) {
+            return Cycle { states, prefix };
+        }
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+    }
+}
+
+/// The prefix and period of the states reached from `start`, with Brent's
+/// algorithm. Only a couple of states are kept at a time, for simulations
+/// whose states are too big to keep them all or can't be hashed; getting
+/// the state at some step is up to the caller.
+pub fn brent<S: Clone + Eq>(start: S, mut next: impl FnMut(&S) -> S) -> (usize, usize) {
+    // the period: a tortoise waits at powers of two for the hare to catch up
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = next(&hare);
+        period += 1;
+    }
+
+    // the prefix: with the hare a period ahead, they meet where the loop starts
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = next(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+    (prefix, period)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        let mut n = 0;
+        let mut states = vec![0];
+        for _ in 0..20 {
+            n = (n * 7 + 3) % 25;
+            states.push(n);
+        }
+        let cycle = find_cycle(0, |n| (n * 7 + 3) % 25);
+        let (prefix, period) = brent(0, |n| (n * 7 + 3) % 25);
+        assert_eq!((cycle.prefix(), cycle.period()), (prefix, period));
+        for (steps, state) in states.iter().enumerate() {
+            assert_eq!(cycle.state_at(steps), state);
+        }
+    }
+
+    #[test]
+    fn test_prefix_before_the_loop() {
+        // 3, then 0, 1, 2, 5, 6, 7 over and over
+        let step = |x: &u64| (x * x + 1) % 10;
+        let cycle = find_cycle(3, step);
+        assert_eq!((cycle.prefix(), cycle.period()), (1, 6));
+        assert_eq!(brent(3, step), (1, 6));
+
+        let mut x = 3;
+        for _ in 0..1000 {
+            x = step(&x);
+        }
+        assert_eq!(*cycle.state_at(1000), x);
+        assert_eq!(
+            cycle.state_at(1_000_000_000),
+            cycle.state_at(1_000_000_000 - 100 * cycle.period())
+        );
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let cycle = find_cycle(5, |_| 7);
+        assert_eq!((cycle.prefix(), cycle.period()), (1, 1));
+        assert_eq!(brent(5, |_| 7), (1, 1));
+        assert_eq!(*cycle.state_at(0), 5);
+        assert_eq!(*cycle.state_at(10), 7);
+    }
+}
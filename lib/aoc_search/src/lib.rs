@@ -0,0 +1,342 @@
+//! Graph searches over implicit graphs: states are anything hashable, and
+//! edges come from a successor function.
+
+mod cycle;
+
+pub use cycle::{Cycle, brent, find_cycle};
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Anything that can be used as the weight of an edge. `Default` has to be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Every state reached so far, addressed by index so the searches don't need
+/// `S: Ord` or to clone states around.
+struct Visited<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    /// states this one was reached from with its best cost
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Visited {
+            states: vec![start.clone()],
+            index: HashMap::from([(start, 0)]),
+            predecessors: vec![Vec::new()],
+        }
+    }
+
+    /// The index of `state`, and whether it had never been seen before.
+    fn insert(&mut self, state: S) -> (usize, bool) {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let i = self.states.len();
+                self.states.push(entry.key().clone());
+                self.predecessors.push(Vec::new());
+                entry.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    /// Follows the first predecessor of every state back to the start.
+    fn path_to(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while let Some(&previous) = self.predecessors[i].first() {
+            path.push(self.states[previous].clone());
+            i = previous;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search for the path with the fewest steps to a goal,
+/// including both the start and the goal.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&visited.states[i]) {
+            return Some(visited.path_to(i));
+        }
+        for next in successors(&visited.states[i]) {
+            let (j, new) = visited.insert(next);
+            if new {
+                visited.predecessors[j].push(i);
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// Steps from the start to every state reachable in at most `max_steps`.
+pub fn bfs_distances<S, I>(
+    start: S,
+    max_steps: usize,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, steps)) = queue.pop_front() {
+        if steps == max_steps {
+            continue;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back((entry.key().clone(), steps + 1));
+                entry.insert(steps + 1);
+            }
+        }
+    }
+    distances
+}
+
+/// Shared by Dijkstra and A*. Stops at the first goal unless `all` is set, in
+/// which case it keeps going until every goal with the best cost has been
+/// found, recording every predecessor on an optimal path.
+fn best_first<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<(Visited<S>, Vec<usize>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&visited.states[0]), C::default(), 0))]);
+    let mut goals = Vec::new();
+    let mut goal_cost = None;
+
+    while let Some(Reverse((estimate, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue; // already reached more cheaply
+        }
+        if goal_cost.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if is_goal(&visited.states[i]) {
+            goals.push(i);
+            goal_cost = Some(cost);
+            if !all {
+                break;
+            }
+            continue;
+        }
+
+        for (next, weight) in successors(&visited.states[i]) {
+            let next_cost = cost + weight;
+            let (j, new) = visited.insert(next);
+            if new {
+                costs.push(next_cost);
+            } else if next_cost == costs[j] {
+                visited.predecessors[j].push(i);
+                continue;
+            } else if next_cost > costs[j] {
+                continue;
+            }
+            costs[j] = next_cost;
+            visited.predecessors[j] = vec![i];
+            let estimate = next_cost + heuristic(&visited.states[j]);
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+    goal_cost.map(|cost| (visited, goals, cost))
+}
+
+/// Cheapest path to a goal and its cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate
+/// the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (visited, goals, cost) = best_first(start, successors, heuristic, is_goal, false)?;
+    Some((visited.path_to(goals[0]), cost))
+}
+
+/// Every optimal path to every goal with the best cost.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (visited, goals, cost) = best_first(start, successors, |_| C::default(), is_goal, true)?;
+    Some(AllPaths {
+        visited,
+        goals,
+        cost,
+    })
+}
+
+/// The result of [`dijkstra_all`]: the predecessor sets of every state on an
+/// optimal path.
+pub struct AllPaths<S, C> {
+    visited: Visited<S>,
+    goals: Vec<usize>,
+    pub cost: C,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&i| &self.visited.states[i])
+    }
+
+    /// The states this one can be reached from on an optimal path.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        let previous = match self.visited.index.get(state) {
+            Some(&i) => self.visited.predecessors[i].as_slice(),
+            None => &[],
+        };
+        previous.iter().map(|&i| &self.visited.states[i])
+    }
+
+    /// Every state that lies on at least one optimal path.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<usize> = self.goals.iter().copied().collect();
+        let mut stack = self.goals.clone();
+        while let Some(i) = stack.pop() {
+            for &previous in &self.visited.predecessors[i] {
+                if seen.insert(previous) {
+                    stack.push(previous);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|i| self.visited.states[i].clone())
+            .collect()
+    }
+
+    /// Every optimal path, from the start to a goal. There can be
+    /// exponentially many of them.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        for &goal in &self.goals {
+            let mut stack = vec![vec![goal]];
+            while let Some(partial) = stack.pop() {
+                let first = *partial.last().unwrap();
+                let previous = &self.visited.predecessors[first];
+                if previous.is_empty() {
+                    let path = partial
+                        .iter()
+                        .rev()
+                        .map(|&i| self.visited.states[i].clone());
+                    paths.push(path.collect());
+                }
+                for &p in previous {
+                    let mut longer = partial.clone();
+                    longer.push(p);
+                    stack.push(longer);
+                }
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// open 4x4 grid, every move costs 1
+    fn moves(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..4).contains(&x) && (0..4).contains(&y))
+            .collect()
+    }
+
+    fn weighted(state: &(i32, i32)) -> Vec<((i32, i32), u32)> {
+        moves(state).into_iter().map(|next| (next, 1)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs((0, 0), moves, |&s| s == (3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[6], (3, 3));
+        assert_eq!(bfs((0, 0), moves, |&s| s == (9, 9)), None);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances((0, 0), 2, moves);
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&(1, 1)], 2);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let (path, cost) = dijkstra((0, 0), weighted, |&s| s == (3, 3)).unwrap();
+        assert_eq!((path.len(), cost), (7, 6));
+
+        let manhattan = |&(x, y): &(i32, i32)| (3 - x + 3 - y) as u32;
+        let (path, cost) = astar((0, 0), weighted, manhattan, |&s| s == (3, 3)).unwrap();
+        assert_eq!((path.len(), cost), (7, 6));
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let all = dijkstra_all((0, 0), weighted, |&s| s == (2, 2)).unwrap();
+        assert_eq!(all.cost, 4);
+        // C(4, 2) ways to arrange 2 rights and 2 downs
+        assert_eq!(all.paths().len(), 6);
+        assert_eq!(all.states().len(), 9);
+        assert_eq!(all.predecessors(&(2, 2)).count(), 2);
+    }
+}